@@ -0,0 +1,53 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use polars_arrow::array::{Array, PrimitiveArray};
+use polars_arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use polars_arrow::io::ipc::write::{Compression, FileWriter, WriteOptions};
+use polars_arrow::record_batch::RecordBatchT;
+
+const NUM_COLUMNS: usize = 200;
+const NUM_ROWS: usize = 1 << 14;
+
+fn write(batch: &RecordBatchT<Box<dyn Array>>, schema: &Arc<ArrowSchema>, options: WriteOptions) {
+    let mut writer =
+        FileWriter::try_new(Cursor::new(vec![]), schema.clone(), None, options).unwrap();
+    writer.write(batch, None).unwrap();
+    writer.finish().unwrap();
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let schema =
+        Arc::new(ArrowSchema::from_iter((0..NUM_COLUMNS).map(|i| {
+            Field::new(format!("c{i}").into(), ArrowDataType::Int64, true)
+        })));
+    let arrays = (0..NUM_COLUMNS)
+        .map(|i| {
+            PrimitiveArray::from_values((0..NUM_ROWS as i64).map(|x| (x * i as i64) % 1000)).boxed()
+        })
+        .collect();
+    let batch = RecordBatchT::try_new(NUM_ROWS, schema.clone(), arrays).unwrap();
+
+    for (name, compression) in [("lz4", Compression::LZ4), ("zstd", Compression::ZSTD)] {
+        for parallel_compression in [false, true] {
+            let options = WriteOptions {
+                compression: Some(compression),
+                parallel_compression,
+                ..Default::default()
+            };
+            let mode = if parallel_compression {
+                "parallel"
+            } else {
+                "sequential"
+            };
+            c.bench_function(
+                &format!("write_ipc {NUM_COLUMNS} columns {name} {mode}"),
+                |b| b.iter(|| write(&batch, &schema, options)),
+            );
+        }
+    }
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -5,27 +5,41 @@
 // write new footer
 use std::io::{Read, Seek, SeekFrom, Write};
 
-use polars_error::{PolarsResult, polars_bail, polars_err};
+#[cfg(feature = "io_ipc_checksum")]
+use crc32c::Crc32cReader;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 
 use super::endianness::is_native_little_endian;
 use super::read::{self, FileMetadata};
 use super::write::common::DictionaryTracker;
 use super::write::writer::*;
 use super::write::*;
+use crate::datatypes::ArrowSchema;
 
 impl<R: Read + Seek + Write> FileWriter<R> {
     /// Creates a new [`FileWriter`] from an existing file, seeking to the last message
     /// and appending new messages afterwards. Users call `finish` to write the footer (with both)
     /// the existing and appended messages on it.
+    ///
+    /// The custom metadata of the existing footer, including its statistics, is not kept, as it
+    /// no longer describes the file once messages are appended.
     /// # Error
     /// This function errors iff:
     /// * the file's endianness is not the native endianness (not yet supported)
     /// * the file is not a valid Arrow IPC file
+    /// * [`WriteOptions::collect_statistics`] is set, as the statistics of the existing record
+    ///   batches can't be restored from the footer
     pub fn try_from_file(
         mut writer: R,
         metadata: FileMetadata,
         options: WriteOptions,
     ) -> PolarsResult<FileWriter<R>> {
+        // The footer only stores the estimated distinct counts, not the sketches they were
+        // estimated from, so the statistics can't be extended to the appended chunks.
+        polars_ensure!(
+            !options.collect_statistics,
+            InvalidOperation: "statistics can't be collected when appending to an IPC file"
+        );
         if metadata.ipc_schema.is_little_endian != is_native_little_endian() {
             polars_bail!(ComputeError: "appending to a file of a non-native endianness is not supported")
         }
@@ -50,10 +64,27 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             .map_err(|_| polars_err!(oos = "the block's body length must be a positive number"))?;
         let offset: u64 = offset + meta_data_length + body_length;
 
+        // The checksum of the appended file also covers the existing messages.
+        options.validate_checksum()?;
+        #[cfg(feature = "io_ipc_checksum")]
+        let checksum = if options.write_checksum {
+            writer.seek(SeekFrom::Start(0))?;
+            let mut reader = Crc32cReader::new((&mut writer).take(offset));
+            std::io::copy(&mut reader, &mut std::io::sink())?;
+            Some(reader.crc32c())
+        } else {
+            None
+        };
+        #[cfg(not(feature = "io_ipc_checksum"))]
+        let checksum = None;
+
         writer.seek(SeekFrom::Start(offset))?;
 
         Ok(FileWriter {
-            writer,
+            writer: ChecksumWriter {
+                inner: writer,
+                checksum,
+            },
             options,
             schema: metadata.schema,
             ipc_fields: metadata.ipc_schema.fields,
@@ -63,10 +94,46 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             state: State::Started, // file already exists, so we are ready
             dictionary_tracker: DictionaryTracker {
                 dictionaries,
-                cannot_replace: true,
+                cannot_replace: !options.allow_dictionary_replacement,
+                allow_delta: options.delta_dictionaries,
+                replacements: 0,
             },
             encoded_message: Default::default(),
-            custom_schema_metadata: None,
+            custom_schema_metadata: metadata.custom_schema_metadata,
+            custom_footer_metadata: None,
+            written_rows: 0,
+            written_batches: 0,
+            footer_length: 0,
+            staged_chunks: vec![],
+            staged_bytes: 0,
+            staged_offset_lengths: vec![],
+            on_block: None,
+            schema_evolution: None,
+            statistics: None,
+            checkpoint_end: 0,
+            kept_dictionaries: None,
         })
     }
+
+    /// Creates a new [`FileWriter`] that appends to an existing IPC file, reading its footer from
+    /// `writer`.
+    ///
+    /// The existing record and dictionary blocks are kept, so `finish` writes a footer
+    /// covering both the existing and the appended messages.
+    /// # Error
+    /// This function errors iff:
+    /// * `schema` does not match the schema of the existing file
+    /// * any of the conditions of [`FileWriter::try_from_file`] are met
+    pub fn try_from_existing(
+        mut writer: R,
+        schema: &ArrowSchema,
+        options: WriteOptions,
+    ) -> PolarsResult<FileWriter<R>> {
+        writer.seek(SeekFrom::Start(0))?;
+        let metadata = read::read_file_metadata(&mut writer)?;
+        if metadata.schema.as_ref() != schema {
+            polars_bail!(SchemaMismatch: "cannot append to an IPC file with a different schema");
+        }
+        Self::try_from_file(writer, metadata, options)
+    }
 }
@@ -36,6 +36,7 @@ pub use arrow_format as format;
 const ARROW_MAGIC_V1: [u8; 4] = [b'F', b'E', b'A', b'1'];
 const ARROW_MAGIC_V2: [u8; 6] = [b'A', b'R', b'R', b'O', b'W', b'1'];
 pub(crate) const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+const MULTI_TABLE_MAGIC: [u8; 8] = *b"ARROWMT1";
 
 /// Struct containing `dictionary_id` and nested `IpcField`, allowing users
 /// to specify the dictionary ids of the IPC fields when writing to IPC.
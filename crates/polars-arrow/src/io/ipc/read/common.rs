@@ -2,13 +2,14 @@ use std::collections::VecDeque;
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
-use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::Dictionaries;
 use super::deserialize::{read, skip};
 use crate::array::*;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::{ArrowDataType, ArrowSchema, Field};
 use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
@@ -266,7 +267,9 @@ pub(crate) fn first_dict_field<'a>(
 }
 
 /// Reads a dictionary from the reader,
-/// updating `dictionaries` with the resulting dictionary
+/// updating `dictionaries` with the resulting dictionary.
+///
+/// The values of a delta dictionary batch are appended to the dictionary that was read before.
 #[allow(clippy::too_many_arguments)]
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
@@ -278,16 +281,17 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> PolarsResult<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        polars_bail!(ComputeError: "delta dictionary batches not supported")
-    }
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferId(err)))?;
+    polars_ensure!(
+        !is_delta || dictionaries.contains_key(&id),
+        ComputeError: "delta dictionary batch for dictionary {id} is not preceded by a dictionary batch"
+    );
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
     let batch = batch
@@ -326,7 +330,12 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let mut values = chunk.into_arrays().pop().unwrap();
+    if is_delta {
+        let last = dictionaries.get(&id).unwrap();
+        values = concatenate(&[last.as_ref(), values.as_ref()])?;
+    }
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -3,6 +3,10 @@ use std::sync::Arc;
 
 use arrow_format::ipc::FooterRef;
 use arrow_format::ipc::planus::ReadAsRoot;
+#[cfg(feature = "io_ipc_checksum")]
+use crc32c::Crc32cReader;
+#[cfg(feature = "io_ipc_checksum")]
+use polars_error::polars_ensure;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_utils::aliases::{InitHashMaps, PlHashMap};
 
@@ -13,6 +17,9 @@ use super::{Dictionaries, OutOfSpecKind, SendableIterator};
 use crate::array::Array;
 use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
+#[cfg(feature = "io_ipc_checksum")]
+use crate::io::ipc::write::IPC_CHECKSUM_KEY;
+use crate::io::ipc::write::SchemaEvolution;
 use crate::record_batch::RecordBatchT;
 
 /// Metadata of an Arrow IPC file, written in the footer of the file.
@@ -39,6 +46,18 @@ pub struct FileMetadata {
     pub size: u64,
 }
 
+impl FileMetadata {
+    /// The schema versioning information that was written with
+    /// [`FileWriter::set_schema_evolution`](crate::io::ipc::write::FileWriter::set_schema_evolution),
+    /// if any.
+    pub fn schema_evolution(&self) -> PolarsResult<Option<SchemaEvolution>> {
+        match &self.custom_schema_metadata {
+            Some(metadata) => SchemaEvolution::from_metadata(metadata),
+            None => Ok(None),
+        }
+    }
+}
+
 /// Read the row count by summing the length of the of the record batches
 pub fn get_row_count<R: Read + Seek>(reader: &mut R) -> PolarsResult<i64> {
     let (_, footer_len) = read_footer_len(reader)?;
@@ -277,6 +296,40 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMe
     deserialize_footer(&serialized_footer, end - start)
 }
 
+/// Verifies the CRC32C checksum that is stored in the footer of an Arrow IPC file written with
+/// [`WriteOptions::write_checksum`](crate::io::ipc::write::WriteOptions::write_checksum).
+///
+/// The checksum is recomputed over all bytes that precede the footer. Errors if the file has no
+/// checksum or if the recomputed checksum doesn't match the stored one.
+#[cfg(feature = "io_ipc_checksum")]
+pub fn verify_ipc_checksum<R: Read + Seek>(reader: &mut R) -> PolarsResult<()> {
+    let start = reader.stream_position()?;
+    let (end, footer_len) = read_footer_len(reader)?;
+    let serialized_footer = read_footer(reader, footer_len)?;
+    let footer = deserialize_footer_ref(&serialized_footer)?;
+
+    let expected = footer
+        .custom_metadata()
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferFooter(err)))?
+        .into_iter()
+        .flatten()
+        .filter_map(|kv| kv.ok())
+        .find(|kv| kv.key().ok().flatten() == Some(IPC_CHECKSUM_KEY))
+        .and_then(|kv| kv.value().ok().flatten()?.parse::<u32>().ok())
+        .ok_or_else(|| polars_err!(ComputeError: "the IPC file has no checksum"))?;
+
+    let length = end - start - 10 - footer_len as u64;
+    reader.seek(SeekFrom::Start(start))?;
+    let mut reader = Crc32cReader::new(reader.take(length));
+    std::io::copy(&mut reader, &mut std::io::sink())?;
+    let checksum = reader.crc32c();
+    polars_ensure!(
+        checksum == expected,
+        ComputeError: "IPC checksum mismatch: the file's checksum is {expected}, but its contents have checksum {checksum}"
+    );
+    Ok(())
+}
+
 pub(crate) fn get_record_batch(
     message: arrow_format::ipc::MessageRef,
 ) -> PolarsResult<arrow_format::ipc::RecordBatchRef> {
@@ -318,7 +371,7 @@ fn get_message_from_block_offset<'a, R: Read + Seek>(
         .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))
 }
 
-pub(super) fn get_message_from_block<'a, R: Read + Seek>(
+pub(crate) fn get_message_from_block<'a, R: Read + Seek>(
     reader: &mut R,
     block: &arrow_format::ipc::Block,
     message_scratch: &'a mut Vec<u8>,
@@ -13,6 +13,7 @@ mod error;
 pub(crate) mod file;
 #[cfg(feature = "io_flight")]
 mod flight;
+mod multi_table;
 mod read_basic;
 mod reader;
 mod schema;
@@ -21,10 +22,13 @@ mod stream;
 pub(crate) use common::first_dict_field;
 pub use common::{ProjectionInfo, prepare_projection};
 pub use error::OutOfSpecKind;
+#[cfg(feature = "io_ipc_checksum")]
+pub use file::verify_ipc_checksum;
 pub use file::{
     FileMetadata, deserialize_footer, get_row_count, get_row_count_from_blocks, read_batch,
     read_file_dictionaries, read_file_metadata,
 };
+pub use multi_table::{MultiTableIndex, TableReader, read_multi_table_index};
 use polars_utils::aliases::PlHashMap;
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
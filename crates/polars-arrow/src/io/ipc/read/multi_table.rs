@@ -0,0 +1,150 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::super::MULTI_TABLE_MAGIC;
+
+/// The names and byte ranges of the tables written by a
+/// [`MultiTableWriter`](crate::io::ipc::write::MultiTableWriter), read from its manifest.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultiTableIndex {
+    /// The tables in the order they were written
+    pub tables: Vec<(PlSmallStr, Range<u64>)>,
+}
+
+impl MultiTableIndex {
+    /// The byte range of the table `name`, if any.
+    pub fn get(&self, name: &str) -> Option<Range<u64>> {
+        self.tables
+            .iter()
+            .find(|(table, _)| table == name)
+            .map(|(_, range)| range.clone())
+    }
+
+    /// Opens the table `name` of `reader`, which can then be read like an IPC file, e.g. with
+    /// [`read_file_metadata`](super::read_file_metadata) and [`FileReader`](super::FileReader).
+    pub fn open_table<R: Read + Seek>(
+        &self,
+        reader: R,
+        name: &str,
+    ) -> PolarsResult<TableReader<R>> {
+        let Some(range) = self.get(name) else {
+            polars_bail!(ComputeError: "no table named '{}' in the multi-table IPC file", name)
+        };
+        TableReader::try_new(reader, range)
+    }
+}
+
+/// Reads the manifest of a file written by a
+/// [`MultiTableWriter`](crate::io::ipc::write::MultiTableWriter).
+pub fn read_multi_table_index<R: Read + Seek>(reader: &mut R) -> PolarsResult<MultiTableIndex> {
+    let end = reader.seek(SeekFrom::End(0))?;
+    polars_ensure!(
+        end >= 16,
+        oos = "a multi-table IPC file must be at least 16 bytes long"
+    );
+    reader.seek(SeekFrom::End(-16))?;
+    let mut trailer = [0; 16];
+    reader.read_exact(&mut trailer)?;
+    polars_ensure!(
+        trailer[8..] == MULTI_TABLE_MAGIC,
+        oos = "the file does not contain a multi-table IPC manifest"
+    );
+    let manifest_length = u64::from_le_bytes(trailer[..8].try_into().unwrap());
+    polars_ensure!(
+        manifest_length <= end - 16,
+        oos = "the multi-table IPC manifest is longer than the file"
+    );
+    let manifest_start = end - 16 - manifest_length;
+    reader.seek(SeekFrom::Start(manifest_start))?;
+    let mut manifest = vec![0; manifest_length as usize];
+    reader.read_exact(&mut manifest)?;
+
+    let mut manifest = manifest.as_slice();
+    let mut take = |n: usize| {
+        polars_ensure!(
+            manifest.len() >= n,
+            oos = "the multi-table IPC manifest is truncated"
+        );
+        let (bytes, rest) = manifest.split_at(n);
+        manifest = rest;
+        Ok(bytes)
+    };
+    let num_tables = u32::from_le_bytes(take(4)?.try_into().unwrap());
+    let mut tables = vec![];
+    for _ in 0..num_tables {
+        let name_length = u32::from_le_bytes(take(4)?.try_into().unwrap());
+        let name = std::str::from_utf8(take(name_length as usize)?)
+            .map_err(|_| polars_err!(oos = "a multi-table IPC table name is not valid UTF-8"))?;
+        let name = PlSmallStr::from_str(name);
+        let offset = u64::from_le_bytes(take(8)?.try_into().unwrap());
+        let length = u64::from_le_bytes(take(8)?.try_into().unwrap());
+        polars_ensure!(
+            offset
+                .checked_add(length)
+                .is_some_and(|end| end <= manifest_start),
+            oos = format!("the multi-table IPC table '{name}' exceeds the tables of the file")
+        );
+        tables.push((name, offset..offset + length));
+    }
+    Ok(MultiTableIndex { tables })
+}
+
+/// A reader of a single table of a multi-table IPC file, restricted to its byte range, so that
+/// it reads like a standalone IPC file.
+pub struct TableReader<R> {
+    inner: R,
+    range: Range<u64>,
+    /// The position relative to the start of the table
+    position: u64,
+}
+
+impl<R: Read + Seek> TableReader<R> {
+    /// Creates a [`TableReader`] of the bytes of `reader` in `range`.
+    pub fn try_new(mut inner: R, range: Range<u64>) -> PolarsResult<Self> {
+        inner.seek(SeekFrom::Start(range.start))?;
+        Ok(Self {
+            inner,
+            range,
+            position: 0,
+        })
+    }
+
+    /// Consumes itself into the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for TableReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = (self.range.end - self.range.start).saturating_sub(self.position);
+        let length = buf.len().min(remaining.try_into().unwrap_or(usize::MAX));
+        let read = self.inner.read(&mut buf[..length])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: Seek> Seek for TableReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let length = self.range.end - self.range.start;
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => length.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        let Some(position) = position else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+        self.inner
+            .seek(SeekFrom::Start(self.range.start + position))?;
+        self.position = position;
+        Ok(position)
+    }
+}
@@ -360,7 +360,10 @@ fn get_dtype(
         Struct(_) => deserialize_struct(field)?,
         Union(union_) => deserialize_union(union_, field)?,
         Map(map) => deserialize_map(map, field)?,
-        RunEndEncoded(_) => todo!(),
+        RunEndEncoded(_) => polars_bail!(
+            nyi = "reading IPC field '{}' of the run-end encoded type, as there is no run-end encoded array",
+            field.name()?.unwrap_or_default()
+        ),
         LargeListView(_) | ListView(_) => todo!(),
     })
 }
@@ -1,12 +1,17 @@
 use std::borrow::{Borrow, Cow};
+use std::sync::Arc;
 
 use arrow_format::ipc;
 use arrow_format::ipc::planus::Builder;
-use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::super::IpcField;
+use super::error::IpcWriteError;
+use super::schema::serialize_custom_metadata;
 use super::{write, write_dictionary};
 use crate::array::*;
+use crate::compute::aggregate::estimated_bytes_size;
 use crate::datatypes::*;
 use crate::io::ipc::endianness::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
@@ -16,28 +21,526 @@ use crate::record_batch::RecordBatchT;
 use crate::types::Index;
 
 /// Compression codec
+///
+/// These are the codecs defined by the Arrow IPC format's `CompressionType`. Note that there is
+/// no raw (unframed) LZ4 codec in the IPC format, unlike in Parquet, so buffers are always LZ4
+/// compressed with framing, which is what all Arrow IPC readers expect.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Compression {
-    /// LZ4 (framed)
+    /// LZ4 (framed), written as `CompressionType::LZ4_FRAME`
     LZ4,
     /// ZSTD
     ZSTD,
 }
 
+impl Compression {
+    /// The (inclusive) range of compression levels accepted by this codec.
+    pub fn level_range(&self) -> (i32, i32) {
+        match self {
+            // LZ4 frame levels; 0 selects the fast mode, 3 and above select LZ4HC.
+            Compression::LZ4 => (0, 12),
+            // `ZSTD_minCLevel()..=ZSTD_maxCLevel()`
+            Compression::ZSTD => (-(1 << 17), 22),
+        }
+    }
+
+    /// An upper bound of the size of `len` bytes compressed with this codec, including the
+    /// framing written by the codec.
+    pub fn max_compressed_len(&self, len: usize) -> usize {
+        match self {
+            // Incompressible 64 KiB blocks are stored as is, with a 4 byte block header. The
+            // frame header, end mark and content checksum take at most 27 bytes.
+            Compression::LZ4 => len + 4 * (len / (64 << 10) + 1) + 27,
+            // `ZSTD_COMPRESSBOUND`, with some slack for the streaming encoder.
+            Compression::ZSTD => {
+                let small = if len < (128 << 10) {
+                    ((128 << 10) - len) >> 11
+                } else {
+                    0
+                };
+                len + (len >> 8) + small + 32
+            },
+        }
+    }
+}
+
+/// A compression codec together with the level at which it is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompressionOptions {
+    /// The codec used to compress the buffers.
+    pub codec: Compression,
+    /// The compression level. `None` selects the codec's default level.
+    pub level: Option<i32>,
+}
+
+/// Schema metadata key under which the compression level of a written IPC file is stored.
+pub const IPC_COMPRESSION_LEVEL_KEY: &str = "_PL_IPC_COMPRESSION_LEVEL";
+
+/// Footer metadata key under which the CRC32C checksum of a written IPC file is stored, see
+/// [`WriteOptions::write_checksum`].
+pub const IPC_CHECKSUM_KEY: &str = "_PL_IPC_CRC32C";
+
+/// Schema metadata key under which [`SchemaEvolution::fingerprint`] is stored.
+pub const IPC_SCHEMA_FINGERPRINT_KEY: &str = "_PL_IPC_SCHEMA_FINGERPRINT";
+
+/// Schema metadata key under which [`SchemaEvolution::parent`] is stored.
+pub const IPC_SCHEMA_PARENT_KEY: &str = "_PL_IPC_SCHEMA_PARENT";
+
+/// Schema metadata key under which the dictionary mode of a written IPC file or stream is stored
+/// if [`WriteOptions::write_dictionary_mode`] is set, see [`DictionaryTracker::dictionary_mode`].
+pub const IPC_DICTIONARY_MODE_KEY: &str = "_PL_IPC_DICTIONARY_MODE";
+
+/// The key of the footer metadata under which the [`FileWriter`](super::FileWriter) stores
+/// per-column statistics if [`WriteOptions::collect_statistics`] is set.
+///
+/// The statistics are stored as a JSON object of the form
+/// `{"num_rows": 3, "columns": [{"name": "a", "null_count": 1, "min": 1, "max": 2,
+/// "distinct_count": 2}]}`, with a column object per field of the schema, in order.
+/// `min` and `max` are JSON booleans, numbers or strings, or `null` if they are unknown, which
+/// is the case for data types other than booleans, integers of at most 64 bits, floats and
+/// strings, and for columns without non-null values. NaNs are ignored by `min` and `max`.
+/// `distinct_count` is an approximation of the number of distinct non-null values, or `null`
+/// for data types other than the above and binary.
+pub const IPC_STATISTICS_KEY: &str = "_PL_IPC_STATISTICS";
+
+/// The boundary in bytes that buffers are padded to if [`WriteOptions::page_align_buffers`] is set.
+pub const IPC_PAGE_SIZE: usize = 4096;
+
+/// Versioning information of the schema of an IPC file, so that readers can detect schema
+/// evolution.
+///
+/// It is written to the schema metadata of the footer with
+/// [`FileWriter::set_schema_evolution`](super::FileWriter::set_schema_evolution), and read back
+/// with [`FileMetadata::schema_evolution`](crate::io::ipc::read::FileMetadata::schema_evolution).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemaEvolution {
+    /// A fingerprint identifying the schema.
+    pub fingerprint: u64,
+    /// The fingerprint of the schema this schema evolved from, if any.
+    pub parent: Option<u64>,
+}
+
+impl SchemaEvolution {
+    /// Adds the reserved keys of this schema evolution to `metadata`.
+    pub(crate) fn write_metadata(&self, metadata: &mut Metadata) {
+        metadata.insert(
+            PlSmallStr::from_static(IPC_SCHEMA_FINGERPRINT_KEY),
+            PlSmallStr::from(self.fingerprint.to_string()),
+        );
+        if let Some(parent) = self.parent {
+            metadata.insert(
+                PlSmallStr::from_static(IPC_SCHEMA_PARENT_KEY),
+                PlSmallStr::from(parent.to_string()),
+            );
+        }
+    }
+
+    /// Reads a schema evolution from the reserved keys of `metadata`. Returns `None` if
+    /// `metadata` has no schema fingerprint.
+    pub fn from_metadata(metadata: &Metadata) -> PolarsResult<Option<Self>> {
+        let parse = |key: &str| {
+            metadata
+                .get(key)
+                .map(|value| {
+                    value.parse::<u64>().map_err(|_| {
+                        polars_err!(ComputeError: "invalid value '{}' for IPC schema metadata key '{}'", value, key)
+                    })
+                })
+                .transpose()
+        };
+        let Some(fingerprint) = parse(IPC_SCHEMA_FINGERPRINT_KEY)? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            fingerprint,
+            parent: parse(IPC_SCHEMA_PARENT_KEY)?,
+        }))
+    }
+}
+
+/// A rayon thread pool to compress the arrays of record batches on, see
+/// [`WriteOptions::compression_thread_pool`].
+///
+/// Thread pools are compared and hashed by their address.
+#[cfg(feature = "io_ipc_compression")]
+#[derive(Debug, Clone, Copy)]
+pub struct CompressionThreadPool(pub &'static rayon::ThreadPool);
+
+#[cfg(feature = "io_ipc_compression")]
+impl PartialEq for CompressionThreadPool {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+#[cfg(feature = "io_ipc_compression")]
+impl Eq for CompressionThreadPool {}
+
+#[cfg(feature = "io_ipc_compression")]
+impl std::hash::Hash for CompressionThreadPool {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self.0, state)
+    }
+}
+
 /// Options declaring the behaviour of writing to IPC
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// The level at which the buffers are compressed. `None` selects the codec's default level.
+    /// Must be within [`Compression::level_range`] of the selected codec.
+    pub compression_level: Option<i32>,
+    /// If set, the [`FileWriter`](super::FileWriter) stages written chunks and concatenates them
+    /// until their estimated size reaches this number of bytes, before encoding them as a single
+    /// record batch. Residual staged rows are written on `finish`. The staged chunks are also
+    /// written early if concatenating them would overflow the 32-bit offsets of a column.
+    pub min_record_batch_size: Option<usize>,
+    /// If set, the [`FileWriter`](super::FileWriter) splits written chunks by rows so that the
+    /// body of every record batch message is at most this number of bytes. A chunk with a row
+    /// that doesn't fit on its own errors without writing any of its record batches.
+    pub max_body_length: Option<usize>,
+    /// Whether the [`FileWriter`](super::FileWriter) may replace a dictionary that was already
+    /// written with a dictionary with different values. Replacements are written as dictionary
+    /// batches with `isDelta=false`.
+    ///
+    /// Note that the Arrow IPC file format only allows a single dictionary per field, so not all
+    /// readers support files that contain dictionary replacements.
+    pub allow_dictionary_replacement: bool,
+    /// Whether a dictionary that extends a dictionary that was already written is written as
+    /// a delta dictionary batch (`isDelta=true`) containing only the newly seen values, instead
+    /// of as a replacement. Defaults to `false`.
+    ///
+    /// Note that not all readers support delta dictionary batches.
+    pub delta_dictionaries: bool,
+    /// The alignment in bytes of the header, message metadata and message bodies. Must be a
+    /// power of two of at least 8; defaults to 8.
+    ///
+    /// Larger alignments, e.g. 64 for aligned SIMD loads, also apply to the buffers within the
+    /// bodies, which are aligned to at least 64 bytes regardless.
+    pub alignment: usize,
+    /// Whether the [`FileWriter`](super::FileWriter) checks that written chunks match the schema
+    /// of the file. Defaults to `true` in debug builds and to `false` in release builds.
+    pub validate_schema: bool,
+    /// Whether the [`FileWriter`](super::FileWriter) computes a CRC32C checksum of all bytes
+    /// that precede the footer and stores it in the footer's custom metadata under
+    /// [`IPC_CHECKSUM_KEY`]. Defaults to `false`, in which case no checksum is computed.
+    ///
+    /// The checksum can be verified with `verify_ipc_checksum`. Computing it requires the
+    /// `io_ipc_checksum` feature; starting a file with a checksum errors without it.
+    pub write_checksum: bool,
+    /// Whether the arrays of a record batch are compressed in parallel, on
+    /// `compression_thread_pool`. The encoded record batch is identical to the one that is
+    /// compressed sequentially. Only has an effect if `compression` is set. Defaults to `false`.
+    pub parallel_compression: bool,
+    /// The thread pool that `parallel_compression` compresses on. Defaults to `None`, in which
+    /// case the current rayon thread pool is used, i.e. the global one unless the caller
+    /// installed another.
+    #[cfg(feature = "io_ipc_compression")]
+    pub compression_thread_pool: Option<CompressionThreadPool>,
+    /// Whether every buffer is padded to a multiple of [`IPC_PAGE_SIZE`] bytes within the
+    /// message body instead of to a multiple of 64 bytes, so that a reader can memory-map the
+    /// buffers without copying them. Defaults to `false`, as it can grow files considerably.
+    ///
+    /// The offsets of the buffers are page-aligned relative to the start of the body; set
+    /// `alignment` to [`IPC_PAGE_SIZE`] as well to also page-align the bodies in the file.
+    pub page_align_buffers: bool,
+    /// If set, the footer is padded to exactly this number of bytes, so that it can later be
+    /// overwritten in place by a footer of at most this size. The padding follows the footer
+    /// flatbuffer and consists of EOS markers. Finishing the file errors if the footer is
+    /// larger. Defaults to `None`, in which case the footer isn't padded.
+    pub footer_padding: Option<usize>,
+    /// Whether the [`FileWriter`](super::FileWriter) collects the minimum, maximum, null count
+    /// and an estimate of the number of distinct values of every column, and stores them as
+    /// JSON in the footer's custom metadata under [`IPC_STATISTICS_KEY`]. Only chunks written
+    /// through `write` are taken into account. Defaults to `false`.
+    pub collect_statistics: bool,
+    /// Whether the [`FileWriter`](super::FileWriter) writes the end-of-stream marker between
+    /// the last message and the footer. Defaults to `true`.
+    ///
+    /// The Arrow specification lays out a file as the streaming format, including its
+    /// end-of-stream marker, followed by the footer, so the default is what the spec
+    /// describes and what the Arrow C++, Java and Rust file writers produce.
+    ///
+    /// - Readers that locate the messages through the footer accept both modes. These are
+    ///   polars' [`FileReader`](crate::io::ipc::read::FileReader), Arrow C++ (and pyarrow's
+    ///   `ipc.open_file`), Arrow Java's `ArrowFileReader` and arrow-rs' `FileReader`.
+    /// - Readers that read the body of the file, i.e. everything after the leading magic, as
+    ///   a stream need the marker, as without it they go on to parse the footer as a message.
+    ///   These are polars' [`StreamReader`](crate::io::ipc::read::StreamReader) and the
+    ///   stream readers of the Arrow implementations.
+    ///
+    /// Disabling it only saves the 8 bytes of the marker, so keep it enabled unless a reader
+    /// is known to reject it.
+    pub write_eos: bool,
+    /// Whether [`FileWriter::write`](super::FileWriter::write) skips chunks without rows
+    /// instead of writing empty record batches. Defaults to `false`.
+    pub skip_empty_batches: bool,
+    /// Whether the schema message declares how dictionaries may change between record batches,
+    /// by storing the [`DictionaryTracker::dictionary_mode`] of the writer in its metadata under
+    /// [`IPC_DICTIONARY_MODE_KEY`]. This is for validators that check the dictionary discipline
+    /// of a file up front. Defaults to `false`.
+    pub write_dictionary_mode: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: None,
+            compression_level: None,
+            min_record_batch_size: None,
+            max_body_length: None,
+            allow_dictionary_replacement: false,
+            delta_dictionaries: false,
+            alignment: 8,
+            validate_schema: cfg!(debug_assertions),
+            write_checksum: false,
+            parallel_compression: false,
+            #[cfg(feature = "io_ipc_compression")]
+            compression_thread_pool: None,
+            page_align_buffers: false,
+            footer_padding: None,
+            collect_statistics: false,
+            write_eos: true,
+            skip_empty_batches: false,
+            write_dictionary_mode: false,
+        }
+    }
+}
+
+/// A builder for [`WriteOptions`].
+///
+/// Options that are not set keep their [default](WriteOptions::default) value. See the fields of
+/// [`WriteOptions`] for the meaning of every option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WriteOptionsBuilder {
+    options: WriteOptions,
+}
+
+impl WriteOptionsBuilder {
+    /// Creates a builder with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether and with which codec the buffers are compressed.
+    pub fn compression(mut self, compression: Option<Compression>) -> Self {
+        self.options.compression = compression;
+        self
+    }
+
+    /// Sets the level at which the buffers are compressed.
+    pub fn compression_level(mut self, compression_level: Option<i32>) -> Self {
+        self.options.compression_level = compression_level;
+        self
+    }
+
+    /// Sets the minimum estimated size in bytes of a written record batch.
+    pub fn min_record_batch_size(mut self, min_record_batch_size: Option<usize>) -> Self {
+        self.options.min_record_batch_size = min_record_batch_size;
+        self
+    }
+
+    /// Sets the maximum body length in bytes of a written record batch.
+    pub fn max_body_length(mut self, max_body_length: Option<usize>) -> Self {
+        self.options.max_body_length = max_body_length;
+        self
+    }
+
+    /// Sets whether dictionaries that were already written may be replaced.
+    pub fn allow_dictionary_replacement(mut self, allow_dictionary_replacement: bool) -> Self {
+        self.options.allow_dictionary_replacement = allow_dictionary_replacement;
+        self
+    }
+
+    /// Sets whether extended dictionaries are written as delta dictionary batches.
+    pub fn delta_dictionaries(mut self, delta_dictionaries: bool) -> Self {
+        self.options.delta_dictionaries = delta_dictionaries;
+        self
+    }
+
+    /// Sets the alignment in bytes of the written messages.
+    pub fn alignment(mut self, alignment: usize) -> Self {
+        self.options.alignment = alignment;
+        self
+    }
+
+    /// Sets whether written chunks are checked against the schema.
+    pub fn validate_schema(mut self, validate_schema: bool) -> Self {
+        self.options.validate_schema = validate_schema;
+        self
+    }
+
+    /// Sets whether a CRC32C checksum is written to the footer.
+    pub fn write_checksum(mut self, write_checksum: bool) -> Self {
+        self.options.write_checksum = write_checksum;
+        self
+    }
+
+    /// Sets whether the arrays of a record batch are compressed in parallel.
+    pub fn parallel_compression(mut self, parallel_compression: bool) -> Self {
+        self.options.parallel_compression = parallel_compression;
+        self
+    }
+
+    /// Sets the thread pool that the arrays of a record batch are compressed on in parallel.
+    #[cfg(feature = "io_ipc_compression")]
+    pub fn compression_thread_pool(
+        mut self,
+        compression_thread_pool: Option<&'static rayon::ThreadPool>,
+    ) -> Self {
+        self.options.compression_thread_pool = compression_thread_pool.map(CompressionThreadPool);
+        self
+    }
+
+    /// Sets whether every buffer is padded to a multiple of [`IPC_PAGE_SIZE`] bytes.
+    pub fn page_align_buffers(mut self, page_align_buffers: bool) -> Self {
+        self.options.page_align_buffers = page_align_buffers;
+        self
+    }
+
+    /// Sets the number of bytes the footer is padded to.
+    pub fn footer_padding(mut self, footer_padding: Option<usize>) -> Self {
+        self.options.footer_padding = footer_padding;
+        self
+    }
+
+    /// Sets whether per-column statistics are written to the footer.
+    pub fn collect_statistics(mut self, collect_statistics: bool) -> Self {
+        self.options.collect_statistics = collect_statistics;
+        self
+    }
+
+    /// Sets whether the end-of-stream marker is written before the footer. See
+    /// [`WriteOptions::write_eos`] for the readers that need it.
+    pub fn write_eos(mut self, write_eos: bool) -> Self {
+        self.options.write_eos = write_eos;
+        self
+    }
+
+    /// Sets whether chunks without rows are skipped.
+    pub fn skip_empty_batches(mut self, skip_empty_batches: bool) -> Self {
+        self.options.skip_empty_batches = skip_empty_batches;
+        self
+    }
+
+    /// Sets whether the dictionary mode is written to the schema message.
+    pub fn write_dictionary_mode(mut self, write_dictionary_mode: bool) -> Self {
+        self.options.write_dictionary_mode = write_dictionary_mode;
+        self
+    }
+
+    /// Returns the configured [`WriteOptions`].
+    pub fn build(self) -> WriteOptions {
+        self.options
+    }
+}
+
+impl WriteOptions {
+    /// Returns a [`WriteOptionsBuilder`] initialized with the default options.
+    pub fn builder() -> WriteOptionsBuilder {
+        WriteOptionsBuilder::new()
+    }
+
+    /// The codec and level that buffers should be compressed with, if any.
+    pub fn compression_options(&self) -> Option<CompressionOptions> {
+        self.compression.map(|codec| CompressionOptions {
+            codec,
+            level: self.compression_level,
+        })
+    }
+
+    /// The number of bytes every buffer in a message body is padded to a multiple of.
+    pub(crate) fn buffer_alignment(&self) -> usize {
+        if self.page_align_buffers {
+            IPC_PAGE_SIZE.max(self.alignment)
+        } else {
+            self.alignment.max(64)
+        }
+    }
+
+    /// Checks that the options are valid.
+    pub fn validate(&self) -> PolarsResult<()> {
+        self.validate_compression_codec()?;
+        self.validate_checksum()?;
+        self.validate_compression_level()?;
+        polars_ensure!(
+            self.alignment.is_power_of_two() && self.alignment >= 8,
+            InvalidOperation: "invalid IPC alignment {}; the alignment must be a power of two of at least 8",
+            self.alignment
+        );
+        Ok(())
+    }
+
+    /// Checks that the selected codec, if any, is compiled in, so that requesting compression
+    /// never silently results in uncompressed output.
+    pub fn validate_compression_codec(&self) -> PolarsResult<()> {
+        #[cfg(not(feature = "io_ipc_compression"))]
+        if let Some(compression) = self.compression {
+            polars_bail!(
+                InvalidOperation: "IPC compression with {:?} was requested, but the crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.",
+                compression
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks that a requested checksum can be computed, so that requesting a checksum never
+    /// silently results in a file without one.
+    pub fn validate_checksum(&self) -> PolarsResult<()> {
+        #[cfg(not(feature = "io_ipc_checksum"))]
+        polars_ensure!(
+            !self.write_checksum,
+            InvalidOperation: "an IPC checksum was requested, but the crate was compiled without IPC checksums. Use `io_ipc_checksum` to write checksums."
+        );
+        Ok(())
+    }
+
+    /// Checks that the configured compression level is valid for the selected codec.
+    pub fn validate_compression_level(&self) -> PolarsResult<()> {
+        let Some(level) = self.compression_level else {
+            return Ok(());
+        };
+        let Some(compression) = self.compression else {
+            polars_bail!(InvalidOperation: "an IPC compression level was given without a compression codec");
+        };
+        let (min, max) = compression.level_range();
+        polars_ensure!(
+            (min..=max).contains(&level),
+            InvalidOperation: "invalid compression level {} for {:?}; expected a level between {} and {}",
+            level, compression, min, max
+        );
+        Ok(())
+    }
+
+    /// Adds the compression level to the given schema-level metadata, so that readers can
+    /// report it.
+    pub(crate) fn schema_metadata<'a>(
+        &self,
+        metadata: Option<&'a Metadata>,
+    ) -> Option<Cow<'a, Metadata>> {
+        let Some(level) = self.compression_options().and_then(|c| c.level) else {
+            return metadata.map(Cow::Borrowed);
+        };
+        let mut metadata = metadata.cloned().unwrap_or_default();
+        metadata.insert(
+            PlSmallStr::from_static(IPC_COMPRESSION_LEVEL_KEY),
+            PlSmallStr::from(level.to_string()),
+        );
+        Some(Cow::Owned(metadata))
+    }
 }
 
 /// Find the dictionary that are new and need to be encoded.
+///
+/// Every dictionary is pushed to `dicts_to_encode` together with its id and whether it is a
+/// delta dictionary, in which case it only holds the values that weren't written before.
 pub fn dictionaries_to_encode(
     field: &IpcField,
     array: &dyn Array,
     dictionary_tracker: &mut DictionaryTracker,
-    dicts_to_encode: &mut Vec<(i64, Box<dyn Array>)>,
+    dicts_to_encode: &mut Vec<(i64, Box<dyn Array>, bool)>,
 ) -> PolarsResult<()> {
     use PhysicalType::*;
     match array.dtype().to_physical_type() {
@@ -47,12 +550,21 @@ pub fn dictionaries_to_encode(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| polars_err!(InvalidOperation: "Dictionaries must have an associated id"))?;
 
-            if dictionary_tracker.insert(dict_id, array)? {
-                dicts_to_encode.push((dict_id, array.to_boxed()));
-            }
-
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
+
+            if let Some(offset) = dictionary_tracker.delta_offset(dict_id, values.as_ref()) {
+                let delta = DictionaryArray::<$T>::try_new(
+                    array.dtype().clone(),
+                    array.keys().clone().sliced(0, 0),
+                    values.sliced(offset, values.len() - offset),
+                )?;
+                dictionary_tracker.dictionaries.insert(dict_id, values.clone());
+                dicts_to_encode.push((dict_id, delta.to_boxed(), true));
+            } else if dictionary_tracker.insert(dict_id, array)? {
+                dicts_to_encode.push((dict_id, array.to_boxed(), false));
+            }
+
             // @Q? Should this not pick fields[0]?
             dictionaries_to_encode(field,
                 values.as_ref(),
@@ -141,7 +653,8 @@ pub fn dictionaries_to_encode(
     }
 }
 
-/// Encode a dictionary array with a certain id.
+/// Encode a dictionary array with a certain id. If `is_delta` is set, the dictionary batch is
+/// marked as a delta of the dictionary that was written before.
 ///
 /// # Panics
 ///
@@ -149,6 +662,7 @@ pub fn dictionaries_to_encode(
 pub fn encode_dictionary(
     dict_id: i64,
     array: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     encoded_dictionaries: &mut Vec<EncodedData>,
 ) -> PolarsResult<()> {
@@ -161,6 +675,7 @@ pub fn encode_dictionary(
         encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
             dict_id,
             array,
+            is_delta,
             options,
             is_native_little_endian(),
         ));
@@ -178,12 +693,35 @@ pub fn encode_new_dictionaries(
 ) -> PolarsResult<()> {
     let mut dicts_to_encode = Vec::new();
     dictionaries_to_encode(field, array, dictionary_tracker, &mut dicts_to_encode)?;
-    for (dict_id, dict_array) in dicts_to_encode {
-        encode_dictionary(dict_id, dict_array.as_ref(), options, encoded_dictionaries)?;
+    for (dict_id, dict_array, is_delta) in dicts_to_encode {
+        encode_dictionary(
+            dict_id,
+            dict_array.as_ref(),
+            is_delta,
+            options,
+            encoded_dictionaries,
+        )?;
     }
     Ok(())
 }
 
+/// Encode `chunk` into its dictionary batches and record batch message, without writing them.
+///
+/// Only the dictionaries that `dictionary_tracker` hasn't seen yet are encoded, so sharing
+/// one tracker allows encoding a chunk once and writing it to several writers:
+///
+/// 1. Derive the IPC fields once, e.g. with
+///    [`default_ipc_fields`](super::default_ipc_fields), and pass the same fields to every
+///    writer, so that they agree on the dictionary ids.
+/// 2. Create a single [`DictionaryTracker`]. Set [`DictionaryTracker::cannot_replace`] if one of
+///    the writers is a [`FileWriter`](super::FileWriter), as the file format doesn't allow
+///    dictionary replacements.
+/// 3. Encode every chunk with this function and hand the result to the `write_encoded` method
+///    of each writer, e.g. [`FileWriter::write_encoded`](super::FileWriter::write_encoded) and
+///    [`StreamWriter::write_encoded`](super::StreamWriter::write_encoded).
+///
+/// All writers must receive every encoded chunk, in the same order, as the dictionary batches
+/// of a chunk are only emitted once.
 pub fn encode_chunk(
     chunk: &RecordBatchT<Box<dyn Array>>,
     fields: &[IpcField],
@@ -196,19 +734,23 @@ pub fn encode_chunk(
         fields,
         dictionary_tracker,
         options,
+        None,
         &mut encoded_message,
     )?;
     Ok((encoded_dictionaries, encoded_message))
 }
 
 // Amortizes `EncodedData` allocation.
+// `custom_metadata` is written to the record batch message only.
 pub fn encode_chunk_amortized(
     chunk: &RecordBatchT<Box<dyn Array>>,
     fields: &[IpcField],
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
+    custom_metadata: Option<&Metadata>,
     encoded_message: &mut EncodedData,
 ) -> PolarsResult<Vec<EncodedData>> {
+    options.validate()?;
     let mut encoded_dictionaries = vec![];
 
     for (field, array) in fields.iter().zip(chunk.as_ref()) {
@@ -220,7 +762,7 @@ pub fn encode_chunk_amortized(
             &mut encoded_dictionaries,
         )?;
     }
-    encode_record_batch(chunk, options, encoded_message);
+    encode_record_batch(chunk, options, custom_metadata, encoded_message);
 
     Ok(encoded_dictionaries)
 }
@@ -329,15 +871,18 @@ pub fn encode_array(
         nodes,
         offset,
         is_native_little_endian(),
-        options.compression,
+        options.compression_options(),
     )
 }
 
 /// Write [`RecordBatchT`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
-/// other for the batch's data
+/// other for the batch's data.
+///
+/// `custom_metadata` is attached to the message of this record batch.
 pub fn encode_record_batch(
     chunk: &RecordBatchT<Box<dyn Array>>,
     options: &WriteOptions,
+    custom_metadata: Option<&Metadata>,
     encoded_message: &mut EncodedData,
 ) {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
@@ -346,16 +891,32 @@ pub fn encode_record_batch(
 
     let mut offset = 0;
     let mut variadic_buffer_counts = vec![];
-    for array in chunk.arrays() {
-        encode_array(
-            array,
+    #[cfg(feature = "io_ipc_compression")]
+    let parallel = options.parallel_compression && options.compression.is_some();
+    #[cfg(not(feature = "io_ipc_compression"))]
+    let parallel = false;
+    if parallel {
+        #[cfg(feature = "io_ipc_compression")]
+        encode_arrays_parallel(
+            chunk.arrays(),
             options,
             &mut variadic_buffer_counts,
             &mut buffers,
             &mut encoded_message.arrow_data,
             &mut nodes,
-            &mut offset,
         );
+    } else {
+        for array in chunk.arrays() {
+            encode_array(
+                array,
+                options,
+                &mut variadic_buffer_counts,
+                &mut buffers,
+                &mut encoded_message.arrow_data,
+                &mut nodes,
+                &mut offset,
+            );
+        }
     }
 
     commit_encoded_arrays(
@@ -364,18 +925,202 @@ pub fn encode_record_batch(
         variadic_buffer_counts,
         buffers,
         nodes,
+        custom_metadata,
         encoded_message,
     );
 }
 
+/// Encodes the record batches of `chunk` into `out`, without its dictionaries, splitting the
+/// chunk by rows so that every body takes at most `max_body_length` bytes. This is how the
+/// [`FileWriter`](super::FileWriter) writes chunks with [`WriteOptions::max_body_length`].
+///
+/// The rows per record batch are picked from the [`estimated_bytes_size`] of the chunk, so a
+/// chunk is only encoded once unless a record batch turns out larger than estimated, in which
+/// case that record batch is halved.
+pub(crate) fn encode_split_record_batches(
+    chunk: &RecordBatchT<Box<dyn Array>>,
+    schema: &ArrowSchemaRef,
+    options: &WriteOptions,
+    max_body_length: usize,
+    custom_metadata: Option<&Metadata>,
+    out: &mut Vec<EncodedData>,
+) -> PolarsResult<()> {
+    let estimated_bytes = chunk
+        .arrays()
+        .iter()
+        .map(|array| estimated_bytes_size(array.as_ref()))
+        .sum::<usize>();
+    if estimated_bytes <= max_body_length {
+        return encode_record_batch_halving(
+            chunk,
+            schema,
+            options,
+            max_body_length,
+            custom_metadata,
+            out,
+        );
+    }
+
+    let rows_per_batch =
+        ((chunk.len() as u128 * max_body_length as u128) / estimated_bytes as u128) as usize;
+    let rows_per_batch = rows_per_batch.max(1);
+    for offset in (0..chunk.len()).step_by(rows_per_batch) {
+        let length = rows_per_batch.min(chunk.len() - offset);
+        let chunk = slice_chunk(chunk, schema, offset, length);
+        encode_record_batch_halving(
+            &chunk,
+            schema,
+            options,
+            max_body_length,
+            custom_metadata,
+            out,
+        )?;
+    }
+    Ok(())
+}
+
+/// Encodes the record batch of `chunk` into `out`, halving it until every body takes at most
+/// `max_body_length` bytes.
+fn encode_record_batch_halving(
+    chunk: &RecordBatchT<Box<dyn Array>>,
+    schema: &ArrowSchemaRef,
+    options: &WriteOptions,
+    max_body_length: usize,
+    custom_metadata: Option<&Metadata>,
+    out: &mut Vec<EncodedData>,
+) -> PolarsResult<()> {
+    let mut encoded_message = EncodedData::default();
+    encode_record_batch(chunk, options, custom_metadata, &mut encoded_message);
+
+    let arrow_data_len = encoded_message.arrow_data.len();
+    let body_length = arrow_data_len + pad_to_alignment(arrow_data_len, options.alignment);
+    if body_length <= max_body_length {
+        out.push(encoded_message);
+        return Ok(());
+    }
+    if chunk.len() <= 1 {
+        polars_bail!(
+            ComputeError: "IPC record batch body of {} bytes for a single row exceeds the maximum body length of {} bytes",
+            body_length, max_body_length
+        );
+    }
+
+    let mid = chunk.len() / 2;
+    for (offset, length) in [(0, mid), (mid, chunk.len() - mid)] {
+        let chunk = slice_chunk(chunk, schema, offset, length);
+        encode_record_batch_halving(
+            &chunk,
+            schema,
+            options,
+            max_body_length,
+            custom_metadata,
+            out,
+        )?;
+    }
+    Ok(())
+}
+
+fn slice_chunk(
+    chunk: &RecordBatchT<Box<dyn Array>>,
+    schema: &ArrowSchemaRef,
+    offset: usize,
+    length: usize,
+) -> RecordBatchT<Box<dyn Array>> {
+    let arrays = chunk
+        .arrays()
+        .iter()
+        .map(|array| array.sliced(offset, length))
+        .collect();
+    RecordBatchT::new(length, schema.clone(), arrays)
+}
+
+/// Like calling [`encode_array`] for every array in turn, but the arrays are encoded, and thus
+/// compressed, in parallel on [`WriteOptions::compression_thread_pool`].
+///
+/// Every array is encoded into its own body, which are concatenated afterwards. As every buffer
+/// is padded to 64 bytes, shifting the buffer offsets by the length of the preceding bodies
+/// gives the same result as encoding the arrays sequentially.
+#[cfg(feature = "io_ipc_compression")]
+fn encode_arrays_parallel(
+    arrays: &[Box<dyn Array>],
+    options: &WriteOptions,
+    variadic_buffer_counts: &mut Vec<i64>,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    nodes: &mut Vec<ipc::FieldNode>,
+) {
+    use rayon::prelude::*;
+
+    let encode = || {
+        arrays
+            .par_iter()
+            .map(|array| {
+                let mut variadic_buffer_counts = vec![];
+                let mut buffers = vec![];
+                let mut arrow_data = vec![];
+                let mut nodes = vec![];
+                encode_array(
+                    array,
+                    options,
+                    &mut variadic_buffer_counts,
+                    &mut buffers,
+                    &mut arrow_data,
+                    &mut nodes,
+                    &mut 0,
+                );
+                (variadic_buffer_counts, buffers, arrow_data, nodes)
+            })
+            .collect::<Vec<_>>()
+    };
+    let encoded = match options.compression_thread_pool {
+        Some(CompressionThreadPool(pool)) => pool.install(encode),
+        None => encode(),
+    };
+
+    for (array_variadic_buffer_counts, array_buffers, array_data, array_nodes) in encoded {
+        let shift = arrow_data.len() as i64;
+        variadic_buffer_counts.extend(array_variadic_buffer_counts);
+        buffers.extend(array_buffers.into_iter().map(|buffer| ipc::Buffer {
+            offset: buffer.offset + shift,
+            length: buffer.length,
+        }));
+        arrow_data.extend(array_data);
+        nodes.extend(array_nodes);
+    }
+}
+
+/// Moves every buffer in `arrow_data` to an offset that is a multiple of `alignment` and pads it
+/// with zeros to a multiple of `alignment` bytes, updating the offsets of `buffers`.
+fn align_buffers(buffers: &mut [ipc::Buffer], arrow_data: &mut Vec<u8>, alignment: usize) {
+    let mut aligned = Vec::with_capacity(arrow_data.len());
+    for buffer in buffers {
+        let start = buffer.offset as usize;
+        let length = buffer.length as usize;
+        buffer.offset = aligned.len() as i64;
+        aligned.extend_from_slice(&arrow_data[start..start + length]);
+        aligned.resize(aligned.len() + pad_to_alignment(length, alignment), 0);
+    }
+    *arrow_data = aligned;
+}
+
 pub fn commit_encoded_arrays(
     array_len: usize,
     options: &WriteOptions,
     variadic_buffer_counts: Vec<i64>,
-    buffers: Vec<ipc::Buffer>,
+    mut buffers: Vec<ipc::Buffer>,
     nodes: Vec<ipc::FieldNode>,
+    custom_metadata: Option<&Metadata>,
     encoded_message: &mut EncodedData,
 ) {
+    // The buffers are serialized with 64-byte alignment.
+    if options.buffer_alignment() > 64 {
+        align_buffers(
+            &mut buffers,
+            &mut encoded_message.arrow_data,
+            options.buffer_alignment(),
+        );
+    }
+
     let variadic_buffer_counts = if variadic_buffer_counts.is_empty() {
         None
     } else {
@@ -396,7 +1141,7 @@ pub fn commit_encoded_arrays(
             },
         ))),
         body_length: encoded_message.arrow_data.len() as i64,
-        custom_metadata: None,
+        custom_metadata: custom_metadata.and_then(serialize_custom_metadata),
     };
 
     let mut builder = Builder::new();
@@ -409,11 +1154,34 @@ pub fn commit_encoded_arrays(
 fn dictionary_batch_to_bytes<K: DictionaryKey>(
     dict_id: i64,
     array: &DictionaryArray<K>,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
-    let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
+    dictionary_batch_to_bytes_impl(
+        dict_id,
+        array,
+        is_delta,
+        options,
+        options.compression_options(),
+        is_little_endian,
+        &mut buffers,
+    )
+}
+
+/// Like [`dictionary_batch_to_bytes`], but the buffers are compressed with `compression`
+/// regardless of the compression declared in the message, and are returned in `buffers`.
+fn dictionary_batch_to_bytes_impl<K: DictionaryKey>(
+    dict_id: i64,
+    array: &DictionaryArray<K>,
+    is_delta: bool,
+    options: &WriteOptions,
+    compression: Option<CompressionOptions>,
+    is_little_endian: bool,
+    buffers: &mut Vec<arrow_format::ipc::Buffer>,
+) -> EncodedData {
+    let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
     let mut variadic_buffer_counts = vec![];
     set_variadic_buffer_counts(&mut variadic_buffer_counts, array.values().as_ref());
@@ -426,14 +1194,17 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
 
     let length = write_dictionary(
         array,
-        &mut buffers,
+        buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
-        options.compression,
+        compression,
         false,
     );
+    if options.buffer_alignment() > 64 {
+        align_buffers(buffers, &mut arrow_data, options.buffer_alignment());
+    }
 
     let compression = serialize_compression(options.compression);
 
@@ -445,11 +1216,11 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                 data: Some(Box::new(arrow_format::ipc::RecordBatch {
                     length: length as i64,
                     nodes: Some(nodes),
-                    buffers: Some(buffers),
+                    buffers: Some(buffers.clone()),
                     compression,
                     variadic_buffer_counts,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -465,15 +1236,264 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// The number of bytes that writing a message with `ipc_message` metadata and a body of
+/// `body_length` bytes takes, see [`write_message`](super::common_sync::write_message).
+fn message_size(ipc_message: &[u8], body_length: usize, alignment: usize) -> usize {
+    let prefix_size = 8;
+    let aligned_size = ipc_message.len() + prefix_size;
+    let aligned_size = aligned_size + pad_to_alignment(aligned_size, alignment);
+    aligned_size + body_length + pad_to_alignment(body_length, alignment)
+}
+
+/// How [`estimate_ipc_size_with`] estimates the size of compressed buffers, which it doesn't
+/// actually compress.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CompressedSizeEstimate {
+    /// Every buffer takes [`Compression::max_compressed_len`] bytes, so the estimate is a
+    /// conservative upper bound.
+    #[default]
+    WorstCase,
+    /// Every buffer takes this fraction of its uncompressed length, e.g. `0.25` for data that
+    /// compresses fourfold, but at most [`Compression::max_compressed_len`] bytes. The estimate
+    /// is only as close as the ratio is to the actual compression ratio of the data.
+    Ratio(f64),
+}
+
+impl CompressedSizeEstimate {
+    /// The estimated size of `len` bytes compressed with `codec`.
+    fn compressed_len(&self, codec: Compression, len: usize) -> usize {
+        let max_compressed_len = codec.max_compressed_len(len);
+        match *self {
+            Self::WorstCase => max_compressed_len,
+            Self::Ratio(ratio) => ((len as f64 * ratio).ceil() as usize).min(max_compressed_len),
+        }
+    }
+}
+
+/// Estimates the number of bytes that writing `chunk` adds to an IPC file, without writing it.
+///
+/// The estimate covers the record batch messages and the dictionary batches of the
+/// dictionary-encoded fields of `chunk`, assuming none of its dictionaries were written before.
+/// With [`WriteOptions::max_body_length`] the chunk is split into record batches the way the
+/// [`FileWriter`](super::FileWriter) splits it.
+///
+/// Without compression the estimate is exact. With compression the buffers are not actually
+/// compressed; every buffer is assumed to take [`Compression::max_compressed_len`] bytes instead,
+/// so the estimate is a conservative upper bound. See [`estimate_ipc_size_with`] to estimate
+/// compressed buffers by a compression ratio instead.
+///
+/// As the split of a compressed chunk depends on how well its buffers compress, the size of a
+/// compressed chunk can't be estimated with `max_body_length`, which errors.
+pub fn estimate_ipc_size(
+    chunk: &RecordBatchT<Box<dyn Array>>,
+    ipc_fields: &[IpcField],
+    options: &WriteOptions,
+) -> PolarsResult<usize> {
+    estimate_ipc_size_with(
+        chunk,
+        ipc_fields,
+        options,
+        CompressedSizeEstimate::WorstCase,
+    )
+}
+
+/// Like [`estimate_ipc_size`], but with compressed buffers estimated by `compressed_size`.
+pub fn estimate_ipc_size_with(
+    chunk: &RecordBatchT<Box<dyn Array>>,
+    ipc_fields: &[IpcField],
+    options: &WriteOptions,
+    compressed_size: CompressedSizeEstimate,
+) -> PolarsResult<usize> {
+    options.validate()?;
+    if let CompressedSizeEstimate::Ratio(ratio) = compressed_size {
+        polars_ensure!(
+            ratio.is_finite() && ratio > 0.0,
+            InvalidOperation: "the estimated compression ratio must be positive, got {}", ratio
+        );
+    }
+    polars_ensure!(
+        options.max_body_length.is_none() || options.compression.is_none(),
+        InvalidOperation: "the size of compressed IPC record batches can't be estimated with a maximum body length, as their split depends on how well they compress"
+    );
+
+    let buffer_alignment = options.buffer_alignment();
+    let body_length = |buffers: &[ipc::Buffer], arrow_data: &[u8]| match options.compression {
+        None if !options.page_align_buffers => arrow_data.len(),
+        None => buffers
+            .iter()
+            .map(|buffer| {
+                let length = buffer.length as usize;
+                length + pad_to_alignment(length, buffer_alignment)
+            })
+            .sum(),
+        Some(codec) => buffers
+            .iter()
+            .map(|buffer| {
+                // The compressed buffer is prefixed by its uncompressed length.
+                let length = 8 + compressed_size.compressed_len(codec, buffer.length as usize);
+                length + pad_to_alignment(length, buffer_alignment)
+            })
+            .sum(),
+    };
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+        allow_delta: false,
+        replacements: 0,
+    };
+    let mut dicts_to_encode = vec![];
+    for (field, array) in ipc_fields.iter().zip(chunk.arrays()) {
+        dictionaries_to_encode(
+            field,
+            array.as_ref(),
+            &mut dictionary_tracker,
+            &mut dicts_to_encode,
+        )?;
+    }
+
+    let mut size = 0;
+    for (dict_id, array, is_delta) in dicts_to_encode {
+        let PhysicalType::Dictionary(key_type) = array.dtype().to_physical_type() else {
+            unreachable!()
+        };
+        match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let mut buffers = vec![];
+            let encoded = dictionary_batch_to_bytes_impl::<$T>(
+                dict_id,
+                array,
+                is_delta,
+                options,
+                None,
+                is_native_little_endian(),
+                &mut buffers,
+            );
+            size += message_size(
+                &encoded.ipc_message,
+                body_length(&buffers, &encoded.arrow_data),
+                options.alignment,
+            );
+        });
+    }
+
+    // The split only depends on the encoded bodies, which are not compressed here.
+    if let Some(max_body_length) = options.max_body_length {
+        let schema = Arc::new(chunk.schema().clone());
+        let mut encoded_messages = vec![];
+        encode_split_record_batches(
+            chunk,
+            &schema,
+            options,
+            max_body_length,
+            None,
+            &mut encoded_messages,
+        )?;
+        for encoded_message in &encoded_messages {
+            size += message_size(
+                &encoded_message.ipc_message,
+                encoded_message.arrow_data.len(),
+                options.alignment,
+            );
+        }
+        return Ok(size);
+    }
+
+    let uncompressed = WriteOptions {
+        compression: None,
+        ..*options
+    };
+    let mut nodes = vec![];
+    let mut buffers = vec![];
+    let mut encoded_message = EncodedData::default();
+    let mut offset = 0;
+    let mut variadic_buffer_counts = vec![];
+    for array in chunk.arrays() {
+        encode_array(
+            array,
+            &uncompressed,
+            &mut variadic_buffer_counts,
+            &mut buffers,
+            &mut encoded_message.arrow_data,
+            &mut nodes,
+            &mut offset,
+        );
+    }
+    let body_length = body_length(&buffers, &encoded_message.arrow_data);
+    // The metadata declares the compression of `options`, its size doesn't depend on the
+    // values of the buffers.
+    commit_encoded_arrays(
+        chunk.len(),
+        options,
+        variadic_buffer_counts,
+        buffers,
+        nodes,
+        None,
+        &mut encoded_message,
+    );
+    size += message_size(&encoded_message.ipc_message, body_length, options.alignment);
+
+    Ok(size)
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
-/// isn't allowed in the `FileWriter`.
+/// isn't allowed in the `FileWriter` unless [`WriteOptions::allow_dictionary_replacement`] is set.
+///
+/// A tracker can be shared by several writers through [`encode_chunk`].
 pub struct DictionaryTracker {
     pub dictionaries: Dictionaries,
     pub cannot_replace: bool,
+    /// Whether a dictionary that extends the dictionary that was written before may be written
+    /// as a delta, see [`WriteOptions::delta_dictionaries`].
+    pub allow_delta: bool,
+    /// The number of times a dictionary was replaced by one with different values.
+    pub replacements: usize,
 }
 
 impl DictionaryTracker {
+    /// How dictionaries may change between record batches with this tracker:
+    ///
+    /// * `"none"`: a dictionary is written once and never changes.
+    /// * `"delta"`: a dictionary may only be extended with delta dictionary batches.
+    /// * `"replace"`: a dictionary may be replaced, and extended with delta dictionary batches if
+    ///   [`DictionaryTracker::allow_delta`] is set.
+    pub fn dictionary_mode(&self) -> &'static str {
+        match (self.cannot_replace, self.allow_delta) {
+            (true, false) => "none",
+            (true, true) => "delta",
+            (false, _) => "replace",
+        }
+    }
+
+    /// Adds the [`DictionaryTracker::dictionary_mode`] of this tracker to `metadata`, under
+    /// [`IPC_DICTIONARY_MODE_KEY`].
+    pub(crate) fn write_dictionary_mode(&self, metadata: &mut Metadata) {
+        metadata.insert(
+            PlSmallStr::from_static(IPC_DICTIONARY_MODE_KEY),
+            PlSmallStr::from_static(self.dictionary_mode()),
+        );
+    }
+
+    /// Forgets the dictionaries that were written and the number of replacements, e.g. to write
+    /// another file with the same tracker. The configuration of the tracker is kept.
+    pub fn reset(&mut self) {
+        self.dictionaries.clear();
+        self.replacements = 0;
+    }
+
+    /// If delta dictionaries are allowed and `values` extends the values of the dictionary with
+    /// the given ID that was written before, returns the number of values that were written
+    /// before. Only the values after that offset need to be written as a delta dictionary.
+    pub fn delta_offset(&self, dict_id: i64, values: &dyn Array) -> Option<usize> {
+        if !self.allow_delta {
+            return None;
+        }
+        let last = self.dictionaries.get(&dict_id)?;
+        (values.len() > last.len() && values.sliced(0, last.len()).as_ref() == last.as_ref())
+            .then_some(last.len())
+    }
+
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
@@ -503,12 +1523,9 @@ impl DictionaryTracker {
                 // Same dictionary values => no need to emit it again
                 return Ok(false);
             } else if self.cannot_replace {
-                polars_bail!(InvalidOperation:
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                );
+                return Err(IpcWriteError::DictionaryReplacementForbidden { dict_id }.into());
             }
+            self.replacements += 1;
         };
 
         self.dictionaries.insert(dict_id, values.clone());
@@ -531,6 +1548,13 @@ pub(crate) fn pad_to_64(len: usize) -> usize {
     ((len + 63) & !63) - len
 }
 
+/// Calculate an alignment boundary and return the number of bytes needed to pad to it.
+/// `alignment` must be a power of two.
+#[inline]
+pub(crate) fn pad_to_alignment(len: usize, alignment: usize) -> usize {
+    ((len + alignment - 1) & !(alignment - 1)) - len
+}
+
 /// An array [`RecordBatchT`] with optional accompanying IPC fields.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Record<'a> {
@@ -3,21 +3,24 @@ use std::io::Write;
 use polars_error::PolarsResult;
 
 use super::super::CONTINUATION_MARKER;
-use super::common::{EncodedData, pad_to_64};
+use super::common::{EncodedData, pad_to_alignment};
 
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
+///
+/// The metadata and the body are padded to a multiple of `alignment`, which must be a power
+/// of two.
 pub fn write_message<W: Write>(
     writer: &mut W,
     encoded: &EncodedData,
+    alignment: usize,
 ) -> PolarsResult<(usize, usize)> {
     let arrow_data_len = encoded.arrow_data.len();
 
-    let a = 8 - 1;
     let buffer = &encoded.ipc_message;
     let flatbuf_size = buffer.len();
     let prefix_size = 8;
-    let aligned_size = (flatbuf_size + prefix_size + a) & !a;
-    let padding_bytes = aligned_size - flatbuf_size - prefix_size;
+    let padding_bytes = pad_to_alignment(flatbuf_size + prefix_size, alignment);
+    let aligned_size = flatbuf_size + prefix_size + padding_bytes;
 
     write_continuation(writer, (aligned_size - prefix_size) as i32)?;
 
@@ -26,13 +29,17 @@ pub fn write_message<W: Write>(
         writer.write_all(buffer)?;
     }
     // write padding
-    // aligned to a 8 byte boundary, so maximum is [u8;8]
+    // with the default 8 byte alignment, the maximum is [u8;8]
     const PADDING_MAX: [u8; 8] = [0u8; 8];
-    writer.write_all(&PADDING_MAX[..padding_bytes])?;
+    if padding_bytes <= PADDING_MAX.len() {
+        writer.write_all(&PADDING_MAX[..padding_bytes])?;
+    } else {
+        writer.write_all(&vec![0u8; padding_bytes][..])?;
+    }
 
     // write arrow data
     let body_len = if arrow_data_len > 0 {
-        write_body_buffers(writer, &encoded.arrow_data)?
+        write_body_buffers(writer, &encoded.arrow_data, alignment)?
     } else {
         0
     };
@@ -40,9 +47,24 @@ pub fn write_message<W: Write>(
     Ok((aligned_size, body_len))
 }
 
-fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> PolarsResult<usize> {
+/// The metadata and buffer data lengths that [`write_message`] writes for `encoded`.
+pub(crate) fn message_lengths(encoded: &EncodedData, alignment: usize) -> (usize, usize) {
+    let prefix_size = 8;
+    let flatbuf_size = encoded.ipc_message.len();
+    let aligned_size =
+        flatbuf_size + prefix_size + pad_to_alignment(flatbuf_size + prefix_size, alignment);
+    let arrow_data_len = encoded.arrow_data.len();
+    let body_len = arrow_data_len + pad_to_alignment(arrow_data_len, alignment);
+    (aligned_size, body_len)
+}
+
+fn write_body_buffers<W: Write>(
+    mut writer: W,
+    data: &[u8],
+    alignment: usize,
+) -> PolarsResult<usize> {
     let len = data.len();
-    let pad_len = pad_to_64(data.len());
+    let pad_len = pad_to_alignment(data.len(), alignment);
     let total_len = len + pad_len;
 
     // write body buffer
@@ -0,0 +1,141 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use arrow_format::ipc::planus::Builder;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::aliases::PlHashMap;
+
+use super::{EncodedData, FileWriter, WriteOptions};
+use crate::io::ipc::IpcField;
+use crate::io::ipc::read::file::get_message_from_block;
+use crate::io::ipc::read::{OutOfSpecKind, read_file_metadata};
+
+/// Concatenates the Arrow IPC files `inputs` into a single IPC file written to `writer`,
+/// without decoding and re-encoding their record batches.
+///
+/// All inputs must have the same schema. Their record batches are copied verbatim and in
+/// order, and a single footer is written for all of them. The dictionary ids of the later
+/// inputs are remapped to those of the first input. As an IPC file has a single set of
+/// dictionaries, the dictionaries of all inputs must be identical; inputs with different
+/// dictionaries must be read and written again instead.
+///
+/// The custom schema metadata of the first input is kept.
+pub fn concat_ipc_files<W: Write, R: Read + Seek>(writer: W, inputs: &mut [R]) -> PolarsResult<()> {
+    let metadatas = inputs
+        .iter_mut()
+        .map(read_file_metadata)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let Some(first) = metadatas.first() else {
+        polars_bail!(InvalidOperation: "at least one IPC file is required to concatenate IPC files")
+    };
+    let mut first_ids = vec![];
+    dictionary_ids(&first.ipc_schema.fields, &mut first_ids);
+
+    let mut writer = FileWriter::try_new(
+        writer,
+        first.schema.clone(),
+        Some(first.ipc_schema.fields.clone()),
+        WriteOptions::default(),
+    )?;
+    if let Some(custom_schema_metadata) = &first.custom_schema_metadata {
+        writer.set_custom_schema_metadata(custom_schema_metadata.clone());
+    }
+
+    let mut written_dictionaries: Option<Vec<EncodedData>> = None;
+    let mut message_scratch = vec![];
+    for (input, metadata) in inputs.iter_mut().zip(&metadatas) {
+        polars_ensure!(
+            metadata.schema == first.schema
+                && metadata.ipc_schema.is_little_endian == first.ipc_schema.is_little_endian,
+            SchemaMismatch: "cannot concatenate IPC files with different schemas"
+        );
+        let mut ids = vec![];
+        dictionary_ids(&metadata.ipc_schema.fields, &mut ids);
+        let id_map: PlHashMap<i64, i64> = ids.into_iter().zip(first_ids.iter().copied()).collect();
+
+        let mut dictionaries = vec![];
+        for block in metadata.dictionaries.iter().flatten() {
+            let (ipc_message, arrow_data) = {
+                let message = get_message_from_block(input, block, &mut message_scratch)?;
+                let mut message = arrow_format::ipc::Message::try_from(message).map_err(|err| {
+                    polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err))
+                })?;
+                let Some(arrow_format::ipc::MessageHeader::DictionaryBatch(batch)) =
+                    &mut message.header
+                else {
+                    polars_bail!(oos = OutOfSpecKind::UnexpectedMessageType)
+                };
+                batch.id = *id_map.get(&batch.id).ok_or_else(|| {
+                    polars_err!(
+                        oos = OutOfSpecKind::InvalidId {
+                            requested_id: batch.id
+                        }
+                    )
+                })?;
+                let mut builder = Builder::new();
+                let ipc_message = builder.finish(&message, None).to_vec();
+                (ipc_message, read_body(input, block)?)
+            };
+            dictionaries.push(EncodedData {
+                ipc_message,
+                arrow_data,
+            });
+        }
+        if !dictionaries.is_empty() {
+            match &written_dictionaries {
+                None => {
+                    writer.write_encoded_dictionaries(&dictionaries)?;
+                    written_dictionaries = Some(dictionaries);
+                },
+                Some(written) => polars_ensure!(
+                    written.len() == dictionaries.len()
+                        && written.iter().zip(&dictionaries).all(|(l, r)| {
+                            l.ipc_message == r.ipc_message && l.arrow_data == r.arrow_data
+                        }),
+                    InvalidOperation: "cannot concatenate IPC files with different dictionaries without re-encoding them"
+                ),
+            }
+        }
+
+        for block in &metadata.blocks {
+            let message = get_message_from_block(input, block, &mut message_scratch)?;
+            polars_ensure!(
+                matches!(
+                    message.header(),
+                    Ok(Some(arrow_format::ipc::MessageHeaderRef::RecordBatch(_)))
+                ),
+                oos = OutOfSpecKind::UnexpectedMessageType
+            );
+            let encoded_message = EncodedData {
+                ipc_message: message_scratch.clone(),
+                arrow_data: read_body(input, block)?,
+            };
+            writer.write_encoded_record_batch(&encoded_message)?;
+        }
+    }
+
+    writer.finish()?;
+    Ok(())
+}
+
+/// Collects the dictionary ids of `fields` in depth-first order.
+fn dictionary_ids(fields: &[IpcField], ids: &mut Vec<i64>) {
+    for field in fields {
+        ids.extend(field.dictionary_id);
+        dictionary_ids(&field.fields, ids);
+    }
+}
+
+/// Reads the body of the message in `block`.
+fn read_body<R: Read + Seek>(
+    reader: &mut R,
+    block: &arrow_format::ipc::Block,
+) -> PolarsResult<Vec<u8>> {
+    let offset = u64::try_from(block.offset + block.meta_data_length as i64)
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let body_length = usize::try_from(block.body_length)
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?;
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut body = vec![0; body_length];
+    reader.read_exact(&mut body)?;
+    Ok(body)
+}
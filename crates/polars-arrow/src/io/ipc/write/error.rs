@@ -0,0 +1,141 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::sync::Arc;
+
+use polars_error::{PolarsError, polars_err};
+
+/// The cause of a failure to write IPC.
+///
+/// It is returned as the [`PolarsError`] of the matching kind, i.e. a
+/// [`PolarsError::SchemaMismatch`] for a record batch that doesn't match the schema, a
+/// [`PolarsError::InvalidOperation`] for a forbidden dictionary replacement, a
+/// [`PolarsError::IO`] for a failure of the inner writer and an out-of-spec
+/// [`PolarsError::ComputeError`] for an operation in the wrong state of the writer. Use
+/// [`IpcWriteError::from_polars_error`] to match on the cause.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum IpcWriteError {
+    /// The writer wasn't started before the operation.
+    NotStarted {
+        /// The operation that was attempted, e.g. `"write"`
+        operation: String,
+    },
+    /// The writer was started more than once.
+    AlreadyStarted,
+    /// The writer was already finished or aborted before the operation.
+    AlreadyFinished {
+        /// The operation that was attempted, e.g. `"write"`
+        operation: String,
+    },
+    /// A written record batch doesn't match the schema of the writer.
+    SchemaMismatch(String),
+    /// A dictionary that was already written would be replaced by one with different values,
+    /// which the writer doesn't allow.
+    DictionaryReplacementForbidden {
+        /// The ID of the dictionary
+        dict_id: i64,
+    },
+    /// Writing to the inner writer failed.
+    Io(Arc<io::Error>),
+}
+
+const NOT_STARTED: &str = "The IPC file must be started before calling `";
+const ALREADY_STARTED: &str = "The IPC file can only be started once";
+const ALREADY_FINISHED: &str = "The IPC file was already finished before calling `";
+const DICTIONARY_REPLACEMENT: &str = "Dictionary replacement detected for dictionary ";
+
+impl IpcWriteError {
+    /// The error for `operation` on a writer that isn't started, which depends on whether it
+    /// hasn't been started yet or has already been finished.
+    pub(crate) fn not_started(finished: bool, operation: &str) -> Self {
+        let operation = operation.to_string();
+        if finished {
+            Self::AlreadyFinished { operation }
+        } else {
+            Self::NotStarted { operation }
+        }
+    }
+
+    /// The IPC write error that `error` holds, if any. Errors with added context are looked
+    /// through.
+    ///
+    /// This is meant for errors returned by the IPC writers; any [`PolarsError::IO`] and
+    /// [`PolarsError::SchemaMismatch`] is taken to be an [`IpcWriteError::Io`] and
+    /// [`IpcWriteError::SchemaMismatch`] respectively.
+    pub fn from_polars_error(error: &PolarsError) -> Option<Self> {
+        match error {
+            PolarsError::IO { error, .. } => Some(Self::Io(error.clone())),
+            PolarsError::SchemaMismatch(msg) => Some(Self::SchemaMismatch(msg.to_string())),
+            PolarsError::ComputeError(msg) => {
+                let msg = msg.as_ref().strip_prefix("out-of-spec: ")?;
+                // The operation is quoted in the message.
+                let operation = || Some(msg.split('`').nth(1)?.to_string());
+                if msg.starts_with(NOT_STARTED) {
+                    Some(Self::NotStarted {
+                        operation: operation()?,
+                    })
+                } else if msg.starts_with(ALREADY_FINISHED) {
+                    Some(Self::AlreadyFinished {
+                        operation: operation()?,
+                    })
+                } else if msg.starts_with(ALREADY_STARTED) {
+                    Some(Self::AlreadyStarted)
+                } else {
+                    None
+                }
+            },
+            PolarsError::InvalidOperation(msg) => {
+                let dict_id = msg.as_ref().strip_prefix(DICTIONARY_REPLACEMENT)?;
+                let dict_id = dict_id.split(' ').next()?.parse().ok()?;
+                Some(Self::DictionaryReplacementForbidden { dict_id })
+            },
+            PolarsError::Context { error, .. } => Self::from_polars_error(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for IpcWriteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotStarted { operation } => write!(
+                f,
+                "{NOT_STARTED}{operation}`. Call `start` before `{operation}`"
+            ),
+            Self::AlreadyStarted => write!(f, "{ALREADY_STARTED}"),
+            Self::AlreadyFinished { operation } => write!(f, "{ALREADY_FINISHED}{operation}`"),
+            Self::SchemaMismatch(msg) => write!(f, "{msg}"),
+            Self::DictionaryReplacementForbidden { dict_id } => write!(
+                f,
+                "{DICTIONARY_REPLACEMENT}{dict_id} when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+            ),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for IpcWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<IpcWriteError> for PolarsError {
+    fn from(error: IpcWriteError) -> Self {
+        match error {
+            IpcWriteError::NotStarted { .. }
+            | IpcWriteError::AlreadyStarted
+            | IpcWriteError::AlreadyFinished { .. } => polars_err!(oos = error),
+            IpcWriteError::SchemaMismatch(msg) => PolarsError::SchemaMismatch(msg.into()),
+            IpcWriteError::DictionaryReplacementForbidden { .. } => {
+                polars_err!(InvalidOperation: "{}", error)
+            },
+            IpcWriteError::Io(error) => PolarsError::IO { error, msg: None },
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Async writer for the Arrow IPC file format.
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::super::IpcField;
+use super::common::{EncodedData, WriteOptions};
+use super::writer::{FileLayout, FileWriter};
+use crate::array::Array;
+use crate::datatypes::*;
+use crate::record_batch::RecordBatchT;
+
+/// Async Arrow file writer
+///
+/// Encoding and the bookkeeping of the footer are done by an inner [`FileWriter`] that
+/// writes each message to an in-memory buffer; only writing the buffered messages to `W` is
+/// async. The state machine and dictionary tracking therefore behave identically to the
+/// [`FileWriter`].
+pub struct AsyncFileWriter<W: AsyncWrite + Unpin> {
+    /// The object to write to
+    writer: W,
+    /// Synchronous writer that encodes the messages into a buffer
+    inner: FileWriter<Vec<u8>>,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncFileWriter<W> {
+    /// Creates a new [`AsyncFileWriter`] and writes the header to `writer`
+    pub async fn try_new(
+        writer: W,
+        schema: ArrowSchemaRef,
+        ipc_fields: Option<Vec<IpcField>>,
+        options: WriteOptions,
+    ) -> PolarsResult<Self> {
+        let mut slf = Self::new(writer, schema, ipc_fields, options);
+        slf.start().await?;
+
+        Ok(slf)
+    }
+
+    /// Creates a new [`AsyncFileWriter`].
+    pub fn new(
+        writer: W,
+        schema: ArrowSchemaRef,
+        ipc_fields: Option<Vec<IpcField>>,
+        options: WriteOptions,
+    ) -> Self {
+        Self {
+            writer,
+            inner: FileWriter::new(vec![], schema, ipc_fields, options),
+        }
+    }
+
+    /// Consumes itself into the inner writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes the header and first (schema) message to the file.
+    /// # Errors
+    /// Errors if the file has been started or has finished.
+    pub async fn start(&mut self) -> PolarsResult<()> {
+        self.inner.start()?;
+        self.write_buffered().await
+    }
+
+    /// Writes [`RecordBatchT`] to the file
+    pub async fn write(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+    ) -> PolarsResult<()> {
+        self.inner.write(chunk, ipc_fields)?;
+        self.write_buffered().await
+    }
+
+    pub async fn write_encoded(
+        &mut self,
+        encoded_dictionaries: &[EncodedData],
+        encoded_message: &EncodedData,
+    ) -> PolarsResult<()> {
+        self.inner
+            .write_encoded(encoded_dictionaries, encoded_message)?;
+        self.write_buffered().await
+    }
+
+    /// Writes the staged chunks, if any, and flushes `writer`, without finishing the file.
+    pub async fn flush(&mut self) -> PolarsResult<()> {
+        self.inner.flush()?;
+        self.write_buffered().await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Write footer and closing tag, then mark the writer as done
+    ///
+    /// Returns the [`FileLayout`] of the written file.
+    pub async fn finish(&mut self) -> PolarsResult<FileLayout> {
+        let layout = self.inner.finish()?;
+        self.write_buffered().await?;
+        self.writer.flush().await?;
+        Ok(layout)
+    }
+
+    /// Sets custom schema metadata. Can be called any time before `finish` is called.
+    pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.inner.set_custom_schema_metadata(custom_metadata);
+    }
+
+    /// Sets custom footer metadata. Can be called any time before `finish` is called.
+    pub fn set_custom_footer_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.inner.set_custom_footer_metadata(custom_metadata);
+    }
+
+    /// Writes the messages buffered by the inner writer to `writer`.
+    async fn write_buffered(&mut self) -> PolarsResult<()> {
+        self.writer.write_all(&self.inner.writer.inner).await?;
+        self.inner.writer.inner.clear();
+        Ok(())
+    }
+}
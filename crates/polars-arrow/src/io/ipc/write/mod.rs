@@ -1,22 +1,43 @@
 //! APIs to write to Arrow's IPC format.
 pub(crate) mod common;
+mod concat;
+mod error;
+mod multi_table;
+mod partitioned;
 mod schema;
 mod serialize;
+mod statistics;
 mod stream;
 pub(crate) mod writer;
 
+#[cfg(feature = "io_ipc_compression")]
+pub use common::CompressionThreadPool;
 pub use common::{
-    Compression, DictionaryTracker, EncodedData, Record, WriteOptions, commit_encoded_arrays,
-    dictionaries_to_encode, encode_array, encode_dictionary, encode_new_dictionaries,
-    encode_record_batch,
+    CompressedSizeEstimate, Compression, CompressionOptions, DictionaryTracker, EncodedData,
+    IPC_CHECKSUM_KEY, IPC_COMPRESSION_LEVEL_KEY, IPC_DICTIONARY_MODE_KEY, IPC_PAGE_SIZE,
+    IPC_SCHEMA_FINGERPRINT_KEY, IPC_SCHEMA_PARENT_KEY, IPC_STATISTICS_KEY, Record, SchemaEvolution,
+    WriteOptions, WriteOptionsBuilder, commit_encoded_arrays, dictionaries_to_encode, encode_array,
+    encode_chunk, encode_dictionary, encode_new_dictionaries, encode_record_batch,
+    estimate_ipc_size, estimate_ipc_size_with,
 };
+pub use concat::concat_ipc_files;
+pub use error::IpcWriteError;
+pub use multi_table::{MultiTableWriter, TableWriter};
+pub use partitioned::PartitionedIpcWriter;
 pub use schema::schema_to_bytes;
 pub use serialize::write;
 use serialize::write_dictionary;
 pub use stream::StreamWriter;
-pub use writer::FileWriter;
+pub use writer::{BlockKind, FileLayout, FileWriter, OnBlock, ReserveCapacity};
+
+#[cfg(feature = "io_ipc_write_async")]
+mod file_async;
+#[cfg(feature = "io_ipc_write_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_write_async")))]
+pub use file_async::AsyncFileWriter;
 
 pub(crate) mod common_sync;
+pub use common_sync::write_message;
 
 use super::IpcField;
 use crate::datatypes::{ArrowDataType, Field};
@@ -0,0 +1,129 @@
+use std::io::Write;
+use std::ops::{Deref, DerefMut, Range};
+
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::super::{IpcField, MULTI_TABLE_MAGIC};
+use super::writer::State;
+use super::{FileWriter, WriteOptions};
+use crate::datatypes::ArrowSchemaRef;
+
+/// Writes several named tables, each a complete Arrow IPC file with its own schema and
+/// dictionaries, one after the other into a single output, followed by a manifest that maps
+/// the names of the tables to their byte ranges.
+///
+/// The manifest is read with [`read_multi_table_index`](crate::io::ipc::read::read_multi_table_index),
+/// after which a single table can be opened without reading the others.
+///
+/// The manifest consists of the number of tables as a little-endian `u32`, and for every
+/// table the length of its name as a `u32`, its UTF-8 name, and its offset and length in bytes
+/// as `u64`s. It is followed by its own length as a `u64` and the magic `ARROWMT1`.
+pub struct MultiTableWriter<W: Write> {
+    writer: W,
+    tables: Vec<(PlSmallStr, Range<u64>)>,
+    offset: u64,
+}
+
+impl<W: Write> MultiTableWriter<W> {
+    /// Creates a new [`MultiTableWriter`] writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            tables: vec![],
+            offset: 0,
+        }
+    }
+
+    /// Starts writing the table `name`, returning a [`TableWriter`] that must be finished with
+    /// [`TableWriter::finish`] before the next table is started.
+    ///
+    /// Errors if a table with this name was already written.
+    pub fn start_table(
+        &mut self,
+        name: PlSmallStr,
+        schema: ArrowSchemaRef,
+        ipc_fields: Option<Vec<IpcField>>,
+        options: WriteOptions,
+    ) -> PolarsResult<TableWriter<'_, W>> {
+        polars_ensure!(
+            self.tables.iter().all(|(existing, _)| *existing != name),
+            Duplicate: "a table named '{}' was already written", name
+        );
+        let writer = FileWriter::try_new(&mut self.writer, schema, ipc_fields, options)?;
+        Ok(TableWriter {
+            writer,
+            name,
+            tables: &mut self.tables,
+            offset: &mut self.offset,
+        })
+    }
+
+    /// The names of the tables written so far, with their byte ranges.
+    pub fn tables(&self) -> &[(PlSmallStr, Range<u64>)] {
+        &self.tables
+    }
+
+    /// Writes the manifest and returns the inner writer.
+    pub fn finish(mut self) -> PolarsResult<W> {
+        let mut manifest = vec![];
+        manifest.extend_from_slice(&(self.tables.len() as u32).to_le_bytes());
+        for (name, range) in &self.tables {
+            manifest.extend_from_slice(&(name.len() as u32).to_le_bytes());
+            manifest.extend_from_slice(name.as_bytes());
+            manifest.extend_from_slice(&range.start.to_le_bytes());
+            manifest.extend_from_slice(&(range.end - range.start).to_le_bytes());
+        }
+        self.writer.write_all(&manifest)?;
+        self.writer
+            .write_all(&(manifest.len() as u64).to_le_bytes())?;
+        self.writer.write_all(&MULTI_TABLE_MAGIC)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Writes a single table of a [`MultiTableWriter`]; dereferences to its [`FileWriter`].
+///
+/// A table that is dropped without being finished is not part of the manifest, but its bytes
+/// remain in the output.
+pub struct TableWriter<'a, W: Write> {
+    writer: FileWriter<&'a mut W>,
+    name: PlSmallStr,
+    tables: &'a mut Vec<(PlSmallStr, Range<u64>)>,
+    offset: &'a mut u64,
+}
+
+impl<W: Write> TableWriter<'_, W> {
+    /// Finishes the IPC file of this table, unless it was already finished, and adds the table
+    /// to the manifest.
+    pub fn finish(mut self) -> PolarsResult<()> {
+        if self.writer.state != State::Finished {
+            self.writer.finish()?;
+        }
+        let range = *self.offset..*self.offset + self.writer.bytes_written();
+        self.tables.push((std::mem::take(&mut self.name), range));
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> Deref for TableWriter<'a, W> {
+    type Target = FileWriter<&'a mut W>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.writer
+    }
+}
+
+impl<W: Write> DerefMut for TableWriter<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Drop for TableWriter<'_, W> {
+    fn drop(&mut self) {
+        // Keep the offsets of the following tables correct.
+        *self.offset += self.writer.bytes_written();
+    }
+}
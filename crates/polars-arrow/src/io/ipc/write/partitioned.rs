@@ -0,0 +1,85 @@
+use std::hash::Hash;
+use std::io::Write;
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+use polars_utils::aliases::{InitHashMaps, PlIndexMap};
+
+use super::{FileWriter, WriteOptions};
+use crate::array::Array;
+use crate::record_batch::RecordBatchT;
+
+/// Writes record batches keyed by a partition value, each partition to its own Arrow IPC file.
+///
+/// The file of a partition is created with the factory and started on the first record batch
+/// of that partition, with the schema of that record batch. Partitions can therefore have
+/// different schemas, while the record batches of one partition must share theirs.
+pub struct PartitionedIpcWriter<K, W: Write, F> {
+    factory: F,
+    options: WriteOptions,
+    writers: PlIndexMap<K, FileWriter<W>>,
+}
+
+impl<K, W, F> PartitionedIpcWriter<K, W, F>
+where
+    K: Hash + Eq,
+    W: Write,
+    F: FnMut(&K) -> W,
+{
+    /// Creates a new [`PartitionedIpcWriter`] that creates the writer of a partition with
+    /// `factory` and writes every partition with `options`.
+    pub fn new(factory: F, options: WriteOptions) -> Self {
+        Self {
+            factory,
+            options,
+            writers: PlIndexMap::new(),
+        }
+    }
+
+    /// Writes `chunk` to the file of the partition `key`, starting the file if this is the
+    /// first record batch of the partition.
+    pub fn write(&mut self, key: K, chunk: &RecordBatchT<Box<dyn Array>>) -> PolarsResult<()> {
+        let index = match self.writers.get_index_of(&key) {
+            Some(index) => index,
+            None => {
+                let inner = (self.factory)(&key);
+                let schema = Arc::new(chunk.schema().clone());
+                let writer = FileWriter::try_new(inner, schema, None, self.options)?;
+                self.writers.insert_full(key, writer).0
+            },
+        };
+        self.writers[index].write(chunk, None)
+    }
+
+    /// The number of partitions written so far.
+    pub fn len(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Whether no partition was written yet.
+    pub fn is_empty(&self) -> bool {
+        self.writers.is_empty()
+    }
+
+    /// The writer of the partition `key`, if a record batch of it was written.
+    pub fn get(&self, key: &K) -> Option<&FileWriter<W>> {
+        self.writers.get(key)
+    }
+
+    /// The keys of the partitions written so far, in the order they were first seen.
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> {
+        self.writers.keys()
+    }
+
+    /// Finishes the file of every partition and returns the inner writers with their keys, in
+    /// the order the partitions were first seen.
+    pub fn finish_all(self) -> PolarsResult<Vec<(K, W)>> {
+        self.writers
+            .into_iter()
+            .map(|(key, mut writer)| {
+                writer.finish()?;
+                Ok((key, writer.into_inner()))
+            })
+            .collect()
+    }
+}
@@ -42,13 +42,7 @@ pub fn serialize_schema(
         .map(|(field, ipc_field)| serialize_field(field, ipc_field))
         .collect::<Vec<_>>();
 
-    let custom_metadata = custom_schema_metadata.and_then(|custom_meta| {
-        let as_kv = custom_meta
-            .iter()
-            .map(|(key, val)| key_value(key.clone().into_string(), val.clone().into_string()))
-            .collect::<Vec<_>>();
-        (!as_kv.is_empty()).then_some(as_kv)
-    });
+    let custom_metadata = custom_schema_metadata.and_then(serialize_custom_metadata);
 
     arrow_format::ipc::Schema {
         endianness,
@@ -58,6 +52,18 @@ pub fn serialize_schema(
     }
 }
 
+/// Converts [`Metadata`] into the key-value pairs used by the IPC flatbuffers.
+/// Returns `None` if the metadata is empty.
+pub(crate) fn serialize_custom_metadata(
+    custom_metadata: &Metadata,
+) -> Option<Vec<arrow_format::ipc::KeyValue>> {
+    let as_kv = custom_metadata
+        .iter()
+        .map(|(key, val)| key_value(key.clone().into_string(), val.clone().into_string()))
+        .collect::<Vec<_>>();
+    (!as_kv.is_empty()).then_some(as_kv)
+}
+
 fn key_value(key: impl Into<String>, val: impl Into<String>) -> arrow_format::ipc::KeyValue {
     arrow_format::ipc::KeyValue {
         key: Some(key.into()),
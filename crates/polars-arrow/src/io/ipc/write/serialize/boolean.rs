@@ -6,7 +6,7 @@ pub(super) fn write_boolean(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     _: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     write_bitmap(
         array.validity(),
@@ -9,7 +9,7 @@ pub fn write_dictionary<K: DictionaryKey>(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
     write_keys: bool,
 ) -> usize {
     if write_keys {
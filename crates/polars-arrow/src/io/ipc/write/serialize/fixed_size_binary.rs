@@ -6,7 +6,7 @@ pub(super) fn write_fixed_size_binary(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     _is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     write_bitmap(
         array.validity(),
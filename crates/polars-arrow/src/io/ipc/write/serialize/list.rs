@@ -7,7 +7,7 @@ pub(super) fn write_list<O: Offset>(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
@@ -3,13 +3,13 @@ use arrow_format::ipc;
 
 use super::super::compression;
 use super::super::endianness::is_native_little_endian;
-use super::common::{Compression, pad_to_64};
+use super::common::{Compression, CompressionOptions, pad_to_64};
 use crate::array::*;
 use crate::bitmap::Bitmap;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{PhysicalType, PrimitiveType};
 use crate::offset::{Offset, OffsetsBuffer};
 use crate::trusted_len::TrustedLen;
-use crate::types::NativeType;
+use crate::types::{NativeType, i256};
 use crate::{match_integer_type, with_match_primitive_type_full};
 mod binary;
 mod binview;
@@ -43,7 +43,7 @@ pub fn write(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
@@ -60,6 +60,14 @@ pub fn write(
             is_little_endian,
             compression,
         ),
+        Primitive(PrimitiveType::Int256) => write_primitive::<i256>(
+            array.as_any().downcast_ref().unwrap(),
+            buffers,
+            arrow_data,
+            offset,
+            is_little_endian,
+            compression,
+        ),
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
             let array = array.as_any().downcast_ref().unwrap();
             write_primitive::<$T>(array, buffers, arrow_data, offset, is_little_endian, compression)
@@ -207,17 +215,17 @@ fn write_bytes(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
+        match compression.codec {
             Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
+                compression::compress_lz4(bytes, arrow_data, compression.level).unwrap();
             },
             Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
+                compression::compress_zstd(bytes, arrow_data, compression.level).unwrap();
             },
         }
     } else {
@@ -233,7 +241,7 @@ fn write_bitmap(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     match bitmap {
         Some(bitmap) => {
@@ -264,7 +272,7 @@ fn write_buffer<T: NativeType>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
@@ -300,7 +308,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     buffer: I,
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: CompressionOptions,
 ) {
     let len = buffer.size_hint().0;
     let mut swapped = Vec::with_capacity(len * size_of::<T>());
@@ -314,12 +322,12 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
     arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
+    match compression.codec {
         Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
+            compression::compress_lz4(&swapped, arrow_data, compression.level).unwrap();
         },
         Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
+            compression::compress_zstd(&swapped, arrow_data, compression.level).unwrap();
         },
     }
 }
@@ -338,17 +346,17 @@ fn _write_compressed_buffer<T: NativeType>(
     buffer: &[T],
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: CompressionOptions,
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
+        match compression.codec {
             Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
+                compression::compress_lz4(bytes, arrow_data, compression.level).unwrap();
             },
             Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
+                compression::compress_zstd(bytes, arrow_data, compression.level).unwrap();
             },
         }
     } else {
@@ -364,7 +372,7 @@ fn write_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<CompressionOptions>,
 ) {
     let start = arrow_data.len();
 
@@ -0,0 +1,271 @@
+use std::fmt::Write;
+use std::hash::{BuildHasher, Hash};
+
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::cardinality_sketch::CardinalitySketch;
+
+use crate::array::{
+    Array, BinaryArray, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8Array, Utf8ViewArray,
+};
+use crate::datatypes::{ArrowSchema, PhysicalType, PrimitiveType};
+use crate::record_batch::RecordBatchT;
+use crate::types::NativeType;
+
+/// A minimum or maximum of a column.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+enum StatValue {
+    Boolean(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+}
+
+/// The statistics of a single column, accumulated over the written chunks.
+#[derive(Clone, Default)]
+struct ColumnStatistics {
+    null_count: usize,
+    min: Option<StatValue>,
+    max: Option<StatValue>,
+    /// `None` if a distinct count can't be estimated for the data type of the column.
+    distinct: Option<CardinalitySketch>,
+}
+
+impl ColumnStatistics {
+    fn update(&mut self, array: &dyn Array) {
+        self.null_count += array.null_count();
+
+        let mut sketch = self.distinct.take().unwrap_or_default();
+        let hasher = PlFixedStateQuality::default();
+        let (min, max) = match array.dtype().to_physical_type() {
+            PhysicalType::Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                min_max(array.non_null_values_iter(), &hasher, &mut sketch, |v| {
+                    StatValue::Boolean(v)
+                })
+            },
+            PhysicalType::Primitive(primitive) => match primitive {
+                PrimitiveType::Int8 => integer_min_max::<i8>(array, &hasher, &mut sketch),
+                PrimitiveType::Int16 => integer_min_max::<i16>(array, &hasher, &mut sketch),
+                PrimitiveType::Int32 => integer_min_max::<i32>(array, &hasher, &mut sketch),
+                PrimitiveType::Int64 => integer_min_max::<i64>(array, &hasher, &mut sketch),
+                PrimitiveType::UInt8 => integer_min_max::<u8>(array, &hasher, &mut sketch),
+                PrimitiveType::UInt16 => integer_min_max::<u16>(array, &hasher, &mut sketch),
+                PrimitiveType::UInt32 => integer_min_max::<u32>(array, &hasher, &mut sketch),
+                PrimitiveType::UInt64 => integer_min_max::<u64>(array, &hasher, &mut sketch),
+                PrimitiveType::Float32 => {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<f32>>()
+                        .unwrap();
+                    float_min_max(
+                        array.non_null_values_iter().map(|v| v as f64),
+                        &hasher,
+                        &mut sketch,
+                    )
+                },
+                PrimitiveType::Float64 => {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<f64>>()
+                        .unwrap();
+                    float_min_max(array.non_null_values_iter(), &hasher, &mut sketch)
+                },
+                _ => return,
+            },
+            PhysicalType::Utf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                string_min_max(array.non_null_values_iter(), &hasher, &mut sketch)
+            },
+            PhysicalType::LargeUtf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                string_min_max(array.non_null_values_iter(), &hasher, &mut sketch)
+            },
+            PhysicalType::Utf8View => {
+                let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+                string_min_max(array.non_null_values_iter(), &hasher, &mut sketch)
+            },
+            // Binary values have no JSON representation, so only their distinct count is kept.
+            PhysicalType::Binary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                insert_hashes(array.non_null_values_iter(), &hasher, &mut sketch);
+                (None, None)
+            },
+            PhysicalType::LargeBinary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+                insert_hashes(array.non_null_values_iter(), &hasher, &mut sketch);
+                (None, None)
+            },
+            PhysicalType::BinaryView => {
+                let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+                insert_hashes(array.non_null_values_iter(), &hasher, &mut sketch);
+                (None, None)
+            },
+            _ => return,
+        };
+        self.distinct = Some(sketch);
+
+        if let Some(min) = min {
+            if self.min.as_ref().is_none_or(|current| min < *current) {
+                self.min = Some(min);
+            }
+        }
+        if let Some(max) = max {
+            if self.max.as_ref().is_none_or(|current| max > *current) {
+                self.max = Some(max);
+            }
+        }
+    }
+}
+
+fn insert_hashes<T: Hash>(
+    values: impl Iterator<Item = T>,
+    hasher: &PlFixedStateQuality,
+    sketch: &mut CardinalitySketch,
+) {
+    for value in values {
+        sketch.insert(hasher.hash_one(value));
+    }
+}
+
+fn min_max<T: Copy + Hash + PartialOrd>(
+    values: impl Iterator<Item = T>,
+    hasher: &PlFixedStateQuality,
+    sketch: &mut CardinalitySketch,
+    to_stat: impl Fn(T) -> StatValue,
+) -> (Option<StatValue>, Option<StatValue>) {
+    let mut min_max: Option<(T, T)> = None;
+    for value in values {
+        sketch.insert(hasher.hash_one(value));
+        min_max = Some(match min_max {
+            None => (value, value),
+            Some((min, max)) => (
+                if value < min { value } else { min },
+                if value > max { value } else { max },
+            ),
+        });
+    }
+    match min_max {
+        None => (None, None),
+        Some((min, max)) => (Some(to_stat(min)), Some(to_stat(max))),
+    }
+}
+
+fn integer_min_max<T: NativeType + Hash + Ord + Into<i128>>(
+    array: &dyn Array,
+    hasher: &PlFixedStateQuality,
+    sketch: &mut CardinalitySketch,
+) -> (Option<StatValue>, Option<StatValue>) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    min_max(array.non_null_values_iter(), hasher, sketch, |v| {
+        StatValue::Integer(v.into())
+    })
+}
+
+/// NaNs are counted as distinct values, but are ignored by the minimum and maximum.
+fn float_min_max(
+    values: impl Iterator<Item = f64>,
+    hasher: &PlFixedStateQuality,
+    sketch: &mut CardinalitySketch,
+) -> (Option<StatValue>, Option<StatValue>) {
+    let mut min: Option<f64> = None;
+    let mut max: Option<f64> = None;
+    for value in values {
+        // Normalize -0.0 and NaNs, so that equal values hash the same.
+        let bits = if value.is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            (value + 0.0).to_bits()
+        };
+        sketch.insert(hasher.hash_one(bits));
+        if !value.is_nan() {
+            min = Some(min.map_or(value, |min| min.min(value)));
+            max = Some(max.map_or(value, |max| max.max(value)));
+        }
+    }
+    (min.map(StatValue::Float), max.map(StatValue::Float))
+}
+
+fn string_min_max<'a>(
+    values: impl Iterator<Item = &'a str>,
+    hasher: &PlFixedStateQuality,
+    sketch: &mut CardinalitySketch,
+) -> (Option<StatValue>, Option<StatValue>) {
+    min_max(values, hasher, sketch, |v| StatValue::String(v.to_string()))
+}
+
+/// Per-column statistics of the chunks written to a [`FileWriter`](super::FileWriter), see
+/// [`WriteOptions::collect_statistics`](super::WriteOptions::collect_statistics).
+#[derive(Clone)]
+pub(crate) struct FileStatistics {
+    num_rows: usize,
+    columns: Vec<ColumnStatistics>,
+}
+
+impl FileStatistics {
+    pub(crate) fn new(num_columns: usize) -> Self {
+        Self {
+            num_rows: 0,
+            columns: vec![ColumnStatistics::default(); num_columns],
+        }
+    }
+
+    pub(crate) fn update(&mut self, chunk: &RecordBatchT<Box<dyn Array>>) {
+        self.num_rows += chunk.len();
+        for (statistics, array) in self.columns.iter_mut().zip(chunk.arrays()) {
+            statistics.update(array.as_ref());
+        }
+    }
+
+    /// Serializes the statistics as JSON, see [`IPC_STATISTICS_KEY`](super::IPC_STATISTICS_KEY)
+    /// for the format.
+    pub(crate) fn to_json(&self, schema: &ArrowSchema) -> String {
+        let mut out = String::new();
+        write!(out, "{{\"num_rows\":{},\"columns\":[", self.num_rows).unwrap();
+        for (i, (field, statistics)) in schema.iter_values().zip(&self.columns).enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            write_json_string(&mut out, field.name.as_str());
+            write!(out, ",\"null_count\":{},\"min\":", statistics.null_count).unwrap();
+            write_json_value(&mut out, statistics.min.as_ref());
+            out.push_str(",\"max\":");
+            write_json_value(&mut out, statistics.max.as_ref());
+            out.push_str(",\"distinct_count\":");
+            match &statistics.distinct {
+                Some(sketch) => write!(out, "{}", sketch.estimate()).unwrap(),
+                None => out.push_str("null"),
+            }
+            out.push('}');
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+fn write_json_value(out: &mut String, value: Option<&StatValue>) {
+    match value {
+        Some(StatValue::Boolean(v)) => write!(out, "{v}").unwrap(),
+        Some(StatValue::Integer(v)) => write!(out, "{v}").unwrap(),
+        // JSON has no representation of infinities.
+        Some(StatValue::Float(v)) if v.is_finite() => write!(out, "{v:?}").unwrap(),
+        Some(StatValue::String(v)) => write_json_string(out, v),
+        _ => out.push_str("null"),
+    }
+}
+
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
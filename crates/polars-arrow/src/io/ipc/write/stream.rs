@@ -47,6 +47,8 @@ impl<W: Write> StreamWriter<W> {
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                allow_delta: write_options.delta_dictionaries,
+                replacements: 0,
             },
             ipc_fields: None,
             custom_schema_metadata: None,
@@ -65,21 +67,36 @@ impl<W: Write> StreamWriter<W> {
         schema: &ArrowSchema,
         ipc_fields: Option<Vec<IpcField>>,
     ) -> PolarsResult<()> {
+        self.write_options.validate()?;
         self.ipc_fields = Some(if let Some(ipc_fields) = ipc_fields {
             ipc_fields
         } else {
             default_ipc_fields(schema.iter_values())
         });
 
+        let mut custom_schema_metadata = self
+            .write_options
+            .schema_metadata(self.custom_schema_metadata.as_deref());
+        if self.write_options.write_dictionary_mode {
+            self.dictionary_tracker.write_dictionary_mode(
+                custom_schema_metadata
+                    .get_or_insert_with(Default::default)
+                    .to_mut(),
+            );
+        }
         let encoded_message = EncodedData {
             ipc_message: schema_to_bytes(
                 schema,
                 self.ipc_fields.as_ref().unwrap(),
-                self.custom_schema_metadata.as_deref(),
+                custom_schema_metadata.as_deref(),
             ),
             arrow_data: vec![],
         };
-        write_message(&mut self.writer, &encoded_message)?;
+        write_message(
+            &mut self.writer,
+            &encoded_message,
+            self.write_options.alignment,
+        )?;
         Ok(())
     }
 
@@ -108,11 +125,41 @@ impl<W: Write> StreamWriter<W> {
             &self.write_options,
         )?;
 
+        self.write_encoded(&encoded_dictionaries, &encoded_message)
+    }
+
+    /// Writes dictionary batches and a record batch that were already encoded, e.g. with
+    /// [`encode_chunk`](super::encode_chunk).
+    ///
+    /// The dictionaries are not checked against the dictionaries that were written before, so
+    /// the caller must make sure that every dictionary batch the record batch refers to was
+    /// written to the stream.
+    pub fn write_encoded(
+        &mut self,
+        encoded_dictionaries: &[EncodedData],
+        encoded_message: &EncodedData,
+    ) -> PolarsResult<()> {
+        if self.finished {
+            let io_err = std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Cannot write to a finished stream".to_string(),
+            );
+            return Err(PolarsError::from(io_err));
+        }
+
         for encoded_dictionary in encoded_dictionaries {
-            write_message(&mut self.writer, &encoded_dictionary)?;
+            write_message(
+                &mut self.writer,
+                encoded_dictionary,
+                self.write_options.alignment,
+            )?;
         }
 
-        write_message(&mut self.writer, &encoded_message)?;
+        write_message(
+            &mut self.writer,
+            encoded_message,
+            self.write_options.alignment,
+        )?;
         Ok(())
     }
 
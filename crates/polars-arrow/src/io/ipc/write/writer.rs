@@ -1,29 +1,106 @@
-use std::io::Write;
+use std::io::{BufWriter, Cursor, Seek, SeekFrom, Write};
 use std::sync::Arc;
 
-use arrow_format::ipc::planus::Builder;
-use polars_error::{PolarsResult, polars_bail};
+use arrow_format::ipc::planus::{Builder, ReadAsRoot};
+use polars_error::{PolarsResult, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::super::{ARROW_MAGIC_V2, IpcField};
-use super::common::{DictionaryTracker, EncodedData, WriteOptions};
-use super::common_sync::{write_continuation, write_message};
+#[cfg(feature = "io_ipc_checksum")]
+use super::common::IPC_CHECKSUM_KEY;
+use super::common::{
+    Compression, DictionaryTracker, EncodedData, IPC_STATISTICS_KEY, SchemaEvolution, WriteOptions,
+    encode_dictionary, encode_new_dictionaries, encode_split_record_batches, pad_to_alignment,
+};
+use super::common_sync::{message_lengths, write_continuation, write_message};
+use super::error::IpcWriteError;
+use super::statistics::FileStatistics;
 use super::{default_ipc_fields, schema, schema_to_bytes};
-use crate::array::Array;
+use crate::array::{
+    Array, BinaryArray, DictionaryArray, FixedSizeListArray, ListArray, MapArray, PrimitiveArray,
+    StructArray, UnionArray, Utf8Array,
+};
+use crate::compute::aggregate::estimated_bytes_size;
+use crate::compute::concatenate::concatenate;
 use crate::datatypes::*;
+use crate::io::ipc::read::file::get_record_batch;
+use crate::io::ipc::read::{Dictionaries, OutOfSpecKind};
 use crate::io::ipc::write::common::encode_chunk_amortized;
+use crate::match_integer_type;
 use crate::record_batch::RecordBatchT;
 
+/// The number of rows of the encoded record batch message `ipc_message`.
+fn record_batch_length(ipc_message: &[u8]) -> PolarsResult<usize> {
+    let message = arrow_format::ipc::MessageRef::read_as_root(ipc_message)
+        .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+    let length = get_record_batch(message)?
+        .length()
+        .map_err(|_| polars_err!(oos = OutOfSpecKind::MissingData))?;
+    usize::try_from(length).map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))
+}
+
+/// Wraps the writer of a [`FileWriter`], maintaining a running CRC32C checksum of the bytes
+/// written to it if [`WriteOptions::write_checksum`] is set.
+pub(crate) struct ChecksumWriter<W: Write> {
+    pub(crate) inner: W,
+    /// The checksum of the bytes written so far, `None` if no checksum is computed.
+    pub(crate) checksum: Option<u32>,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        #[cfg(feature = "io_ipc_checksum")]
+        if let Some(checksum) = &mut self.checksum {
+            *checksum = crc32c::crc32c_append(*checksum, &buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The layout of a finished IPC file, as returned by [`FileWriter::finish`].
+///
+/// This allows locating the messages of the file without parsing its footer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileLayout {
+    /// The blocks of the dictionary batches, in the order they were written.
+    pub dictionary_blocks: Vec<arrow_format::ipc::Block>,
+    /// The blocks of the record batches, in the order they were written.
+    pub record_blocks: Vec<arrow_format::ipc::Block>,
+    /// The length of the file in bytes, including the footer and trailing magic.
+    pub file_length: u64,
+}
+
+/// The kind of message a [`Block`](arrow_format::ipc::Block) written by a [`FileWriter`]
+/// refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    /// A dictionary batch
+    Dictionary,
+    /// A record batch
+    Record,
+}
+
+/// A callback that is called with every block written by a [`FileWriter`], see
+/// [`FileWriter::set_on_block`].
+pub type OnBlock = Box<dyn FnMut(BlockKind, &arrow_format::ipc::Block) + Send>;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum State {
     None,
     Started,
     Finished,
+    Aborted,
 }
 
 /// Arrow file writer
 pub struct FileWriter<W: Write> {
     /// The object to write to
-    pub(crate) writer: W,
+    pub(crate) writer: ChecksumWriter<W>,
     /// IPC write options
     pub(crate) options: WriteOptions,
     /// A reference to the schema, used in validating record batches
@@ -43,6 +120,32 @@ pub struct FileWriter<W: Write> {
     pub(crate) encoded_message: EncodedData,
     /// Custom schema-level metadata
     pub(crate) custom_schema_metadata: Option<Arc<Metadata>>,
+    /// Custom footer-level metadata
+    pub(crate) custom_footer_metadata: Option<Arc<Metadata>>,
+    /// The number of rows written through `write`
+    pub(crate) written_rows: usize,
+    /// The number of record batches written
+    pub(crate) written_batches: usize,
+    /// The number of bytes of the end-of-stream marker, footer and trailing magic
+    pub(crate) footer_length: usize,
+    /// Chunks that are staged until [`WriteOptions::min_record_batch_size`] is reached
+    pub(crate) staged_chunks: Vec<RecordBatchT<Box<dyn Array>>>,
+    /// The estimated size in bytes of the staged chunks
+    pub(crate) staged_bytes: usize,
+    /// The lengths spanned by the 32-bit offsets of the staged chunks, see
+    /// [`i32_offset_lengths`]
+    pub(crate) staged_offset_lengths: Vec<usize>,
+    /// Called with every dictionary and record block right after it is written
+    pub(crate) on_block: Option<OnBlock>,
+    /// Schema versioning information that is written to the footer
+    pub(crate) schema_evolution: Option<SchemaEvolution>,
+    /// The statistics of the written chunks, if [`WriteOptions::collect_statistics`] is set
+    pub(crate) statistics: Option<FileStatistics>,
+    /// The end of the furthest footer written by [`FileWriter::checkpoint`], which the footer
+    /// written by `finish` must reach so that no stale bytes remain at the end of the file
+    pub(crate) checkpoint_end: usize,
+    /// Dictionaries kept by [`FileWriter::reset`], which are seeded when the file is started
+    pub(crate) kept_dictionaries: Option<Dictionaries>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -71,9 +174,15 @@ impl<W: Write> FileWriter<W> {
         } else {
             default_ipc_fields(schema.iter_values())
         };
+        let statistics = options
+            .collect_statistics
+            .then(|| FileStatistics::new(schema.len()));
 
         Self {
-            writer,
+            writer: ChecksumWriter {
+                inner: writer,
+                checksum: options.write_checksum.then_some(0),
+            },
             options,
             schema,
             ipc_fields,
@@ -83,16 +192,148 @@ impl<W: Write> FileWriter<W> {
             state: State::None,
             dictionary_tracker: DictionaryTracker {
                 dictionaries: Default::default(),
-                cannot_replace: true,
+                cannot_replace: !options.allow_dictionary_replacement,
+                allow_delta: options.delta_dictionaries,
+                replacements: 0,
             },
             encoded_message: Default::default(),
             custom_schema_metadata: None,
+            custom_footer_metadata: None,
+            written_rows: 0,
+            written_batches: 0,
+            footer_length: 0,
+            staged_chunks: vec![],
+            staged_bytes: 0,
+            staged_offset_lengths: vec![],
+            on_block: None,
+            schema_evolution: None,
+            statistics,
+            checkpoint_end: 0,
+            kept_dictionaries: None,
         }
     }
 
     /// Consumes itself into the inner writer
     pub fn into_inner(self) -> W {
-        self.writer
+        self.writer.inner
+    }
+
+    /// A mutable reference to the inner writer.
+    ///
+    /// Together with [`FileWriter::encode`] and [`FileWriter::record_encoded`], this allows
+    /// taking the bytes written by the writer itself, e.g. the header and footer, out of an
+    /// in-memory writer to send them elsewhere.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer.inner
+    }
+
+    /// The schema that the record batches written are validated against.
+    pub fn schema(&self) -> &ArrowSchemaRef {
+        &self.schema
+    }
+
+    /// The [`IpcField`]s of the schema, either as passed to [`FileWriter::new`] or derived from
+    /// the schema, e.g. with the dictionary IDs assigned to dictionary-encoded fields.
+    pub fn ipc_fields(&self) -> &[IpcField] {
+        &self.ipc_fields
+    }
+
+    /// The codec that the buffers of the record batches are compressed with, if any.
+    ///
+    /// Starting the file errors if the requested codec isn't compiled in, so once the file is
+    /// started this is the codec that is actually applied.
+    pub fn effective_compression(&self) -> Option<Compression> {
+        self.options.compression
+    }
+
+    /// The number of rows written so far, through [`FileWriter::write`] as well as
+    /// [`FileWriter::write_encoded`] and [`FileWriter::record_encoded`].
+    ///
+    /// This only counts the rows of record batches, not the values of dictionary batches, and is
+    /// available before the file is finished. The counter is monotonic over the lifetime of the
+    /// writer.
+    pub fn written_rows(&self) -> usize {
+        self.written_rows
+    }
+
+    /// The number of record batches written so far, excluding dictionary batches.
+    ///
+    /// The counter is monotonic over the lifetime of the writer.
+    pub fn written_batches(&self) -> usize {
+        self.written_batches
+    }
+
+    /// The record blocks written so far, in the order they were written.
+    ///
+    /// The blocks hold the offset, metadata length and (compressed) body length of each record
+    /// batch message.
+    pub fn written_blocks(&self) -> &[arrow_format::ipc::Block] {
+        &self.record_blocks
+    }
+
+    /// The dictionary blocks written so far, in the order they were written.
+    pub fn written_dictionary_blocks(&self) -> &[arrow_format::ipc::Block] {
+        &self.dictionary_blocks
+    }
+
+    /// The number of times a dictionary was replaced by one with different values so far.
+    ///
+    /// This can only be non-zero if [`WriteOptions::allow_dictionary_replacement`] is set, as
+    /// replacing a dictionary is an error otherwise.
+    pub fn dictionary_replacements(&self) -> usize {
+        self.dictionary_tracker.replacements
+    }
+
+    /// The dictionaries that were written so far.
+    ///
+    /// These can be passed to [`FileWriter::seed_dictionaries`] of another writer.
+    pub fn dictionary_tracker(&self) -> &DictionaryTracker {
+        &self.dictionary_tracker
+    }
+
+    /// Writes `dictionaries`, keyed by dictionary ID, as the first dictionary batches of the
+    /// file, so that the dictionaries of several files are identical. This is typically used
+    /// with the dictionaries of another writer of the same schema:
+    /// `writer.seed_dictionaries(&other.dictionary_tracker().dictionaries)`.
+    ///
+    /// Note that unless [`WriteOptions::allow_dictionary_replacement`] is set, the tracker
+    /// cannot replace a dictionary, so writing a chunk whose dictionary differs from a seeded
+    /// dictionary errors. Chunks must then be encoded against the seeded dictionaries, or only
+    /// extend them if [`WriteOptions::delta_dictionaries`] is set.
+    ///
+    /// # Errors
+    /// Errors if the file wasn't started or if record batches were already written.
+    pub fn seed_dictionaries(&mut self, dictionaries: &Dictionaries) -> PolarsResult<()> {
+        self.ensure_started("seed_dictionaries")?;
+        polars_ensure!(
+            self.record_blocks.is_empty() && self.staged_chunks.is_empty(),
+            InvalidOperation: "IPC dictionaries can only be seeded before any record batch is written"
+        );
+
+        let mut dict_ids = dictionaries.keys().copied().collect::<Vec<_>>();
+        dict_ids.sort_unstable();
+        let mut encoded_dictionaries = vec![];
+        for dict_id in dict_ids {
+            let values = dictionaries[&dict_id].clone();
+            // The key type isn't part of a dictionary batch.
+            let dtype = ArrowDataType::Dictionary(
+                IntegerType::Int32,
+                Box::new(values.dtype().clone()),
+                false,
+            );
+            let array =
+                DictionaryArray::<i32>::try_new(dtype, PrimitiveArray::from_vec(vec![]), values)?;
+            if self.dictionary_tracker.insert(dict_id, &array)? {
+                encode_dictionary(
+                    dict_id,
+                    &array,
+                    false,
+                    &self.options,
+                    &mut encoded_dictionaries,
+                )?;
+            }
+        }
+        self.write_encoded_dictionaries(&encoded_dictionaries)
     }
 
     /// Get the inner memory scratches so they can be reused in a new writer.
@@ -106,101 +347,483 @@ impl<W: Write> FileWriter<W> {
         self.encoded_message = scratches;
     }
 
+    /// Errors with [`IpcWriteError::NotStarted`] or [`IpcWriteError::AlreadyFinished`] if the
+    /// file isn't started, naming `operation` in the message.
+    fn ensure_started(&self, operation: &str) -> PolarsResult<()> {
+        match self.state {
+            State::Started => Ok(()),
+            State::None => Err(IpcWriteError::not_started(false, operation).into()),
+            State::Finished | State::Aborted => {
+                Err(IpcWriteError::not_started(true, operation).into())
+            },
+        }
+    }
+
     /// Writes the header and first (schema) message to the file.
     /// # Errors
     /// Errors if the file has been started or has finished.
     pub fn start(&mut self) -> PolarsResult<()> {
-        if self.state != State::None {
-            polars_bail!(oos = "The IPC file can only be started once");
+        match self.state {
+            State::None => {},
+            State::Started => return Err(IpcWriteError::AlreadyStarted.into()),
+            State::Finished | State::Aborted => {
+                return Err(IpcWriteError::not_started(true, "start").into());
+            },
         }
+        self.options.validate()?;
         // write magic to header
         self.writer.write_all(&ARROW_MAGIC_V2[..])?;
-        // create an 8-byte boundary after the header
-        self.writer.write_all(&[0, 0])?;
+        // create an aligned boundary after the header
+        let header_padding = pad_to_alignment(ARROW_MAGIC_V2.len(), self.options.alignment);
+        self.writer.write_all(&vec![0; header_padding])?;
         // write the schema, set the written bytes to the schema
 
+        // No need to pass the custom metadata here, as it is already written to the footer in
+        // `finish`. The dictionary mode is known up front, so it is written to both.
+        let dictionary_mode = self.options.write_dictionary_mode.then(|| {
+            let mut metadata = Metadata::default();
+            self.dictionary_tracker.write_dictionary_mode(&mut metadata);
+            metadata
+        });
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(
-                &self.schema,
-                &self.ipc_fields,
-                // No need to pass metadata here, as it is already written to the footer in `finish`
-                None,
-            ),
+            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields, dictionary_mode.as_ref()),
             arrow_data: vec![],
         };
 
-        let (meta, data) = write_message(&mut self.writer, &encoded_message)?;
-        self.block_offsets += meta + data + 8; // 8 <=> arrow magic + 2 bytes for alignment
+        let (meta, data) =
+            write_message(&mut self.writer, &encoded_message, self.options.alignment)?;
+        self.block_offsets += meta + data + ARROW_MAGIC_V2.len() + header_padding;
         self.state = State::Started;
+        if let Some(dictionaries) = self.kept_dictionaries.take() {
+            self.seed_dictionaries(&dictionaries)?;
+        }
         Ok(())
     }
 
+    /// Replaces the inner writer by `writer` to write another file with the same schema and
+    /// options, and returns the previous inner writer. Call [`FileWriter::finish`] first, as
+    /// the previous file is left as is, and staged chunks that weren't written yet are dropped.
+    ///
+    /// The writer is reset to the state of [`FileWriter::new`], so [`FileWriter::start`] must be
+    /// called before writing to the new file. Its blocks, statistics and dictionaries are
+    /// cleared, but its scratches (see [`FileWriter::get_scratches`]), custom metadata and
+    /// [`FileWriter::written_rows`] and [`FileWriter::written_batches`] counters are kept.
+    ///
+    /// If `keep_dictionaries`, the dictionaries of the previous file are written as the first
+    /// dictionary batches of the new file once it is started, as with
+    /// [`FileWriter::seed_dictionaries`], so that both files share their dictionaries.
+    /// Otherwise the new file starts without dictionaries.
+    pub fn reset(&mut self, writer: W, keep_dictionaries: bool) -> W {
+        let dictionaries = std::mem::take(&mut self.dictionary_tracker.dictionaries);
+        self.dictionary_tracker.reset();
+        self.kept_dictionaries =
+            (keep_dictionaries && !dictionaries.is_empty()).then_some(dictionaries);
+
+        self.writer.checksum = self.options.write_checksum.then_some(0);
+        self.block_offsets = 0;
+        self.dictionary_blocks.clear();
+        self.record_blocks.clear();
+        self.state = State::None;
+        self.footer_length = 0;
+        self.staged_chunks.clear();
+        self.staged_bytes = 0;
+        self.staged_offset_lengths.clear();
+        self.statistics = self
+            .options
+            .collect_statistics
+            .then(|| FileStatistics::new(self.schema.len()));
+        self.checkpoint_end = 0;
+        std::mem::replace(&mut self.writer.inner, writer)
+    }
+
     /// Writes [`RecordBatchT`] to the file
+    ///
+    /// If [`WriteOptions::min_record_batch_size`] is set and no custom `ipc_fields` are given, the
+    /// chunk is staged and only encoded once enough chunks have been staged. If
+    /// [`WriteOptions::skip_empty_batches`] is set, a chunk without rows isn't written at all.
     pub fn write(
         &mut self,
         chunk: &RecordBatchT<Box<dyn Array>>,
         ipc_fields: Option<&[IpcField]>,
     ) -> PolarsResult<()> {
-        if self.state != State::Started {
-            polars_bail!(
-                oos = "The IPC file must be started before it can be written to. Call `start` before `write`"
+        self.check_chunk(chunk)?;
+        if self.options.skip_empty_batches && chunk.is_empty() {
+            return Ok(());
+        }
+
+        match self.options.min_record_batch_size {
+            Some(min_record_batch_size) if ipc_fields.is_none() => {
+                // Concatenating the staged chunks must not overflow 32-bit offsets, so the
+                // staged chunks are written first if adding this chunk would.
+                let mut offset_lengths = vec![];
+                for array in chunk.arrays() {
+                    i32_offset_lengths(array.as_ref(), &mut offset_lengths);
+                }
+                if !self.staged_chunks.is_empty()
+                    && self
+                        .staged_offset_lengths
+                        .iter()
+                        .zip(&offset_lengths)
+                        .any(|(staged, length)| staged + length > i32::MAX as usize)
+                {
+                    self.flush_staged()?;
+                }
+                if self.staged_offset_lengths.is_empty() {
+                    self.staged_offset_lengths = offset_lengths;
+                } else {
+                    for (staged, length) in
+                        self.staged_offset_lengths.iter_mut().zip(offset_lengths)
+                    {
+                        *staged += length;
+                    }
+                }
+
+                self.staged_bytes += chunk
+                    .arrays()
+                    .iter()
+                    .map(|array| estimated_bytes_size(array.as_ref()))
+                    .sum::<usize>();
+                self.staged_chunks.push(chunk.clone());
+                if self.staged_bytes >= min_record_batch_size {
+                    self.flush_staged()?;
+                }
+            },
+            _ => self.write_impl(chunk, ipc_fields, None)?,
+        }
+        if let Some(statistics) = &mut self.statistics {
+            statistics.update(chunk);
+        }
+        Ok(())
+    }
+
+    /// Writes every chunk of `chunks` to the file like [`FileWriter::write`], reusing the same
+    /// scratch buffers for all of them.
+    ///
+    /// Stops at the first chunk that fails to be written, whose index is added as context to
+    /// the error. The chunks before it remain written.
+    pub fn write_all<'a, I>(
+        &mut self,
+        chunks: I,
+        ipc_fields: Option<&[IpcField]>,
+    ) -> PolarsResult<()>
+    where
+        I: IntoIterator<Item = &'a RecordBatchT<Box<dyn Array>>>,
+    {
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            self.write(chunk, ipc_fields)
+                .map_err(|err| err.context(format!("failed to write chunk {index}").into()))?;
+        }
+        Ok(())
+    }
+
+    /// Writes [`RecordBatchT`] to the file, attaching `custom_metadata` to the message of this
+    /// record batch.
+    ///
+    /// The metadata only applies to this record batch; subsequent writes don't carry it.
+    pub fn write_with_metadata(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        custom_metadata: &Metadata,
+    ) -> PolarsResult<()> {
+        self.check_chunk(chunk)?;
+        self.write_impl(chunk, ipc_fields, Some(custom_metadata))?;
+        if let Some(statistics) = &mut self.statistics {
+            statistics.update(chunk);
+        }
+        Ok(())
+    }
+
+    /// Writes the columns of `chunk` at `indices`, in that order, to the file, e.g. to drop
+    /// columns without building a new [`RecordBatchT`].
+    ///
+    /// The projected columns are written with the schema of this writer and are checked against
+    /// it like in [`FileWriter::write`]; only they are tracked for dictionaries. Errors if an
+    /// index is out of range or given more than once.
+    pub fn write_projected(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        indices: &[usize],
+        ipc_fields: Option<&[IpcField]>,
+    ) -> PolarsResult<()> {
+        let mut seen = vec![false; chunk.arrays().len()];
+        for &index in indices {
+            polars_ensure!(
+                index < seen.len(),
+                OutOfBounds: "projection index {} is out of bounds for a record batch with {} columns",
+                index, seen.len()
             );
+            polars_ensure!(
+                !std::mem::replace(&mut seen[index], true),
+                Duplicate: "projection index {} is given more than once", index
+            );
+        }
+        if indices.len() != self.schema.len() {
+            return Err(IpcWriteError::SchemaMismatch(format!(
+                "cannot write a projection of {} columns to an IPC file with {} fields",
+                indices.len(),
+                self.schema.len()
+            ))
+            .into());
         }
 
-        let ipc_fields = if let Some(ipc_fields) = ipc_fields {
-            ipc_fields
-        } else {
-            self.ipc_fields.as_ref()
+        let arrays = indices
+            .iter()
+            .map(|&index| chunk.arrays()[index].clone())
+            .collect();
+        let projected = RecordBatchT::try_new(chunk.len(), self.schema.clone(), arrays)?;
+        self.write(&projected, ipc_fields)
+    }
+
+    /// Checks that the writer has started and, if [`WriteOptions::validate_schema`] is set, that
+    /// the arrays of `chunk` match the schema of the file. A non-nullable array can be written to
+    /// a nullable field.
+    fn check_chunk(&self, chunk: &RecordBatchT<Box<dyn Array>>) -> PolarsResult<()> {
+        self.ensure_started("write")?;
+        if !self.options.validate_schema {
+            return Ok(());
+        }
+
+        if chunk.arrays().len() != self.schema.len() {
+            return Err(IpcWriteError::SchemaMismatch(format!(
+                "cannot write a record batch with {} columns to an IPC file with {} fields",
+                chunk.arrays().len(),
+                self.schema.len()
+            ))
+            .into());
+        }
+        for (i, (array, field)) in chunk
+            .arrays()
+            .iter()
+            .zip(self.schema.iter_values())
+            .enumerate()
+        {
+            if array.dtype() != field.dtype() {
+                return Err(IpcWriteError::SchemaMismatch(format!(
+                    "cannot write column {} ('{}') of type {:?} to an IPC file field of type {:?}",
+                    i,
+                    field.name,
+                    array.dtype(),
+                    field.dtype()
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Concatenates the staged chunks and writes them as a single record batch.
+    ///
+    /// The staged chunks are taken before writing, so this is a no-op when called recursively.
+    fn flush_staged(&mut self) -> PolarsResult<()> {
+        self.staged_bytes = 0;
+        self.staged_offset_lengths.clear();
+        let chunks = std::mem::take(&mut self.staged_chunks);
+        let chunk = match chunks.len() {
+            0 => return Ok(()),
+            1 => chunks.into_iter().next().unwrap(),
+            _ => {
+                let length = chunks.iter().map(|chunk| chunk.len()).sum();
+                let arrays = (0..self.schema.len())
+                    .map(|i| {
+                        let arrays = chunks
+                            .iter()
+                            .map(|chunk| chunk.arrays()[i].as_ref())
+                            .collect::<Vec<_>>();
+                        concatenate(&arrays)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                RecordBatchT::try_new(length, self.schema.clone(), arrays)?
+            },
+        };
+        self.encode_and_write(&chunk, None, None)
+    }
+
+    fn write_impl(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        custom_metadata: Option<&Metadata>,
+    ) -> PolarsResult<()> {
+        // Keep the order of the written rows.
+        self.flush_staged()?;
+        self.encode_and_write(chunk, ipc_fields, custom_metadata)
+    }
+
+    fn encode_and_write(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        custom_metadata: Option<&Metadata>,
+    ) -> PolarsResult<()> {
+        let Some(max_body_length) = self.options.max_body_length else {
+            let encoded_dictionaries = encode_chunk_amortized(
+                chunk,
+                ipc_fields.unwrap_or(&self.ipc_fields),
+                &mut self.dictionary_tracker,
+                &self.options,
+                custom_metadata,
+                &mut self.encoded_message,
+            )?;
+
+            let encoded_message = std::mem::take(&mut self.encoded_message);
+            self.write_encoded(&encoded_dictionaries[..], &encoded_message)?;
+            self.encoded_message = encoded_message;
+            return Ok(());
         };
+
+        // Encoding the record batches doesn't track any dictionaries, so they are all encoded
+        // before anything is tracked or written. A chunk that can't be split to fit the maximum
+        // body length thus leaves the writer as it was.
+        let mut encoded_messages = vec![];
+        encode_split_record_batches(
+            chunk,
+            &self.schema,
+            &self.options,
+            max_body_length,
+            custom_metadata,
+            &mut encoded_messages,
+        )?;
+
+        // Slicing keeps the dictionary values, so the record batches share the dictionaries of
+        // the chunk, which are written once before them.
+        let mut encoded_dictionaries = vec![];
+        for (field, array) in ipc_fields
+            .unwrap_or(&self.ipc_fields)
+            .iter()
+            .zip(chunk.arrays())
+        {
+            encode_new_dictionaries(
+                field,
+                array.as_ref(),
+                &self.options,
+                &mut self.dictionary_tracker,
+                &mut encoded_dictionaries,
+            )?;
+        }
+        self.write_encoded_dictionaries(&encoded_dictionaries)?;
+        for encoded_message in &encoded_messages {
+            self.write_encoded_record_batch(encoded_message)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the dictionaries and the record batch of `chunk` without writing them.
+    ///
+    /// The dictionaries are tracked by this writer, so only new or replaced dictionaries are
+    /// returned. The encoded messages must be written in order, dictionaries first, with
+    /// [`write_message`](super::write_message) using [`WriteOptions::alignment`], after which
+    /// [`FileWriter::record_encoded`] adds them to the footer. Unlike [`FileWriter::write`],
+    /// neither [`WriteOptions::min_record_batch_size`] nor [`WriteOptions::max_body_length`]
+    /// apply.
+    pub fn encode(
+        &mut self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+    ) -> PolarsResult<(Vec<EncodedData>, EncodedData)> {
+        self.check_chunk(chunk)?;
+        let mut encoded_message = EncodedData::default();
         let encoded_dictionaries = encode_chunk_amortized(
             chunk,
-            ipc_fields,
+            ipc_fields.unwrap_or(&self.ipc_fields),
             &mut self.dictionary_tracker,
             &self.options,
-            &mut self.encoded_message,
+            None,
+            &mut encoded_message,
         )?;
+        Ok((encoded_dictionaries, encoded_message))
+    }
 
-        let encoded_message = std::mem::take(&mut self.encoded_message);
-        self.write_encoded(&encoded_dictionaries[..], &encoded_message)?;
-        self.encoded_message = encoded_message;
+    /// Adds the blocks of dictionary batches and a record batch of `num_rows` rows to the
+    /// footer, without writing them, and returns the block of the record batch.
+    ///
+    /// The caller is expected to have written the messages right after the bytes written so far,
+    /// see [`FileWriter::encode`]. As this writer doesn't see the bytes, this can't be used
+    /// together with [`WriteOptions::write_checksum`].
+    pub fn record_encoded(
+        &mut self,
+        encoded_dictionaries: &[EncodedData],
+        encoded_message: &EncodedData,
+        num_rows: usize,
+    ) -> PolarsResult<arrow_format::ipc::Block> {
+        self.ensure_started("record_encoded")?;
+        polars_ensure!(
+            self.writer.checksum.is_none(),
+            InvalidOperation: "messages written by the caller can't be included in the checksum of the IPC file"
+        );
+        polars_ensure!(
+            self.staged_chunks.is_empty(),
+            InvalidOperation: "messages written by the caller must not be interleaved with staged chunks"
+        );
 
-        Ok(())
+        for encoded_dictionary in encoded_dictionaries {
+            let (meta, data) = message_lengths(encoded_dictionary, self.options.alignment);
+            self.add_dictionary_block(meta, data);
+        }
+        let (meta, data) = message_lengths(encoded_message, self.options.alignment);
+        let block = self.add_record_block(meta, data);
+        self.written_rows += num_rows;
+        Ok(block)
     }
 
+    /// Writes dictionary batches and a record batch that were already encoded, e.g. with
+    /// [`encode_chunk`](super::encode_chunk), and adds their blocks to the footer.
     pub fn write_encoded(
         &mut self,
         encoded_dictionaries: &[EncodedData],
         encoded_message: &EncodedData,
     ) -> PolarsResult<()> {
-        if self.state != State::Started {
-            polars_bail!(
-                oos = "The IPC file must be started before it can be written to. Call `start` before `write`"
-            );
-        }
+        self.ensure_started("write")?;
 
-        // add all dictionaries
-        for encoded_dictionary in encoded_dictionaries {
-            let (meta, data) = write_message(&mut self.writer, encoded_dictionary)?;
-
-            let block = arrow_format::ipc::Block {
-                offset: self.block_offsets as i64,
-                meta_data_length: meta as i32,
-                body_length: data as i64,
-            };
-            self.dictionary_blocks.push(block);
-            self.block_offsets += meta + data;
-        }
+        // Keep the order of the written rows.
+        self.flush_staged()?;
 
+        self.write_encoded_dictionaries(encoded_dictionaries)?;
         self.write_encoded_record_batch(encoded_message)?;
 
         Ok(())
     }
 
+    pub(crate) fn write_encoded_dictionaries(
+        &mut self,
+        encoded_dictionaries: &[EncodedData],
+    ) -> PolarsResult<()> {
+        for encoded_dictionary in encoded_dictionaries {
+            let (meta, data) =
+                write_message(&mut self.writer, encoded_dictionary, self.options.alignment)?;
+            self.add_dictionary_block(meta, data);
+        }
+        Ok(())
+    }
+
+    fn add_dictionary_block(&mut self, meta: usize, data: usize) {
+        let block = arrow_format::ipc::Block {
+            offset: self.block_offsets as i64,
+            meta_data_length: meta as i32,
+            body_length: data as i64,
+        };
+        self.dictionary_blocks.push(block);
+        self.block_offsets += meta + data;
+        if let Some(on_block) = &mut self.on_block {
+            on_block(BlockKind::Dictionary, &block);
+        }
+    }
+
+    /// Writes an encoded record batch to the file and returns its [`Block`](arrow_format::ipc::Block).
     pub fn write_encoded_record_batch(
         &mut self,
         encoded_message: &EncodedData,
-    ) -> PolarsResult<()> {
-        let (meta, data) = write_message(&mut self.writer, encoded_message)?;
+    ) -> PolarsResult<arrow_format::ipc::Block> {
+        self.flush_staged()?;
+        let num_rows = record_batch_length(&encoded_message.ipc_message)?;
+        let (meta, data) =
+            write_message(&mut self.writer, encoded_message, self.options.alignment)?;
+        let block = self.add_record_block(meta, data);
+        self.written_rows += num_rows;
+        Ok(block)
+    }
+
+    fn add_record_block(&mut self, meta: usize, data: usize) -> arrow_format::ipc::Block {
         // add a record block for the footer
         let block = arrow_format::ipc::Block {
             offset: self.block_offsets as i64,
@@ -209,48 +832,399 @@ impl<W: Write> FileWriter<W> {
         };
         self.record_blocks.push(block);
         self.block_offsets += meta + data;
-
-        Ok(())
+        self.written_batches += 1;
+        if let Some(on_block) = &mut self.on_block {
+            on_block(BlockKind::Record, &block);
+        }
+        block
     }
 
     /// Write footer and closing tag, then mark the writer as done
-    pub fn finish(&mut self) -> PolarsResult<()> {
-        if self.state != State::Started {
-            polars_bail!(
-                oos = "The IPC file must be started before it can be finished. Call `start` before `finish`"
-            );
-        }
+    ///
+    /// Returns the [`FileLayout`] of the written file.
+    pub fn finish(&mut self) -> PolarsResult<FileLayout> {
+        self.ensure_started("finish")?;
 
-        // write EOS
-        write_continuation(&mut self.writer, 0)?;
+        self.flush_staged()?;
+        self.footer_length = self.write_footer()?;
+        self.state = State::Finished;
+
+        Ok(FileLayout {
+            dictionary_blocks: self.dictionary_blocks.clone(),
+            record_blocks: self.record_blocks.clone(),
+            file_length: self.bytes_written(),
+        })
+    }
+
+    /// Writes the EOS marker, the footer and the trailing magic, and returns the number of
+    /// bytes written.
+    fn write_footer(&mut self) -> PolarsResult<usize> {
+        // The EOS is only written once the footer is known to fit in `footer_padding`.
+        let mut eos_marker = vec![];
+        write_continuation(&mut eos_marker, 0)?;
+        let eos: &[u8] = if self.options.write_eos {
+            &eos_marker
+        } else {
+            &[]
+        };
 
+        let mut custom_schema_metadata = self
+            .options
+            .schema_metadata(self.custom_schema_metadata.as_deref());
+        if let Some(schema_evolution) = &self.schema_evolution {
+            schema_evolution.write_metadata(
+                custom_schema_metadata
+                    .get_or_insert_with(Default::default)
+                    .to_mut(),
+            );
+        }
+        if self.options.write_dictionary_mode {
+            self.dictionary_tracker.write_dictionary_mode(
+                custom_schema_metadata
+                    .get_or_insert_with(Default::default)
+                    .to_mut(),
+            );
+        }
         let schema = schema::serialize_schema(
             &self.schema,
             &self.ipc_fields,
-            self.custom_schema_metadata.as_deref(),
+            custom_schema_metadata.as_deref(),
         );
 
+        // The checksum covers everything up to and including the EOS marker.
+        let mut footer_metadata = self
+            .custom_footer_metadata
+            .as_deref()
+            .or(self.custom_schema_metadata.as_deref())
+            .cloned();
+        #[cfg(feature = "io_ipc_checksum")]
+        if let Some(checksum) = self.writer.checksum {
+            let checksum = crc32c::crc32c_append(checksum, eos);
+            footer_metadata.get_or_insert_default().insert(
+                PlSmallStr::from_static(IPC_CHECKSUM_KEY),
+                PlSmallStr::from(checksum.to_string()),
+            );
+        }
+        if let Some(statistics) = &self.statistics {
+            footer_metadata.get_or_insert_default().insert(
+                PlSmallStr::from_static(IPC_STATISTICS_KEY),
+                PlSmallStr::from(statistics.to_json(&self.schema)),
+            );
+        }
+
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
             schema: Some(Box::new(schema)),
-            dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
-            record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            dictionaries: Some(self.dictionary_blocks.clone()),
+            record_batches: Some(self.record_blocks.clone()),
+            custom_metadata: footer_metadata
+                .as_ref()
+                .and_then(schema::serialize_custom_metadata),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
+        let footer_length = match self.options.footer_padding {
+            Some(footer_padding) => {
+                polars_ensure!(
+                    footer_data.len() <= footer_padding,
+                    ComputeError: "the IPC footer of {} bytes exceeds the reserved footer size of {} bytes",
+                    footer_data.len(), footer_padding
+                );
+                footer_padding
+            },
+            None => footer_data.len(),
+        };
+        // Cover the footer of an earlier checkpoint that extends beyond this footer.
+        let footer_length = footer_length.max(
+            self.checkpoint_end
+                .saturating_sub(self.block_offsets + eos.len() + 4 + ARROW_MAGIC_V2.len()),
+        );
+        polars_ensure!(
+            footer_length <= i32::MAX as usize,
+            ComputeError: "the IPC footer of {} bytes exceeds the maximum footer size", footer_length
+        );
+
+        self.writer.write_all(eos)?;
         self.writer.write_all(footer_data)?;
+        // Pad the footer with EOS markers, which are ignored by readers of the footer.
+        let mut padding = footer_length - footer_data.len();
+        while padding >= eos_marker.len() {
+            self.writer.write_all(&eos_marker)?;
+            padding -= eos_marker.len();
+        }
+        self.writer.write_all(&vec![0; padding])?;
         self.writer
-            .write_all(&(footer_data.len() as i32).to_le_bytes())?;
+            .write_all(&(footer_length as i32).to_le_bytes())?;
         self.writer.write_all(&ARROW_MAGIC_V2)?;
         self.writer.flush()?;
-        self.state = State::Finished;
+        Ok(eos.len() + footer_length + 4 + ARROW_MAGIC_V2.len())
+    }
+
+    /// Finishes the file, unless it was already finished, and returns the inner writer.
+    pub fn finish_into_inner(mut self) -> PolarsResult<W> {
+        if self.state != State::Finished {
+            self.finish()?;
+        }
+        Ok(self.writer.inner)
+    }
 
+    /// Writes the staged chunks, if any, and flushes the inner writer, without finishing the
+    /// file. More record batches can be written afterwards.
+    ///
+    /// This can be called periodically on a long-lived file to make the bytes written so far
+    /// durable. Before the file is started, only the inner writer is flushed.
+    ///
+    /// # Errors
+    /// Errors if the file has finished.
+    pub fn flush(&mut self) -> PolarsResult<()> {
+        if self.state != State::None {
+            self.ensure_started("flush")?;
+            self.flush_staged()?;
+        }
+        self.writer.flush()?;
         Ok(())
     }
 
-    /// Sets custom schema metadata. Must be called before `start` is called
+    /// The offset in bytes at which the next message is written, i.e. the number of bytes
+    /// written to the file so far.
+    pub fn current_offset(&self) -> usize {
+        self.block_offsets
+    }
+
+    /// The number of bytes written to the file so far, including the header and, once finished,
+    /// the footer and trailing magic.
+    ///
+    /// For a writer that appends to an existing file, this includes the bytes of the existing
+    /// messages.
+    pub fn bytes_written(&self) -> u64 {
+        (self.block_offsets + self.footer_length) as u64
+    }
+
+    /// Abort writing the file and return the inner writer, without writing the footer.
+    ///
+    /// Nothing is written or flushed; the bytes already written to the inner writer are
+    /// **not** a valid Arrow IPC file, so the caller is expected to truncate or discard them.
+    /// This can be called in any state as an alternative to [`FileWriter::finish`].
+    pub fn abort(mut self) -> PolarsResult<W> {
+        self.state = State::Aborted;
+        Ok(self.writer.inner)
+    }
+
+    /// Sets custom schema metadata. Can be called any time before `finish` is called.
+    ///
+    /// The metadata is written to the schema in the footer, as the schema message written by
+    /// `start` doesn't carry metadata, and also to the footer's `custom_metadata` unless custom
+    /// footer metadata is set with [`FileWriter::set_custom_footer_metadata`].
     pub fn set_custom_schema_metadata(&mut self, custom_metadata: Arc<Metadata>) {
         self.custom_schema_metadata = Some(custom_metadata);
     }
+
+    /// Sets a callback that is called with every dictionary and record block right after it is
+    /// written, e.g. to record the offsets of the blocks before the footer is written.
+    pub fn set_on_block(
+        &mut self,
+        on_block: impl FnMut(BlockKind, &arrow_format::ipc::Block) + Send + 'static,
+    ) {
+        self.on_block = Some(Box::new(on_block));
+    }
+
+    /// Sets the schema versioning information, which is written to the schema metadata of the
+    /// footer. Can be called any time before `finish` is called.
+    pub fn set_schema_evolution(&mut self, schema_evolution: SchemaEvolution) {
+        self.schema_evolution = Some(schema_evolution);
+    }
+
+    /// Sets custom footer metadata. Can be called any time before `finish` is called.
+    pub fn set_custom_footer_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.custom_footer_metadata = Some(custom_metadata);
+    }
+}
+
+impl<W: Write + Seek> FileWriter<W> {
+    /// Writes the staged chunks, if any, and a footer and trailing magic covering the record
+    /// batches written so far, without finishing the file.
+    ///
+    /// The inner writer is then sought back to the start of the footer, so that more record
+    /// batches can be written afterwards. The file is readable up to this point until the next
+    /// message is written, which overwrites the footer. This is not crash-safe: if the process
+    /// crashes while a later message is being written, the file is left without a valid footer
+    /// and none of its record batches can be read through it. A checkpoint only protects
+    /// against crashes while no message is being written, e.g. while a long-lived writer waits
+    /// for its next batch.
+    pub fn checkpoint(&mut self) -> PolarsResult<()> {
+        self.ensure_started("checkpoint")?;
+        self.flush_staged()?;
+
+        // The footer isn't part of the checksum of the finished file.
+        let checksum = self.writer.checksum;
+        let footer_length = self.write_footer()?;
+        self.writer.checksum = checksum;
+        self.writer
+            .inner
+            .seek(SeekFrom::Current(-(footer_length as i64)))?;
+        self.checkpoint_end = self.checkpoint_end.max(self.block_offsets + footer_length);
+        Ok(())
+    }
+}
+
+/// A writer that can reserve room for bytes that are about to be written to it.
+///
+/// This lets [`FileWriter::with_capacity_hint`] reserve up front, e.g. with the size given by
+/// [`estimate_ipc_size`](super::estimate_ipc_size). Writers that can't reserve use the default
+/// implementation, which does nothing.
+pub trait ReserveCapacity: Write {
+    /// Reserves room for at least `additional` more bytes.
+    ///
+    /// Returns whether the existing capacity was enough, i.e. nothing was allocated.
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        let _ = additional;
+        true
+    }
+}
+
+impl ReserveCapacity for Vec<u8> {
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        let reused = self.capacity() - self.len() >= additional;
+        self.reserve(additional);
+        reused
+    }
+}
+
+impl ReserveCapacity for Cursor<Vec<u8>> {
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        reserve_cursor_capacity(self, additional)
+    }
+}
+
+impl ReserveCapacity for Cursor<&mut Vec<u8>> {
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        reserve_cursor_capacity(self, additional)
+    }
+}
+
+/// Reserves room for `additional` bytes after the position of `cursor`, which may be before
+/// the end of its vector.
+fn reserve_cursor_capacity<T: AsMut<Vec<u8>>>(cursor: &mut Cursor<T>, additional: usize) -> bool {
+    let position = usize::try_from(cursor.position()).unwrap_or(usize::MAX);
+    let vec = cursor.get_mut().as_mut();
+    let additional = position
+        .saturating_add(additional)
+        .saturating_sub(vec.len());
+    vec.reserve_capacity(additional)
+}
+
+impl<W: ReserveCapacity> ReserveCapacity for BufWriter<W> {
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        self.get_mut().reserve_capacity(additional)
+    }
+}
+
+impl<W: ReserveCapacity + ?Sized> ReserveCapacity for &mut W {
+    fn reserve_capacity(&mut self, additional: usize) -> bool {
+        (**self).reserve_capacity(additional)
+    }
+}
+
+impl ReserveCapacity for std::fs::File {}
+
+impl<W: ReserveCapacity> FileWriter<W> {
+    /// Reserves room for at least `bytes` more bytes in the inner writer, to avoid reallocating
+    /// it while writing, e.g. when writing to a [`Vec<u8>`]. This is a no-op for writers that
+    /// can't reserve.
+    ///
+    /// Returns whether the existing capacity of the inner writer was enough, i.e. whether it
+    /// could be reused without allocating.
+    pub fn with_capacity_hint(&mut self, bytes: usize) -> bool {
+        self.writer.inner.reserve_capacity(bytes)
+    }
+}
+
+impl<W: Write> FileWriter<BufWriter<W>> {
+    /// Creates a new [`FileWriter`] that buffers the writes to `writer` in a [`BufWriter`] with
+    /// the default capacity, and writes the header.
+    pub fn try_new_buffered(
+        writer: W,
+        schema: ArrowSchemaRef,
+        ipc_fields: Option<Vec<IpcField>>,
+        options: WriteOptions,
+    ) -> PolarsResult<Self> {
+        Self::try_new(BufWriter::new(writer), schema, ipc_fields, options)
+    }
+
+    /// Creates a new [`FileWriter`] that buffers the writes to `writer` in a [`BufWriter`] with
+    /// a buffer of at least `capacity` bytes, and writes the header.
+    pub fn try_new_buffered_with_capacity(
+        capacity: usize,
+        writer: W,
+        schema: ArrowSchemaRef,
+        ipc_fields: Option<Vec<IpcField>>,
+        options: WriteOptions,
+    ) -> PolarsResult<Self> {
+        Self::try_new(
+            BufWriter::with_capacity(capacity, writer),
+            schema,
+            ipc_fields,
+            options,
+        )
+    }
+
+    /// Flushes the buffer and consumes itself into the unbuffered inner writer.
+    pub fn into_inner_unbuffered(self) -> PolarsResult<W> {
+        self.into_inner()
+            .into_inner()
+            .map_err(|err| err.into_error().into())
+    }
+}
+
+/// Pushes the length spanned by the offsets of every array with 32-bit offsets in `array` to
+/// `out`, in an order that only depends on the data type. Child arrays are not sliced with their
+/// parent, so the lengths of nested arrays are upper bounds.
+fn i32_offset_lengths(array: &dyn Array, out: &mut Vec<usize>) {
+    match array.dtype().to_physical_type() {
+        PhysicalType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            out.push(array.offsets().range() as usize);
+        },
+        PhysicalType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            out.push(array.offsets().range() as usize);
+        },
+        PhysicalType::List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            out.push(array.offsets().range() as usize);
+            i32_offset_lengths(array.values().as_ref(), out);
+        },
+        PhysicalType::LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            i32_offset_lengths(array.values().as_ref(), out);
+        },
+        PhysicalType::FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            i32_offset_lengths(array.values().as_ref(), out);
+        },
+        PhysicalType::Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            out.push(array.offsets().range() as usize);
+            i32_offset_lengths(array.field().as_ref(), out);
+        },
+        PhysicalType::Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            for values in array.values() {
+                i32_offset_lengths(values.as_ref(), out);
+            }
+        },
+        PhysicalType::Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            for field in array.fields() {
+                i32_offset_lengths(field.as_ref(), out);
+            }
+        },
+        PhysicalType::Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                i32_offset_lengths(array.values().as_ref(), out);
+            })
+        },
+        _ => {},
+    }
 }
@@ -781,7 +781,8 @@ impl NativeType for i256 {
     #[inline]
     fn to_le_bytes(&self) -> Self::Bytes {
         let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
+        // The low word comes first in little-endian order.
+        let (b, a) = self.0.into_words();
         let a = a.to_le_bytes();
         (0..16).for_each(|i| {
             bytes[i] = a[i];
@@ -857,4 +858,18 @@ mod test {
         assert_eq!(format!("{}", f16::from_f32(7.0)), "7".to_string());
         assert_eq!(format!("{:?}", f16::from_f32(7.0)), "7.0".to_string());
     }
+
+    #[test]
+    fn test_i256_bytes() {
+        let x = i256(ethnum::I256::from_words(1, -2));
+        let le = x.to_le_bytes();
+        assert_eq!(le[..16], (-2i128).to_le_bytes());
+        assert_eq!(le[16..], 1i128.to_le_bytes());
+        assert_eq!(i256::from_le_bytes(le), x);
+
+        let be = x.to_be_bytes();
+        assert_eq!(be[..16], 1i128.to_be_bytes());
+        assert_eq!(be[16..], (-2i128).to_be_bytes());
+        assert_eq!(i256::from_be_bytes(be), x);
+    }
 }
@@ -269,6 +269,7 @@ where
             &mut self.writer,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                ..Default::default()
             },
         );
 
@@ -1,7 +1,8 @@
 use std::io::Write;
 
 use arrow::datatypes::Metadata;
-use arrow::io::ipc::write::{self, EncodedData, WriteOptions};
+use arrow::io::ipc::write::{self, CompressionThreadPool, EncodedData, WriteOptions};
+use polars_core::POOL;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -66,6 +67,7 @@ impl IpcWriterOptions {
 pub struct IpcWriter<W> {
     pub(super) writer: W,
     pub(super) compression: Option<IpcCompression>,
+    pub(super) compression_level: Option<i32>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) compat_level: CompatLevel,
     pub(super) parallel: bool,
@@ -79,11 +81,21 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Set the compression level. Defaults to None, which uses the codec's default level.
+    ///
+    /// Writing errors if the level is not valid for the chosen compression codec.
+    pub fn with_compression_level(mut self, compression_level: Option<i32>) -> Self {
+        self.compression_level = compression_level;
+        self
+    }
+
     pub fn with_compat_level(mut self, compat_level: CompatLevel) -> Self {
         self.compat_level = compat_level;
         self
     }
 
+    /// Set whether the chunks are aligned and the buffers are compressed in parallel, on the
+    /// polars thread pool. Defaults to true.
     pub fn with_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
         self
@@ -97,6 +109,10 @@ impl<W: Write> IpcWriter<W> {
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                compression_level: self.compression_level,
+                parallel_compression: self.parallel,
+                compression_thread_pool: Some(CompressionThreadPool(&POOL)),
+                ..Default::default()
             },
         );
         writer.start()?;
@@ -121,6 +137,7 @@ where
         IpcWriter {
             writer,
             compression: None,
+            compression_level: None,
             compat_level: CompatLevel::newest(),
             parallel: true,
             custom_schema_metadata: None,
@@ -135,6 +152,10 @@ where
             None,
             WriteOptions {
                 compression: self.compression.map(|c| c.into()),
+                compression_level: self.compression_level,
+                parallel_compression: self.parallel,
+                compression_thread_pool: Some(CompressionThreadPool(&POOL)),
+                ..Default::default()
             },
         )?;
         if let Some(custom_metadata) = &self.custom_schema_metadata {
@@ -0,0 +1,25 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use polars_core::prelude::*;
+use polars_ops::series::is_business_day;
+
+const NUM_DATES: i32 = 1_000_000;
+const NUM_HOLIDAYS: i32 = 1_000;
+
+fn add_benchmark(c: &mut Criterion) {
+    let dates = Int32Chunked::from_vec("dates".into(), (0..NUM_DATES).collect())
+        .into_date()
+        .into_series();
+    let week_mask = [true, true, true, true, true, false, false];
+
+    // Holidays every few days over a couple of decades, and spread thinly over many centuries.
+    for (name, step) in [("dense", 7), ("sparse", 997)] {
+        let holidays = (0..NUM_HOLIDAYS).map(|i| i * step).collect::<Vec<_>>();
+        c.bench_function(
+            &format!("is_business_day {NUM_DATES} dates {NUM_HOLIDAYS} {name} holidays"),
+            |b| b.iter(|| is_business_day(&dates, week_mask, &holidays).unwrap()),
+        );
+    }
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
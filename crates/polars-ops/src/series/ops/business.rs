@@ -1,37 +1,216 @@
-#[cfg(feature = "dtype-date")]
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "business_day_count")]
+use arrow::bitmap::BitmapBuilder;
+#[cfg(feature = "business_day_count")]
+use arrow::offset::Offsets;
+#[cfg(feature = "add_business_days")]
 use chrono::DateTime;
-use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
+use chrono::Datelike;
+#[cfg(feature = "add_business_days")]
+use polars_core::prelude::arity::try_binary_elementwise;
+#[cfg(feature = "business_day_count")]
+use polars_core::prelude::arity::{binary_elementwise_values, broadcast_binary_elementwise_values};
+#[cfg(feature = "is_business_day")]
+use polars_core::prelude::arity::{unary_elementwise, unary_elementwise_values};
 use polars_core::prelude::*;
-#[cfg(feature = "dtype-date")]
+#[cfg(feature = "add_business_days")]
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
-use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
+use polars_core::utils::arrow::temporal_conversions::date32_to_date;
+#[cfg(feature = "business_day_count")]
+use polars_core::utils::arrow::temporal_conversions::{
+    MICROSECONDS, MICROSECONDS_IN_DAY, NANOSECONDS_IN_DAY,
+};
+use polars_utils::binary_search::find_first_ge_index;
+#[cfg(feature = "add_business_days")]
+use polars_utils::binary_search::find_first_gt_index;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "timezones")]
 use crate::prelude::replace_time_zone;
+#[cfg(feature = "business_day_count")]
+use crate::series::ClosedInterval;
 
+#[cfg(feature = "add_business_days")]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Roll {
     Forward,
     Backward,
     Raise,
+    /// Roll to the nearest business day, preferring the next one on ties if `prefer_forward`
+    /// and the previous one otherwise.
+    Nearest {
+        prefer_forward: bool,
+    },
+}
+
+/// Holidays, given as individual days and as inclusive ranges of days.
+///
+/// All days are given as the number of days since the UNIX epoch. Holidays are compared and
+/// hashed by the days they contain, so the order in which they are given doesn't matter.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Holidays {
+    /// Individual holidays.
+    pub singles: Vec<i32>,
+    /// Inclusive `(start, end)` ranges of holidays. Ranges that start after their end are empty.
+    pub ranges: Vec<(i32, i32)>,
+}
+
+/// The largest number of days that [`Holidays`] may contain, about 2,900 years of daily
+/// holidays, so that expanding them with [`Holidays::to_days`] stays within 4 MiB.
+pub const MAX_HOLIDAY_DAYS: i64 = 1 << 20;
+
+impl Holidays {
+    /// The number of distinct days among the holidays, without expanding ranges.
+    pub fn n_days(&self) -> i64 {
+        self.merged_ranges()
+            .iter()
+            .map(|&(start, end)| end as i64 - start as i64 + 1)
+            .sum()
+    }
+
+    /// Expand the holidays into a sorted list of individual days, without duplicates.
+    ///
+    /// Every day of the ranges is allocated, so the holidays should contain at most
+    /// [`MAX_HOLIDAY_DAYS`] days.
+    pub fn to_days(&self) -> Vec<i32> {
+        let ranges = self.merged_ranges();
+        let n_days: i64 = ranges
+            .iter()
+            .map(|&(start, end)| end as i64 - start as i64 + 1)
+            .sum();
+        let mut days = Vec::with_capacity(n_days as usize);
+        for (start, end) in ranges {
+            days.extend(start..=end);
+        }
+        days
+    }
+
+    /// The holidays as sorted, disjoint and non-adjacent inclusive ranges, with the singles
+    /// taken as ranges of one day.
+    ///
+    /// This is the canonical form of the holidays: holidays with the same days have the same
+    /// merged ranges, however they are given.
+    fn merged_ranges(&self) -> Vec<(i32, i32)> {
+        let mut ranges: Vec<(i32, i32)> = self
+            .singles
+            .iter()
+            .map(|&day| (day, day))
+            .chain(
+                self.ranges
+                    .iter()
+                    .copied()
+                    .filter(|(start, end)| start <= end),
+            )
+            .collect();
+        ranges.sort_unstable();
+        let mut merged: Vec<(i32, i32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start as i64 <= last.1 as i64 + 1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+}
+
+impl PartialEq for Holidays {
+    fn eq(&self, other: &Self) -> bool {
+        self.merged_ranges() == other.merged_ranges()
+    }
+}
+
+impl Eq for Holidays {}
+
+impl Hash for Holidays {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.merged_ranges().hash(state);
+    }
+}
+
+impl From<Vec<i32>> for Holidays {
+    fn from(singles: Vec<i32>) -> Self {
+        Self {
+            singles,
+            ranges: Vec::new(),
+        }
+    }
+}
+
+/// The unit in which [`business_day_count_in_unit`] expresses the business day count, and in
+/// which [`add_business_days_in_unit`] takes the number of business days to add.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BusinessDayCountUnit {
+    #[default]
+    Days,
+    /// The number of business days divided by the number of business days in `week_mask`.
+    Weeks,
+}
+
+/// The calendar period by which [`business_day_count_by_period`] buckets business days.
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BusinessPeriod {
+    /// ISO weeks, starting on Monday.
+    Week,
+    Month,
+    Quarter,
+}
+
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
+impl BusinessPeriod {
+    /// The first day of the period that `date` is in.
+    #[cfg(feature = "business_day_count")]
+    fn start(self, date: i32) -> i32 {
+        match self {
+            Self::Week => date - get_day_of_week(date) as i32,
+            Self::Month => first_of_month(date),
+            Self::Quarter => {
+                let mut start = first_of_month(date);
+                for _ in 0..date32_to_date(date).month0() % 3 {
+                    start = first_of_month(start - 1);
+                }
+                start
+            },
+        }
+    }
+
+    /// The first day of the period after the one that starts on `start`.
+    fn next_start(self, start: i32) -> i32 {
+        // Months have at most 31 days, so the 31st day after the first of a month is in the
+        // next month.
+        match self {
+            Self::Week => start + 7,
+            Self::Month => first_of_month(start + 31),
+            Self::Quarter => (0..3).fold(start, |start, _| first_of_month(start + 31)),
+        }
+    }
 }
 
-/// Count the number of business days between `start` and `end`, excluding `end`.
+/// Count the number of business days between `start` and `end`.
 ///
 /// # Arguments
-/// - `start`: Series holding start dates.
-/// - `end`: Series holding end dates.
+/// - `start`: Series holding start dates or datetimes. Time zone aware datetimes are converted to
+///   dates in their own time zone, not in UTC.
+/// - `end`: Series holding end dates or datetimes.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `closed`: which of `start` (left) and `end` (right) are counted if they are business days.
+///   `ClosedInterval::Left` counts the half-open interval `[start, end)`.
+#[cfg(feature = "business_day_count")]
 pub fn business_day_count(
     start: &Series,
     end: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    closed: ClosedInterval,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
@@ -39,17 +218,20 @@ pub fn business_day_count(
 
     // Sort now so we can use `binary_search` in the hot for-loop.
     let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_date(start)?;
+    let end = to_local_date(end)?;
     let start_dates = start.date()?;
     let end_dates = end.date()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
 
     let out = match (start_dates.len(), end_dates.len()) {
         (_, 1) => {
             if let Some(end_date) = end_dates.get(0) {
                 start_dates.apply_values(|start_date| {
-                    business_day_count_impl(
+                    business_day_count_closed(
                         start_date,
                         end_date,
+                        closed,
                         &week_mask,
                         n_business_days_in_week_mask,
                         &holidays,
@@ -62,9 +244,10 @@ pub fn business_day_count(
         (1, _) => {
             if let Some(start_date) = start_dates.get(0) {
                 end_dates.apply_values(|end_date| {
-                    business_day_count_impl(
+                    business_day_count_closed(
                         start_date,
                         end_date,
+                        closed,
                         &week_mask,
                         n_business_days_in_week_mask,
                         &holidays,
@@ -82,9 +265,10 @@ pub fn business_day_count(
                 end_dates.len()
             );
             binary_elementwise_values(start_dates, end_dates, |start_date, end_date| {
-                business_day_count_impl(
+                business_day_count_closed(
                     start_date,
                     end_date,
+                    closed,
                     &week_mask,
                     n_business_days_in_week_mask,
                     &holidays,
@@ -95,15 +279,410 @@ pub fn business_day_count(
     Ok(out.into_series())
 }
 
+/// Count the number of business days between `start` and `end`, expressed in `unit`.
+///
+/// # Arguments
+/// - `start`, `end`, `week_mask`, `holidays`, `closed`: see [`business_day_count`].
+/// - `unit`: whether to count business days or business weeks.
+/// - `hours_per_day`: if given, the business day count is converted to business hours. Can only
+///   be used with [`BusinessDayCountUnit::Days`].
+///
+/// A count of days is returned as `Int32`; a count of weeks or hours is returned as `Float64`.
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_in_unit(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> PolarsResult<Series> {
+    check_hours_per_day(unit, hours_per_day)?;
+
+    let count = business_day_count(start, end, week_mask, holidays, closed)?;
+    match (unit, hours_per_day) {
+        (BusinessDayCountUnit::Days, None) => Ok(count),
+        (BusinessDayCountUnit::Days, Some(hours_per_day)) => {
+            Ok(count.cast(&DataType::Float64)? * hours_per_day)
+        },
+        (BusinessDayCountUnit::Weeks, _) => {
+            let n_business_days_in_week_mask = active_week_days(week_mask);
+            Ok(count.cast(&DataType::Float64)? / n_business_days_in_week_mask as f64)
+        },
+    }
+}
+
+/// Count the number of business days between `start` and `end`, with a separate week mask for
+/// each row.
+///
+/// # Arguments
+/// - `start`, `end`, `holidays`, `closed`, `unit`, `hours_per_day`: see
+///   [`business_day_count_in_unit`].
+/// - `week_masks`: see [`add_business_days_with_week_masks`]. A null week mask results in a
+///   null.
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_with_week_masks(
+    start: &Series,
+    end: &Series,
+    week_masks: &Series,
+    holidays: &[i32],
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> PolarsResult<Series> {
+    check_hours_per_day(unit, hours_per_day)?;
+
+    let start = to_local_date(start)?;
+    let end = to_local_date(end)?;
+    let start_dates = start.date()?;
+    let end_dates = end.date()?;
+    let week_masks = collect_week_masks(week_masks)?;
+    let lens = [start_dates.len(), end_dates.len(), week_masks.len()];
+    let len = lens.into_iter().find(|&len| len != 1).unwrap_or(1);
+    for other_len in lens {
+        polars_ensure!(
+            other_len == len || other_len == 1,
+            length_mismatch = "business_day_count",
+            len,
+            other_len
+        );
+    }
+
+    let start_dates = start_dates.physical().iter().collect::<Vec<_>>();
+    let end_dates = end_dates.physical().iter().collect::<Vec<_>>();
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+    // There are only 127 valid week masks, so normalise the holidays once per mask.
+    let mut holidays_per_week_mask = PlHashMap::new();
+    let counts = (0..len).map(|i| {
+        let (Some(start_date), Some(end_date), Some(week_mask)) = (
+            start_dates[broadcast(start_dates.len(), i)],
+            end_dates[broadcast(end_dates.len(), i)],
+            week_masks[broadcast(week_masks.len(), i)],
+        ) else {
+            return None;
+        };
+        // Sort now so we can use `binary_search` in the hot for-loop.
+        let holidays: &Vec<i32> = holidays_per_week_mask
+            .entry(week_mask)
+            .or_insert_with(|| normalise_holidays(holidays, &week_mask));
+        let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+        let count = business_day_count_closed(
+            start_date,
+            end_date,
+            closed,
+            &week_mask,
+            n_business_days_in_week_mask,
+            holidays,
+        );
+        Some((count, n_business_days_in_week_mask))
+    });
+
+    let out = match (unit, hours_per_day) {
+        (BusinessDayCountUnit::Days, None) => counts
+            .map(|count| count.map(|(count, _)| count))
+            .collect::<Int32Chunked>()
+            .into_series(),
+        (BusinessDayCountUnit::Days, Some(hours_per_day)) => counts
+            .map(|count| count.map(|(count, _)| count as f64 * hours_per_day))
+            .collect::<Float64Chunked>()
+            .into_series(),
+        (BusinessDayCountUnit::Weeks, _) => counts
+            .map(|count| count.map(|(count, n_days)| count as f64 / n_days as f64))
+            .collect::<Float64Chunked>()
+            .into_series(),
+    };
+    Ok(out.with_name(start.name().clone()))
+}
+
+/// Check that `hours_per_day` is a valid number of hours to count `unit` in.
+#[cfg(feature = "business_day_count")]
+fn check_hours_per_day(unit: BusinessDayCountUnit, hours_per_day: Option<f64>) -> PolarsResult<()> {
+    if let Some(hours_per_day) = hours_per_day {
+        polars_ensure!(
+            unit == BusinessDayCountUnit::Days,
+            InvalidOperation: "`hours_per_day` can only be used when counting business days"
+        );
+        polars_ensure!(
+            hours_per_day > 0.0 && hours_per_day <= 24.0,
+            InvalidOperation: "`hours_per_day` must be in the range (0, 24], got {}", hours_per_day
+        );
+    }
+    Ok(())
+}
+
+/// Count the calendar days, weekend days and holidays between `start` and `end`.
+///
+/// The counts are returned as a Struct Series with `Int32` fields `calendar_days`,
+/// `weekend_days` and `holiday_days`, so that the business day count is the calendar days
+/// minus the weekend days and holidays. Holidays that fall on a weekend day are counted as
+/// weekend days. If `end` is before `start`, the counts are negative. A null in `start` or
+/// `end` results in a null.
+///
+/// # Arguments
+/// - `start`, `end`, `week_mask`, `holidays`, `closed`: see [`business_day_count`].
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_breakdown(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    closed: ClosedInterval,
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_date(start)?;
+    let end = to_local_date(end)?;
+    let start_dates = start.date()?;
+    let end_dates = end.date()?;
+    let len = match (start_dates.len(), end_dates.len()) {
+        (len, 1) | (1, len) => len,
+        (start_len, end_len) => {
+            polars_ensure!(
+                start_len == end_len,
+                length_mismatch = "business_day_count_breakdown",
+                start_len,
+                end_len
+            );
+            start_len
+        },
+    };
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+
+    let start_dates = start_dates.physical().iter().collect::<Vec<_>>();
+    let end_dates = end_dates.physical().iter().collect::<Vec<_>>();
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+    let counts = (0..len)
+        .map(|i| {
+            let start_date = start_dates[broadcast(start_dates.len(), i)]?;
+            let end_date = end_dates[broadcast(end_dates.len(), i)]?;
+            let (start_date, end_date) = closed_bounds(start_date, end_date, closed);
+            let (week_mask_days, holiday_days) = week_mask_and_holiday_days(
+                start_date,
+                end_date,
+                &week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+            );
+            let calendar_days = end_date - start_date;
+            Some([calendar_days, calendar_days - week_mask_days, holiday_days])
+        })
+        .collect::<Vec<_>>();
+
+    let fields = ["calendar_days", "weekend_days", "holiday_days"]
+        .into_iter()
+        .enumerate()
+        .map(|(field, name)| {
+            Int32Chunked::from_iter_options(
+                name.into(),
+                counts
+                    .iter()
+                    .map(|counts| counts.map(|counts| counts[field])),
+            )
+            .into_series()
+        })
+        .collect::<Vec<_>>();
+    let validity = counts.iter().map(Option::is_some).collect();
+    let out = StructChunked::from_series(start.name().clone(), len, fields.iter())?
+        .with_outer_validity(Some(validity));
+    Ok(out.into_series())
+}
+
+/// Count the number of business days between `start` and `end` per calendar period.
+///
+/// For each pair of dates, returns a list with a struct for every period that overlaps the
+/// half-open interval `[start, end)`, in chronological order. The struct has the fields:
+/// - `period_start` (`Date`): the first day of the period.
+/// - `business_days` (`Int32`): the number of business days of the period within the interval.
+///
+/// If `end` is before `start`, the periods overlapping `[end, start)` are returned with negative
+/// counts. A null in `start` or `end` results in a null.
+///
+/// # Arguments
+/// - `start`, `end`, `week_mask`, `holidays`: see [`business_day_count`].
+/// - `period`: the calendar period to count business days by.
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_by_period(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    period: BusinessPeriod,
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+    polars_ensure!(
+        start.len() == end.len() || start.len() == 1 || end.len() == 1,
+        length_mismatch = "business_day_count_by_period",
+        start.len(),
+        end.len()
+    );
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start_dates = to_local_date(start)?;
+    let end_dates = to_local_date(end)?;
+    let start_dates = start_dates.date()?.physical().iter().collect::<Vec<_>>();
+    let end_dates = end_dates.date()?.physical().iter().collect::<Vec<_>>();
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+
+    let len = if start_dates.len() == 1 {
+        end_dates.len()
+    } else {
+        start_dates.len()
+    };
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+    let mut period_starts = Vec::new();
+    let mut counts = Vec::new();
+    let mut offsets = Offsets::<i64>::with_capacity(len);
+    let mut validity = BitmapBuilder::with_capacity(len);
+    for i in 0..len {
+        let (Some(start_date), Some(end_date)) = (
+            start_dates[broadcast(start_dates.len(), i)],
+            end_dates[broadcast(end_dates.len(), i)],
+        ) else {
+            offsets.extend_constant(1);
+            validity.push(false);
+            continue;
+        };
+        let (sign, start_date, end_date) = if start_date <= end_date {
+            (1, start_date, end_date)
+        } else {
+            (-1, end_date, start_date)
+        };
+        let n_periods = period_starts.len();
+        let mut period_start = period.start(start_date);
+        while period_start < end_date {
+            let next_period_start = period.next_start(period_start);
+            period_starts.push(period_start);
+            counts.push(
+                sign * business_day_count_impl(
+                    start_date.max(period_start),
+                    end_date.min(next_period_start),
+                    &week_mask,
+                    n_business_days_in_week_mask,
+                    &holidays,
+                ),
+            );
+            period_start = next_period_start;
+        }
+        offsets.try_push(period_starts.len() - n_periods)?;
+        validity.push(true);
+    }
+
+    let n_values = period_starts.len();
+    let fields = [
+        Int32Chunked::from_vec(PlSmallStr::from_static("period_start"), period_starts)
+            .into_series(),
+        Int32Chunked::from_vec(PlSmallStr::from_static("business_days"), counts).into_series(),
+    ];
+    let values = StructChunked::from_series(PlSmallStr::EMPTY, n_values, fields.iter())?
+        .into_series()
+        .rechunk()
+        .to_arrow(0, CompatLevel::newest());
+    let out = LargeListArray::new(
+        LargeListArray::default_datatype(values.dtype().clone()),
+        offsets.into(),
+        values,
+        validity.into_opt_validity(),
+    );
+    let out = ListChunked::with_chunk(start.name().clone(), out).into_series();
+    out.cast(&business_day_count_by_period_dtype())
+}
+
+/// The data type returned by [`business_day_count_by_period`].
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_by_period_dtype() -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("period_start"), DataType::Date),
+        Field::new(PlSmallStr::from_static("business_days"), DataType::Int32),
+    ])))
+}
+
+/// Count the number of business days between `start_date` and `end_date`, including or
+/// excluding either end according to `closed`.
+#[cfg(feature = "business_day_count")]
+fn business_day_count_closed(
+    start_date: i32,
+    end_date: i32,
+    closed: ClosedInterval,
+    week_mask: &[bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: &[i32], // Caller's responsibility to ensure it's sorted.
+) -> i32 {
+    let (start_date, end_date) = closed_bounds(start_date, end_date, closed);
+    business_day_count_impl(
+        start_date,
+        end_date,
+        week_mask,
+        n_business_days_in_week_mask,
+        holidays,
+    )
+}
+
+/// Shift `start_date` and `end_date` so that counting from the former, inclusive, towards the
+/// latter, exclusive, includes or excludes either end according to `closed`.
+#[cfg(feature = "business_day_count")]
+fn closed_bounds(start_date: i32, end_date: i32, closed: ClosedInterval) -> (i32, i32) {
+    let (include_start, include_end) = match closed {
+        ClosedInterval::Both => (true, true),
+        ClosedInterval::Left => (true, false),
+        ClosedInterval::Right => (false, true),
+        ClosedInterval::None => (false, false),
+    };
+    if start_date == end_date && !include_start {
+        return (start_date, start_date);
+    }
+    let step = if start_date <= end_date { 1 } else { -1 };
+    let start_date = if include_start {
+        start_date
+    } else {
+        start_date + step
+    };
+    let end_date = if include_end {
+        end_date + step
+    } else {
+        end_date
+    };
+    (start_date, end_date)
+}
+
+#[cfg(any(feature = "business_day_count", feature = "is_business_day"))]
+fn business_day_count_impl(
+    start_date: i32,
+    end_date: i32,
+    week_mask: &[bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: &[i32], // Caller's responsibility to ensure it's sorted.
+) -> i32 {
+    let (week_mask_days, holiday_days) = week_mask_and_holiday_days(
+        start_date,
+        end_date,
+        week_mask,
+        n_business_days_in_week_mask,
+        holidays,
+    );
+    week_mask_days - holiday_days
+}
+
+/// Count the days in the week mask and the holidays from `start_date`, inclusive, towards
+/// `end_date`, exclusive.
+///
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L355-L433
-fn business_day_count_impl(
+#[cfg(any(feature = "business_day_count", feature = "is_business_day"))]
+fn week_mask_and_holiday_days(
     mut start_date: i32,
     mut end_date: i32,
     week_mask: &[bool; 7],
     n_business_days_in_week_mask: i32,
     holidays: &[i32], // Caller's responsibility to ensure it's sorted.
-) -> i32 {
+) -> (i32, i32) {
     let swapped = start_date > end_date;
     if swapped {
         (start_date, end_date) = (end_date, start_date);
@@ -113,11 +692,11 @@ fn business_day_count_impl(
 
     let holidays_begin = find_first_ge_index(holidays, start_date);
     let holidays_end = find_first_ge_index(&holidays[holidays_begin..], end_date) + holidays_begin;
+    let holiday_days = (holidays_end - holidays_begin) as i32;
     let mut start_day_of_week = get_day_of_week(start_date);
     let diff = end_date - start_date;
     let whole_weeks = diff / 7;
-    let mut count = -((holidays_end - holidays_begin) as i32);
-    count += whole_weeks * n_business_days_in_week_mask;
+    let mut count = whole_weeks * n_business_days_in_week_mask;
     start_date += whole_weeks * 7;
     while start_date < end_date {
         // SAFETY: week_mask is length 7, start_day_of_week is between 0 and 6
@@ -127,13 +706,195 @@ fn business_day_count_impl(
         start_date += 1;
         start_day_of_week = increment_day_of_week(start_day_of_week);
     }
-    if swapped { -count } else { count }
+    if swapped {
+        (-count, -holiday_days)
+    } else {
+        (count, holiday_days)
+    }
+}
+
+/// Count the number of business hours between `start` and `end`.
+///
+/// Only the time between `day_start` and `day_end` of each business day counts towards the
+/// total, so start and end times outside of business hours are effectively clamped to the
+/// nearest business hour. If `end` is before `start`, the count is negative.
+///
+/// # Arguments
+/// - `start`: Series holding start dates or datetimes.
+/// - `end`: Series holding end dates or datetimes.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `day_start`, `day_end`: the business hours of each business day, in nanoseconds since
+///   midnight.
+#[cfg(feature = "business_day_count")]
+pub fn business_hour_count(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    day_start: i64,
+    day_end: i64,
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+    polars_ensure!(
+        0 <= day_start && day_start < day_end && day_end <= NANOSECONDS_IN_DAY,
+        ComputeError: "business hours must satisfy 0 <= start < end <= 24h, got start={}ns and end={}ns",
+        day_start,
+        day_end
+    );
+    polars_ensure!(
+        start.len() == end.len() || start.len() == 1 || end.len() == 1,
+        length_mismatch = "business_hour_count",
+        start.len(),
+        end.len()
+    );
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_datetime(start)?;
+    let end = to_local_datetime(end)?;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+    let (day_start, day_end) = (day_start / 1_000, day_end / 1_000);
+
+    let out: Float64Chunked = broadcast_binary_elementwise_values(
+        start.datetime()?.physical(),
+        end.datetime()?.physical(),
+        |start, end| {
+            let count = business_hour_count_impl(
+                start,
+                end,
+                &week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+                day_start,
+                day_end,
+            );
+            count as f64 / (3_600 * MICROSECONDS) as f64
+        },
+    );
+    Ok(out.into_series())
+}
+
+/// Count the number of business days between `start` and `end`, including the fractions of the
+/// days that `start` and `end` fall on.
+///
+/// The whole business days in between count as one each, while the partial days of `start`
+/// and `end` count by the fraction of the day that is covered, based on the time of day.
+/// Partial days that are not business days count as zero. If `end` is before `start`, the count
+/// is negative.
+///
+/// # Arguments
+/// - `start`: Series holding start dates or datetimes.
+/// - `end`: Series holding end dates or datetimes.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "business_day_count")]
+pub fn business_day_count_fractional(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+    polars_ensure!(
+        start.len() == end.len() || start.len() == 1 || end.len() == 1,
+        length_mismatch = "business_day_count_fractional",
+        start.len(),
+        end.len()
+    );
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_datetime(start)?;
+    let end = to_local_datetime(end)?;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+
+    let out: Float64Chunked = broadcast_binary_elementwise_values(
+        start.datetime()?.physical(),
+        end.datetime()?.physical(),
+        |start, end| {
+            let count = business_hour_count_impl(
+                start,
+                end,
+                &week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+                0,
+                MICROSECONDS_IN_DAY,
+            );
+            count as f64 / MICROSECONDS_IN_DAY as f64
+        },
+    );
+    Ok(out.into_series())
+}
+
+/// Count the number of business microseconds between `start` and `end`.
+#[cfg(feature = "business_day_count")]
+fn business_hour_count_impl(
+    start: i64,
+    end: i64,
+    week_mask: &[bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: &[i32], // Caller's responsibility to ensure it's sorted.
+    day_start: i64,
+    day_end: i64,
+) -> i64 {
+    if start > end {
+        return -business_hour_count_impl(
+            end,
+            start,
+            week_mask,
+            n_business_days_in_week_mask,
+            holidays,
+            day_start,
+            day_end,
+        );
+    }
+
+    // Business time elapsed on `date` before `time` of day.
+    let elapsed_on_day = |date: i32, time: i64| {
+        // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+        if unsafe { *week_mask.get_unchecked(get_day_of_week(date)) }
+            && holidays.binary_search(&date).is_err()
+        {
+            time.clamp(day_start, day_end) - day_start
+        } else {
+            0
+        }
+    };
+
+    let start_date = start.div_euclid(MICROSECONDS_IN_DAY) as i32;
+    let end_date = end.div_euclid(MICROSECONDS_IN_DAY) as i32;
+    let whole_days = business_day_count_impl(
+        start_date,
+        end_date,
+        week_mask,
+        n_business_days_in_week_mask,
+        holidays,
+    ) as i64;
+    whole_days * (day_end - day_start)
+        - elapsed_on_day(start_date, start.rem_euclid(MICROSECONDS_IN_DAY))
+        + elapsed_on_day(end_date, end.rem_euclid(MICROSECONDS_IN_DAY))
 }
 
 /// Add a given number of business days.
 ///
+/// For datetimes, only the date is rolled and stepped, and the time of day is kept. Time zone
+/// aware datetimes are shifted in their own time zone, so it's the local wall-clock time that is
+/// kept. The elapsed time can therefore differ from a whole number of days across a DST
+/// transition: e.g. 1 business day after Friday `2024-03-29 12:00` in `Europe/London` is Monday
+/// `2024-04-01 12:00`, only 71 hours later because the clocks go forward on Sunday. A result whose
+/// local time doesn't exist, because it falls in the hour skipped when the clocks go forward, or
+/// is ambiguous, because it falls in the hour repeated when they go back, is an error.
+///
 /// # Arguments
-/// - `start`: Series holding start dates.
+/// - `start`: Series holding start dates or datetimes.
 /// - `n`: Number of business days to add.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
@@ -141,7 +902,9 @@ fn business_day_count_impl(
 /// - `roll`: what to do when the start date doesn't land on a business day:
 ///   - `Roll::Forward`: roll forward to the next business day.
 ///   - `Roll::Backward`: roll backward to the previous business day.
+///   - `Roll::Nearest`: roll to the nearest business day.
 ///   - `Roll::Raise`: raise an error.
+#[cfg(feature = "add_business_days")]
 pub fn add_business_days(
     start: &Series,
     n: &Series,
@@ -153,65 +916,22 @@ pub fn add_business_days(
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
     }
 
-    match start.dtype() {
-        DataType::Date => {},
-        #[cfg(feature = "dtype-datetime")]
-        DataType::Datetime(time_unit, None) => {
-            let result_date =
-                add_business_days(&start.cast(&DataType::Date)?, n, week_mask, holidays, roll)?;
-            let start_time = start
-                .cast(&DataType::Time)?
-                .cast(&DataType::Duration(*time_unit))?;
-            return std::ops::Add::add(
-                result_date.cast(&DataType::Datetime(*time_unit, None))?,
-                start_time,
-            );
-        },
-        #[cfg(feature = "timezones")]
-        DataType::Datetime(time_unit, Some(time_zone)) => {
-            let start_naive = replace_time_zone(
-                start.datetime().unwrap(),
-                None,
-                &StringChunked::from_iter(std::iter::once("raise")),
-                NonExistent::Raise,
-            )?;
-            let result_date = add_business_days(
-                &start_naive.cast(&DataType::Date)?,
-                n,
-                week_mask,
-                holidays,
-                roll,
-            )?;
-            let start_time = start_naive
-                .cast(&DataType::Time)?
-                .cast(&DataType::Duration(*time_unit))?;
-            let result_naive = std::ops::Add::add(
-                result_date.cast(&DataType::Datetime(*time_unit, None))?,
-                start_time,
-            )?;
-            let result_tz_aware = replace_time_zone(
-                result_naive.datetime().unwrap(),
-                Some(time_zone),
-                &StringChunked::from_iter(std::iter::once("raise")),
-                NonExistent::Raise,
-            )?;
-            return Ok(result_tz_aware.into_series());
-        },
-        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
+    if start.dtype() != &DataType::Date {
+        return map_local_dates(start, |start| {
+            add_business_days(start, n, week_mask, holidays, roll)
+        });
     }
 
     // Sort now so we can use `binary_search` in the hot for-loop.
     let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
-    let n = match &n.dtype() {
-        DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
-        DataType::Int32 => n.clone(),
-        _ => {
-            polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", n.dtype())
-        },
-    };
+    if roll == Roll::Raise {
+        // Check up front, so that the error can point at the offending row.
+        ensure_business_dates(start_dates, &week_mask, &holidays)?;
+    }
+    let n = cast_business_day_offsets(n)?;
     let n = n.i32()?;
-    let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
 
     let out: Int32Chunked = match (start_dates.len(), n.len()) {
         (_, 1) => {
@@ -219,14 +939,14 @@ pub fn add_business_days(
                 start_dates.try_apply_nonnull_values_generic(|start_date| {
                     let (start_date, day_of_week) =
                         roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                    Ok::<i32, PolarsError>(add_business_days_impl(
+                    add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
                         &week_mask,
                         n_business_days_in_week_mask,
                         &holidays,
-                    ))
+                    )
                 })?
             } else {
                 Int32Chunked::full_null(start_dates.name().clone(), start_dates.len())
@@ -236,7 +956,7 @@ pub fn add_business_days(
             if let Some(start_date) = start_dates.get(0) {
                 let (start_date, day_of_week) =
                     roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                n.apply_values(|n| {
+                n.try_apply_nonnull_values_generic(|n| {
                     add_business_days_impl(
                         start_date,
                         day_of_week,
@@ -245,7 +965,7 @@ pub fn add_business_days(
                         n_business_days_in_week_mask,
                         &holidays,
                     )
-                })
+                })?
             } else {
                 Int32Chunked::full_null(start_dates.name().clone(), n.len())
             }
@@ -262,14 +982,15 @@ pub fn add_business_days(
                     (Some(start_date), Some(n)) => {
                         let (start_date, day_of_week) =
                             roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                        Ok::<Option<i32>, PolarsError>(Some(add_business_days_impl(
+                        add_business_days_impl(
                             start_date,
                             day_of_week,
                             n,
                             &week_mask,
                             n_business_days_in_week_mask,
                             &holidays,
-                        )))
+                        )
+                        .map(Some)
                     },
                     _ => Ok(None),
                 }
@@ -279,8 +1000,341 @@ pub fn add_business_days(
     Ok(out.into_date().into_series())
 }
 
+/// Add a given number of business days or business weeks.
+///
+/// A business week is as many business days as there are in `week_mask`, so adding whole
+/// weeks to a business day lands on the same day of the week, unless holidays were skipped.
+///
+/// # Arguments
+/// - `start`, `n`, `week_mask`, `holidays`, `roll`: see [`add_business_days`].
+/// - `unit`: whether `n` is a number of business days or business weeks.
+#[cfg(feature = "add_business_days")]
+pub fn add_business_days_in_unit(
+    start: &Series,
+    n: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+    unit: BusinessDayCountUnit,
+) -> PolarsResult<Series> {
+    match unit {
+        BusinessDayCountUnit::Days => add_business_days(start, n, week_mask, holidays, roll),
+        BusinessDayCountUnit::Weeks => {
+            let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+            let n = cast_business_day_offsets(n)?;
+            let n: Int32Chunked = n.i32()?.try_apply_nonnull_values_generic(|n| {
+                n.checked_mul(n_business_days_in_week_mask).ok_or_else(
+                    || polars_err!(ComputeError: "{} business weeks overflow the range of dates", n),
+                )
+            })?;
+            add_business_days(start, &n.into_series(), week_mask, holidays, roll)
+        },
+    }
+}
+
+/// Generate the business days between `start` and `end`, both inclusive.
+///
+/// Returns a list of dates for each row. Rows for which there are no business days in the range,
+/// including rows where `start` is after `end`, result in an empty list.
+///
+/// # Arguments
+/// - `start`: Series holding start dates.
+/// - `end`: Series holding end dates.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `step`: the number of business days between consecutive dates.
+#[cfg(feature = "add_business_days")]
+pub fn business_date_range(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    step: u32,
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+    polars_ensure!(step > 0, ComputeError: "`step` must be positive");
+    polars_ensure!(
+        start.len() == end.len() || start.len() == 1 || end.len() == 1,
+        length_mismatch = "business_date_range",
+        start.len(),
+        end.len()
+    );
+    let step = i32::try_from(step)
+        .map_err(|_| polars_err!(ComputeError: "`step` must fit in an i32, got {}", step))?;
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let start = to_local_date(start)?;
+    let end = to_local_date(end)?;
+    let start_dates = start.date()?.physical().iter().collect::<Vec<_>>();
+    let end_dates = end.date()?.physical().iter().collect::<Vec<_>>();
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+
+    let len = start_dates.len().max(end_dates.len());
+    let len = if start_dates.len().min(end_dates.len()) == 1 {
+        len
+    } else {
+        start_dates.len()
+    };
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+    let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
+        start.name().clone(),
+        len,
+        len,
+        DataType::Date,
+    );
+    let mut dates = Vec::new();
+    for i in 0..len {
+        let (Some(start_date), Some(end_date)) = (
+            start_dates[broadcast(start_dates.len(), i)],
+            end_dates[broadcast(end_dates.len(), i)],
+        ) else {
+            builder.append_null();
+            continue;
+        };
+        dates.clear();
+        let (mut date, mut day_of_week) =
+            roll_start_date(start_date, Roll::Forward, &week_mask, &holidays)?;
+        while date <= end_date {
+            dates.push(date);
+            // A step beyond the range of dates is beyond `end_date` too.
+            let Ok(next) = add_business_days_impl(
+                date,
+                day_of_week,
+                step,
+                &week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+            ) else {
+                break;
+            };
+            date = next;
+            day_of_week = get_day_of_week(date);
+        }
+        builder.append_slice(&dates);
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// Add a given number of business days, with a separate week mask for each row.
+///
+/// # Arguments
+/// - `start`, `n`, `holidays`, `roll`: see [`add_business_days`].
+/// - `week_masks`: Series of boolean lists of length 7, where `true` indicates that the day is
+///   a business day. Alternatively, an integer Series of bitmasks, where bit 0 (Monday) until
+///   bit 6 (Sunday) indicate business days. A null week mask results in a null.
+#[cfg(feature = "add_business_days")]
+pub fn add_business_days_with_week_masks(
+    start: &Series,
+    n: &Series,
+    week_masks: &Series,
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    if start.dtype() != &DataType::Date {
+        return map_local_dates(start, |start| {
+            add_business_days_with_week_masks(start, n, week_masks, holidays, roll)
+        });
+    }
+
+    let start_dates = start.date()?;
+    let n = cast_business_day_offsets(n)?;
+    let n = n.i32()?;
+    let week_masks = collect_week_masks(week_masks)?;
+    let len = [start_dates.len(), n.len(), week_masks.len()]
+        .into_iter()
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+    for other_len in [n.len(), week_masks.len()] {
+        polars_ensure!(
+            other_len == len || other_len == 1,
+            length_mismatch = "dt.add_business_days",
+            len,
+            other_len
+        );
+    }
+    polars_ensure!(
+        start_dates.len() == len || start_dates.len() == 1,
+        length_mismatch = "dt.add_business_days",
+        start_dates.len(),
+        len
+    );
+
+    let start_dates = start_dates.physical().iter().collect::<Vec<_>>();
+    let n = n.iter().collect::<Vec<_>>();
+    let broadcast = |len: usize, i: usize| if len == 1 { 0 } else { i };
+    // There are only 127 valid week masks, so normalise the holidays once per mask.
+    let mut holidays_per_week_mask = PlHashMap::new();
+    let out = (0..len)
+        .map(|i| {
+            let (Some(start_date), Some(n), Some(week_mask)) = (
+                start_dates[broadcast(start_dates.len(), i)],
+                n[broadcast(n.len(), i)],
+                week_masks[broadcast(week_masks.len(), i)],
+            ) else {
+                return Ok(None);
+            };
+            // Sort now so we can use `binary_search` in the hot for-loop.
+            let holidays: &Vec<i32> = holidays_per_week_mask
+                .entry(week_mask)
+                .or_insert_with(|| normalise_holidays(holidays, &week_mask));
+            let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+            let (start_date, day_of_week) = roll_start_date(start_date, roll, &week_mask, holidays)
+                .map_err(|_| not_a_business_date(start_date, Some(i)))?;
+            add_business_days_impl(
+                start_date,
+                day_of_week,
+                n,
+                &week_mask,
+                n_business_days_in_week_mask,
+                holidays,
+            )
+            .map(Some)
+        })
+        .collect::<PolarsResult<Int32Chunked>>()?;
+    Ok(out
+        .with_name(start.name().clone())
+        .into_date()
+        .into_series())
+}
+
+/// Convert the week masks in a Series of boolean lists or integer bitmasks to arrays.
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
+fn collect_week_masks(week_masks: &Series) -> PolarsResult<Vec<Option<[bool; 7]>>> {
+    let week_masks = match week_masks.dtype() {
+        DataType::List(inner) if inner.as_ref() == &DataType::Boolean => week_masks.list()?,
+        dt if dt.is_integer() => {
+            let bitmasks = week_masks.cast(&DataType::Int64)?;
+            return bitmasks
+                .i64()?
+                .iter()
+                .enumerate()
+                .map(|(i, opt_bitmask)| {
+                    let Some(bitmask) = opt_bitmask else {
+                        return Ok(None);
+                    };
+                    polars_ensure!(
+                        (1..128).contains(&bitmask),
+                        ComputeError: "week mask in row {} must be a bitmask between 1 and 127, got {}", i, bitmask
+                    );
+                    Ok(Some(std::array::from_fn(|day| bitmask >> day & 1 == 1)))
+                })
+                .collect();
+        },
+        dt => polars_bail!(
+            InvalidOperation: "expected week masks of type list[bool] or an integer bitmask, got {}", dt
+        ),
+    };
+    week_masks
+        .amortized_iter()
+        .enumerate()
+        .map(|(i, opt_week_mask)| {
+            let Some(week_mask) = opt_week_mask else {
+                return Ok(None);
+            };
+            let week_mask = week_mask.as_ref().bool()?;
+            polars_ensure!(
+                week_mask.len() == 7,
+                ComputeError: "week mask in row {} has {} elements, expected 7", i, week_mask.len()
+            );
+            polars_ensure!(
+                week_mask.null_count() == 0,
+                ComputeError: "week mask in row {} contains nulls", i
+            );
+            let mut out = [false; 7];
+            for (out, business_day) in out.iter_mut().zip(week_mask.into_no_null_iter()) {
+                *out = business_day;
+            }
+            polars_ensure!(
+                out.iter().any(|&x| x),
+                ComputeError: "week mask in row {} must have at least one business day", i
+            );
+            Ok(Some(out))
+        })
+        .collect()
+}
+
+/// Cast the number of business days to add to `Int32`, raising an error for numbers that are
+/// out of its range rather than nulling them.
+#[cfg(feature = "add_business_days")]
+fn cast_business_day_offsets(n: &Series) -> PolarsResult<Series> {
+    match &n.dtype() {
+        DataType::Int64 | DataType::UInt64 | DataType::UInt32 => {
+            let out = n.cast(&DataType::Int32)?;
+            if out.null_count() != n.null_count() {
+                let out_of_range = out.is_null() & n.is_not_null();
+                let idx = out_of_range
+                    .into_iter()
+                    .position(|x| x == Some(true))
+                    .unwrap();
+                polars_bail!(
+                    ComputeError: "adding {} business days overflows the range of dates",
+                    n.get(idx)?
+                );
+            }
+            Ok(out)
+        },
+        DataType::Int32 => Ok(n.clone()),
+        _ => {
+            polars_bail!(InvalidOperation: "expected Int64, Int32, UInt64, or UInt32, got {}", n.dtype())
+        },
+    }
+}
+
+/// Apply `f` to the local dates of `start`, and add the time of day of `start` back to the
+/// resulting dates, keeping the time zone of `start`.
+#[cfg(feature = "add_business_days")]
+fn map_local_dates(
+    start: &Series,
+    f: impl FnOnce(&Series) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    match start.dtype() {
+        DataType::Date => f(start),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, None) => {
+            let result_date = f(&start.cast(&DataType::Date)?)?;
+            let start_time = start
+                .cast(&DataType::Time)?
+                .cast(&DataType::Duration(*time_unit))?;
+            std::ops::Add::add(
+                result_date.cast(&DataType::Datetime(*time_unit, None))?,
+                start_time,
+            )
+        },
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(time_unit, Some(time_zone)) => {
+            let start_naive = replace_time_zone(
+                start.datetime().unwrap(),
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            let result_date = f(&start_naive.cast(&DataType::Date)?)?;
+            let start_time = start_naive
+                .cast(&DataType::Time)?
+                .cast(&DataType::Duration(*time_unit))?;
+            let result_naive = std::ops::Add::add(
+                result_date.cast(&DataType::Datetime(*time_unit, None))?,
+                start_time,
+            )?;
+            let result_tz_aware = replace_time_zone(
+                result_naive.datetime().unwrap(),
+                Some(time_zone),
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            Ok(result_tz_aware.into_series())
+        },
+        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
+    }
+}
+
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L265-L353
+#[cfg(feature = "add_business_days")]
 fn add_business_days_impl(
     mut date: i32,
     mut day_of_week: usize,
@@ -288,16 +1342,31 @@ fn add_business_days_impl(
     week_mask: &[bool; 7],
     n_business_days_in_week_mask: i32,
     holidays: &[i32], // Caller's responsibility to ensure it's sorted.
-) -> i32 {
+) -> PolarsResult<i32> {
+    let (start_date, offset) = (date, n);
+    let overflow = move || {
+        polars_err!(
+            ComputeError: "adding {} business days to {} overflows the range of dates",
+            offset, start_date
+        )
+    };
+    // Jump whole weeks at once, so that only the remainder and the holidays that were jumped
+    // over are stepped through day by day.
+    let date_after_weeks = move |date: i32, n: i32| {
+        (n / n_business_days_in_week_mask)
+            .checked_mul(7)
+            .and_then(|days| date.checked_add(days))
+            .ok_or_else(overflow)
+    };
     if n > 0 {
         let holidays_begin = find_first_ge_index(holidays, date);
-        date += (n / n_business_days_in_week_mask) * 7;
+        date = date_after_weeks(date, n)?;
         n %= n_business_days_in_week_mask;
         let holidays_temp = find_first_gt_index(&holidays[holidays_begin..], date) + holidays_begin;
         n += (holidays_temp - holidays_begin) as i32;
         let holidays_begin = holidays_temp;
         while n > 0 {
-            date += 1;
+            date = date.checked_add(1).ok_or_else(overflow)?;
             day_of_week = increment_day_of_week(day_of_week);
             // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
             if unsafe {
@@ -307,16 +1376,16 @@ fn add_business_days_impl(
                 n -= 1;
             }
         }
-        date
+        Ok(date)
     } else {
         let holidays_end = find_first_gt_index(holidays, date);
-        date += (n / n_business_days_in_week_mask) * 7;
+        date = date_after_weeks(date, n)?;
         n %= n_business_days_in_week_mask;
         let holidays_temp = find_first_ge_index(&holidays[..holidays_end], date);
         n -= (holidays_end - holidays_temp) as i32;
         let holidays_end = holidays_temp;
         while n < 0 {
-            date -= 1;
+            date = date.checked_sub(1).ok_or_else(overflow)?;
             day_of_week = decrement_day_of_week(day_of_week);
             // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
             if unsafe {
@@ -326,16 +1395,185 @@ fn add_business_days_impl(
                 n += 1;
             }
         }
-        date
+        Ok(date)
     }
 }
 
+/// Subtract a given number of business days.
+///
+/// This is equivalent to adding `-n` business days with [`add_business_days`].
+///
+/// # Arguments
+/// - `start`: Series holding start dates.
+/// - `n`: Number of business days to subtract.
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `roll`: what to do when the start date doesn't land on a business day. The start date is
+///   rolled before subtracting, so the direction of `roll` is independent of the subtraction:
+///   - `Roll::Forward`: roll forward to the next business day.
+///   - `Roll::Backward`: roll backward to the previous business day.
+///   - `Roll::Nearest`: roll to the nearest business day.
+///   - `Roll::Raise`: raise an error.
+#[cfg(feature = "add_business_days")]
+pub fn sub_business_days(
+    start: &Series,
+    n: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    let n = cast_business_day_offsets(n)?;
+    let n: Int32Chunked = n.i32()?.try_apply_nonnull_values_generic(|n| {
+        n.checked_neg().ok_or_else(
+            || polars_err!(ComputeError: "subtracting {} business days overflows the range of dates", n),
+        )
+    })?;
+    let n = n.into_series();
+    add_business_days(start, &n, week_mask, holidays, roll)
+}
+
+/// Get the next business day after each day.
+///
+/// If `inclusive`, days that already are business days are returned unchanged, i.e. the result
+/// is the first business day on or after each day. Otherwise, they are moved to the following
+/// business day, i.e. the result is the first business day strictly after each day.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `inclusive`: whether a day that is a business day is its own next business day.
+#[cfg(feature = "add_business_days")]
+pub fn next_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    inclusive: bool,
+) -> PolarsResult<Series> {
+    let (n, roll) = if inclusive {
+        (0i32, Roll::Forward)
+    } else {
+        // Rolling backward first ensures that non-business days land on the first business day
+        // after them.
+        (1i32, Roll::Backward)
+    };
+    let n = Series::new(PlSmallStr::from_static("n"), [n]);
+    add_business_days(dates, &n, week_mask, holidays, roll)
+}
+
+/// Get the previous business day before each day.
+///
+/// If `inclusive`, days that already are business days are returned unchanged, i.e. the result
+/// is the last business day on or before each day. Otherwise, they are moved to the preceding
+/// business day, i.e. the result is the last business day strictly before each day.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+/// - `inclusive`: whether a day that is a business day is its own previous business day.
+#[cfg(feature = "add_business_days")]
+pub fn previous_business_day(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    inclusive: bool,
+) -> PolarsResult<Series> {
+    let (n, roll) = if inclusive {
+        (0i32, Roll::Backward)
+    } else {
+        // Rolling forward first ensures that non-business days land on the last business day
+        // before them.
+        (-1i32, Roll::Forward)
+    };
+    let n = Series::new(PlSmallStr::from_static("n"), [n]);
+    add_business_days(dates, &n, week_mask, holidays, roll)
+}
+
+/// Get the last business day of the month of each day, i.e. the last business day on or before
+/// the last day of its month.
+///
+/// Datetimes keep their time of day, like with [`previous_business_day`].
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "add_business_days")]
+pub fn business_month_end(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    roll_within_month(dates, week_mask, holidays, Roll::Backward)
+}
+
+/// Get the first business day of the month of each day, i.e. the first business day on or
+/// after the first day of its month.
+///
+/// Datetimes keep their time of day, like with [`next_business_day`].
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "add_business_days")]
+pub fn business_month_begin(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    roll_within_month(dates, week_mask, holidays, Roll::Forward)
+}
+
+/// Roll the last day of the month of each day backward, or the first day forward, to a business
+/// day.
+#[cfg(feature = "add_business_days")]
+fn roll_within_month(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    if dates.dtype() != &DataType::Date {
+        return map_local_dates(dates, |dates| {
+            roll_within_month(dates, week_mask, holidays, roll)
+        });
+    }
+
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = dates.date()?;
+    let out: Int32Chunked = dates.try_apply_nonnull_values_generic(|date| {
+        let first_of_month = first_of_month(date);
+        let date = match roll {
+            Roll::Backward => BusinessPeriod::Month.next_start(first_of_month) - 1,
+            _ => first_of_month,
+        };
+        roll_start_date(date, roll, &week_mask, &holidays).map(|(date, _)| date)
+    })?;
+    Ok(out.into_date().into_series())
+}
+
 /// Determine if a day lands on a business day.
 ///
 /// # Arguments
+/// - `dates`: Series holding dates or datetimes. Time zone aware datetimes are converted to dates
+///   in their own time zone, not in UTC.
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+///
+/// Datetimes are truncated to the date of their local wall-clock time, so the time of day is
+/// ignored. As every instant has exactly one local time, this is well defined around DST
+/// transitions too: e.g. `2024-03-31 23:30 UTC` is `2024-04-01 00:30` in `Europe/London`,
+/// which is on summer time by then, and so lands on the 1st.
+#[cfg(feature = "is_business_day")]
 pub fn is_business_day(
     dates: &Series,
     week_mask: [bool; 7],
@@ -345,12 +1583,181 @@ pub fn is_business_day(
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
     }
 
+    let dates = to_local_date(dates)?;
+    let holidays = HolidayLookup::new(&normalise_holidays(holidays, &week_mask));
+    let week_bits = week_mask_bits(week_mask);
+    let dates = dates.date()?;
+    // Every value is classified, nulls included, so that the loop has no branches on validity;
+    // the validity of `dates` is carried over.
+    let out: BooleanChunked = unary_elementwise_values(dates.physical(), |date| {
+        (week_bits >> get_day_of_week(date)) & 1 == 1 && !holidays.contains(date)
+    });
+    Ok(out.into_series())
+}
+
+/// Determine why each day is or isn't a business day.
+///
+/// Each day is labelled as `"business"`, `"weekend"` if it is excluded by `week_mask`, or
+/// `"holiday"` if it is one of `holidays`. Days excluded by `week_mask` are labelled as
+/// `"weekend"` even if they are also holidays.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "is_business_day")]
+pub fn business_day_reason(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let dates = to_local_date(dates)?;
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = dates.date()?;
+    let out: StringChunked = dates.apply_nonnull_values_generic(DataType::String, |date| {
+        let day_of_week = get_day_of_week(date);
+        // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
+        if unsafe { !*week_mask.get_unchecked(day_of_week) } {
+            "weekend"
+        } else if holidays.binary_search(&date).is_ok() {
+            "holiday"
+        } else {
+            "business"
+        }
+    });
+    Ok(out.into_series())
+}
+
+/// Determine if a day is one of `holidays`, regardless of its day of the week.
+///
+/// # Arguments
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "is_business_day")]
+pub fn is_holiday(dates: &Series, holidays: &[i32]) -> PolarsResult<Series> {
+    let dates = to_local_date(dates)?;
+    // Sort now so we can use `binary_search` in the hot for-loop. Holidays on any day of the
+    // week are kept.
+    let holidays = normalise_holidays(holidays, &[true; 7]);
+    let dates = dates.date()?;
+    let out: BooleanChunked = dates.apply_nonnull_values_generic(DataType::Boolean, |date| {
+        holidays.binary_search(&date).is_ok()
+    });
+    Ok(out.into_series())
+}
+
+/// Determine if a day is excluded by `week_mask`, regardless of holidays.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+#[cfg(feature = "is_business_day")]
+pub fn is_weekend(dates: &Series, week_mask: [bool; 7]) -> PolarsResult<Series> {
+    let dates = to_local_date(dates)?;
+    let week_bits = week_mask_bits(week_mask);
+    let dates = dates.date()?;
+    let out: BooleanChunked = unary_elementwise_values(dates.physical(), |date| {
+        (week_bits >> get_day_of_week(date)) & 1 == 0
+    });
+    Ok(out.into_series())
+}
+
+/// Count the business days from each day until the next holiday at or after it.
+///
+/// Days from the day itself up to, but excluding, the holiday are counted, so a holiday is
+/// zero business days away from itself. Holidays on any day of the week are considered. Days
+/// without a holiday at or after them are null.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "is_business_day")]
+pub fn business_days_until_holiday(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let dates = to_local_date(dates)?;
+    // Sort now so we can use `binary_search` in the hot for-loop. The next holiday may be on
+    // any day of the week, but only holidays on business days reduce the count.
+    let all_holidays = normalise_holidays(holidays, &[true; 7]);
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = dates.date()?;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+    let out: Int32Chunked = unary_elementwise(dates.physical(), |opt_date| {
+        let date = opt_date?;
+        let next_holiday = *all_holidays.get(find_first_ge_index(&all_holidays, date))?;
+        Some(business_day_count_impl(
+            date,
+            next_holiday,
+            &week_mask,
+            n_business_days_in_week_mask,
+            &holidays,
+        ))
+    });
+    Ok(out.into_series())
+}
+
+/// Determine which business day of its month a day is, counting from 1.
+///
+/// Days that are not business days are null.
+///
+/// # Arguments
+/// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+/// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+///   days since the UNIX epoch.
+#[cfg(feature = "is_business_day")]
+pub fn business_day_of_month(
+    dates: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    if !week_mask.iter().any(|&x| x) {
+        polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+    }
+
+    let dates = to_local_date(dates)?;
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
+    let dates = dates.date()?;
+    let n_business_days_in_week_mask = active_week_days(week_mask) as i32;
+    let out: Int32Chunked = unary_elementwise(dates.physical(), |opt_date| {
+        let date = opt_date?;
+        let day_of_week = get_day_of_week(date);
+        // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
+        if unsafe { !*week_mask.get_unchecked(day_of_week) }
+            || holidays.binary_search(&date).is_ok()
+        {
+            return None;
+        }
+        let first_of_month = date - date32_to_date(date).day0() as i32;
+        Some(business_day_count_impl(
+            first_of_month,
+            date + 1,
+            &week_mask,
+            n_business_days_in_week_mask,
+            &holidays,
+        ))
+    });
+    Ok(out.into_series())
+}
+
+/// Convert dates or datetimes to naive microsecond datetimes, in the local time of time zone
+/// aware datetimes.
+#[cfg(feature = "business_day_count")]
+fn to_local_datetime(dates: &Series) -> PolarsResult<Series> {
+    let dtype = DataType::Datetime(TimeUnit::Microseconds, None);
     match dates.dtype() {
-        DataType::Date => {},
-        #[cfg(feature = "dtype-datetime")]
-        DataType::Datetime(_, None) => {
-            return is_business_day(&dates.cast(&DataType::Date)?, week_mask, holidays);
-        },
+        DataType::Date | DataType::Datetime(_, None) => dates.cast(&dtype),
         #[cfg(feature = "timezones")]
         DataType::Datetime(_, Some(_)) => {
             let dates_local = replace_time_zone(
@@ -359,22 +1766,33 @@ pub fn is_business_day(
                 &StringChunked::from_iter(std::iter::once("raise")),
                 NonExistent::Raise,
             )?;
-            return is_business_day(&dates_local.cast(&DataType::Date)?, week_mask, holidays);
+            dates_local.cast(&dtype)
         },
         _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dates.dtype()),
     }
+}
 
-    // Sort now so we can use `binary_search` in the hot for-loop.
-    let holidays = normalise_holidays(holidays, &week_mask);
-    let dates = dates.date()?;
-    let out: BooleanChunked = dates.apply_nonnull_values_generic(DataType::Boolean, |date| {
-        let day_of_week = get_day_of_week(date);
-        // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
-        unsafe { (*week_mask.get_unchecked(day_of_week)) && holidays.binary_search(&date).is_err() }
-    });
-    Ok(out.into_series())
+/// Convert dates or datetimes to dates, in the local time of time zone aware datetimes.
+fn to_local_date(dates: &Series) -> PolarsResult<Series> {
+    match dates.dtype() {
+        DataType::Date => Ok(dates.clone()),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => dates.cast(&DataType::Date),
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => {
+            let dates_local = replace_time_zone(
+                dates.datetime().unwrap(),
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            dates_local.cast(&DataType::Date)
+        },
+        _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dates.dtype()),
+    }
 }
 
+#[cfg(feature = "add_business_days")]
 fn roll_start_date(
     mut date: i32,
     roll: Roll,
@@ -388,12 +1806,7 @@ fn roll_start_date(
             if holidays.binary_search(&date).is_ok()
                 | unsafe { !*week_mask.get_unchecked(day_of_week) }
             {
-                let date = DateTime::from_timestamp(date as i64 * SECONDS_IN_DAY, 0)
-                    .unwrap()
-                    .format("%Y-%m-%d");
-                polars_bail!(ComputeError:
-                    "date {} is not a business date; use `roll` to roll forwards (or backwards) to the next (or previous) valid date.", date
-                )
+                return Err(not_a_business_date(date, None));
             };
         },
         Roll::Forward => {
@@ -414,27 +1827,171 @@ fn roll_start_date(
                 day_of_week = decrement_day_of_week(day_of_week);
             }
         },
+        Roll::Nearest { prefer_forward } => {
+            let is_business_day = |date: i32| {
+                // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+                holidays.binary_search(&date).is_err()
+                    && unsafe { *week_mask.get_unchecked(get_day_of_week(date)) }
+            };
+            // Terminates as `week_mask` has at least one business day and there are only
+            // finitely many holidays.
+            let mut offset = 0;
+            date = loop {
+                let (preferred, other) = if prefer_forward {
+                    (date + offset, date - offset)
+                } else {
+                    (date - offset, date + offset)
+                };
+                if is_business_day(preferred) {
+                    break preferred;
+                }
+                if is_business_day(other) {
+                    break other;
+                }
+                offset += 1;
+            };
+            day_of_week = get_day_of_week(date);
+        },
     }
     Ok((date, day_of_week))
 }
 
-/// Sort and deduplicate holidays and remove holidays that are not business days.
-fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
-    let mut holidays: Vec<i32> = holidays.to_vec();
-    holidays.sort_unstable();
-    let mut previous_holiday: Option<i32> = None;
-    holidays.retain(|&x| {
+/// Error for a start date that is not a business date while rolling is not allowed.
+#[cfg(feature = "add_business_days")]
+fn not_a_business_date(date: i32, row: Option<usize>) -> PolarsError {
+    let date = DateTime::from_timestamp(date as i64 * SECONDS_IN_DAY, 0)
+        .unwrap()
+        .format("%Y-%m-%d");
+    let row = row.map(|row| format!(" (row {row})")).unwrap_or_default();
+    polars_err!(ComputeError:
+        "date {}{} is not a business date; use `roll` to roll forwards (or backwards) to the next (or previous) valid date.", date, row
+    )
+}
+
+/// Raise an error naming the first date in `dates` that is not a business date, if any.
+#[cfg(feature = "add_business_days")]
+fn ensure_business_dates(
+    dates: &DateChunked,
+    week_mask: &[bool; 7],
+    holidays: &[i32], // Caller's responsibility to ensure it's sorted.
+) -> PolarsResult<()> {
+    for (row, date) in dates.physical().iter().enumerate() {
+        let Some(date) = date else {
+            continue;
+        };
         // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
-        if (Some(x) == previous_holiday) || !unsafe { *week_mask.get_unchecked(get_day_of_week(x)) }
+        if holidays.binary_search(&date).is_ok()
+            || unsafe { !*week_mask.get_unchecked(get_day_of_week(date)) }
         {
-            return false;
+            return Err(not_a_business_date(date, Some(row)));
         }
-        previous_holiday = Some(x);
-        true
-    });
+    }
+    Ok(())
+}
+
+/// Sort and deduplicate holidays and remove holidays that are not business days.
+fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
+    let mut holidays: Vec<i32> = holidays.to_vec();
+    // Holidays from `Holidays::to_days` are already sorted and deduplicated.
+    if !holidays.is_sorted_by(|a, b| a < b) {
+        holidays.sort_unstable();
+        holidays.dedup();
+    }
+    // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+    holidays.retain(|&x| unsafe { *week_mask.get_unchecked(get_day_of_week(x)) });
     holidays
 }
 
+/// The largest range of days, from the first to the last holiday, that [`HolidayLookup`] keeps
+/// as a bitset (8 KiB).
+#[cfg(feature = "is_business_day")]
+const MAX_HOLIDAY_BITSET_DAYS: i64 = 1 << 16;
+
+/// Membership test for normalised holidays.
+///
+/// Holidays that span at most [`MAX_HOLIDAY_BITSET_DAYS`] are kept as a bitset over their
+/// range, so that a lookup is a bounds check and a load. Otherwise they are binary searched.
+#[cfg(feature = "is_business_day")]
+enum HolidayLookup {
+    Bitset {
+        first: i32,
+        n_days: u64,
+        words: Vec<u64>,
+    },
+    Sorted(Vec<i32>),
+}
+
+#[cfg(feature = "is_business_day")]
+impl HolidayLookup {
+    /// `holidays` must be sorted, as returned by `normalise_holidays`.
+    fn new(holidays: &[i32]) -> Self {
+        let (Some(&first), Some(&last)) = (holidays.first(), holidays.last()) else {
+            return Self::Bitset {
+                first: 0,
+                n_days: 0,
+                words: vec![],
+            };
+        };
+        let n_days = last as i64 - first as i64 + 1;
+        if n_days > MAX_HOLIDAY_BITSET_DAYS {
+            return Self::Sorted(holidays.to_vec());
+        }
+        let mut words = vec![0u64; (n_days as usize).div_ceil(64)];
+        for &holiday in holidays {
+            let offset = (holiday - first) as usize;
+            words[offset / 64] |= 1 << (offset % 64);
+        }
+        Self::Bitset {
+            first,
+            n_days: n_days as u64,
+            words,
+        }
+    }
+
+    #[inline]
+    fn contains(&self, date: i32) -> bool {
+        match self {
+            Self::Bitset {
+                first,
+                n_days,
+                words,
+            } => {
+                // Dates before `first` wrap around to offsets beyond `n_days`.
+                let offset = (date as i64 - *first as i64) as u64;
+                let word = if offset < *n_days {
+                    // SAFETY: `words` holds at least `n_days` bits.
+                    unsafe { *words.get_unchecked((offset / 64) as usize) }
+                } else {
+                    0
+                };
+                (word >> (offset % 64)) & 1 == 1
+            },
+            Self::Sorted(holidays) => holidays.binary_search(&date).is_ok(),
+        }
+    }
+}
+
+/// `week_mask` as a bitmask, where bit `i` is set if day `i` of the week is a business day.
+#[cfg(feature = "is_business_day")]
+fn week_mask_bits(week_mask: [bool; 7]) -> u8 {
+    week_mask
+        .iter()
+        .enumerate()
+        .fold(0, |bits, (i, &x)| bits | ((x as u8) << i))
+}
+
+/// The first day of the month that `date` is in.
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
+fn first_of_month(date: i32) -> i32 {
+    date - date32_to_date(date).day0() as i32
+}
+
+/// The number of business days in `week_mask`, i.e. the number of days of the week that are
+/// `true`.
+pub fn active_week_days(week_mask: [bool; 7]) -> u8 {
+    week_mask.iter().filter(|&&x| x).count() as u8
+}
+
 fn get_day_of_week(x: i32) -> usize {
     // the first modulo might return a negative number, so we add 7 and take
     // the modulo again so we're sure we have something between 0 (Monday)
@@ -446,6 +2003,7 @@ fn increment_day_of_week(x: usize) -> usize {
     if x == 6 { 0 } else { x + 1 }
 }
 
+#[cfg(feature = "add_business_days")]
 fn decrement_day_of_week(x: usize) -> usize {
     if x == 0 { 6 } else { x - 1 }
 }
@@ -0,0 +1,316 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+
+/// A named calendar of public holidays, for use as the `holidays` of the business day
+/// functions.
+///
+/// Only the regular holidays of each calendar are generated; one-off holidays, such as royal
+/// jubilees, are not included.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HolidayCalendar {
+    /// United States federal holidays, on the days they are observed. A holiday on a Saturday
+    /// is observed on the preceding Friday and a holiday on a Sunday on the following Monday.
+    UsFederal,
+    /// Bank holidays of England and Wales, including substitute days for holidays on weekends.
+    UkEngland,
+    /// The closing days of the TARGET2 (T2) euro payment system.
+    Target2,
+}
+
+impl HolidayCalendar {
+    /// Look up a calendar by its name: `"US_FEDERAL"`, `"UK_ENG"` or `"TARGET2"`.
+    ///
+    /// Names are matched case-insensitively.
+    pub fn from_name(name: &str) -> PolarsResult<Self> {
+        match name.to_uppercase().as_str() {
+            "US_FEDERAL" => Ok(Self::UsFederal),
+            "UK_ENG" => Ok(Self::UkEngland),
+            "TARGET2" => Ok(Self::Target2),
+            _ => polars_bail!(InvalidOperation: "unknown holiday calendar: '{}'", name),
+        }
+    }
+
+    /// The holidays of the years `start_year` until `end_year`, both inclusive, as the number of
+    /// days since the UNIX epoch, sorted.
+    ///
+    /// Holidays that are observed on a day of an adjacent year are included with the year they
+    /// belong to, e.g. New Year's Day of 2022 is observed on 2021-12-31 in the US. Errors if a
+    /// year is outside of [`MIN_HOLIDAY_YEAR`] and [`MAX_HOLIDAY_YEAR`].
+    pub fn holidays(self, start_year: i32, end_year: i32) -> PolarsResult<Vec<i32>> {
+        for year in [start_year, end_year] {
+            polars_ensure!(
+                (MIN_HOLIDAY_YEAR..=MAX_HOLIDAY_YEAR).contains(&year),
+                InvalidOperation: "year {} of holiday calendar {:?} must be between {} and {}",
+                year, self, MIN_HOLIDAY_YEAR, MAX_HOLIDAY_YEAR
+            );
+        }
+        let mut holidays = Vec::new();
+        for year in start_year..=end_year {
+            match self {
+                Self::UsFederal => us_federal_holidays(year, &mut holidays)?,
+                Self::UkEngland => uk_england_holidays(year, &mut holidays)?,
+                Self::Target2 => target2_holidays(year, &mut holidays)?,
+            }
+        }
+        holidays.sort_unstable();
+        Ok(holidays
+            .into_iter()
+            .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+            .collect())
+    }
+}
+
+/// The first year that [`HolidayCalendar::holidays`] accepts, the first full year of the
+/// Gregorian calendar, which the date of Easter is computed in.
+pub const MIN_HOLIDAY_YEAR: i32 = 1583;
+
+/// The last year that [`HolidayCalendar::holidays`] accepts, the last year with four digits.
+pub const MAX_HOLIDAY_YEAR: i32 = 9999;
+
+/// The holidays of the calendar with the given `name` for the years `start_year` until
+/// `end_year`, both inclusive. See [`HolidayCalendar::from_name`] for the accepted names.
+pub fn holiday_calendar(name: &str, start_year: i32, end_year: i32) -> PolarsResult<Vec<i32>> {
+    HolidayCalendar::from_name(name)?.holidays(start_year, end_year)
+}
+
+fn us_federal_holidays(year: i32, out: &mut Vec<NaiveDate>) -> PolarsResult<()> {
+    // Weekend holidays move to the nearest weekday.
+    let observed = |date: NaiveDate| {
+        match date.weekday() {
+            Weekday::Sat => date.pred_opt(),
+            Weekday::Sun => date.succ_opt(),
+            _ => Some(date),
+        }
+        .ok_or_else(|| out_of_range(year))
+    };
+
+    out.push(observed(ymd(year, 1, 1)?)?);
+    if year >= 1986 {
+        // Birthday of Martin Luther King, Jr.
+        out.push(nth_weekday(year, 1, Weekday::Mon, 3)?);
+    }
+    // Washington's Birthday
+    out.push(nth_weekday(year, 2, Weekday::Mon, 3)?);
+    // Memorial Day
+    out.push(last_weekday(year, 5, Weekday::Mon)?);
+    if year >= 2021 {
+        // Juneteenth National Independence Day
+        out.push(observed(ymd(year, 6, 19)?)?);
+    }
+    out.push(observed(ymd(year, 7, 4)?)?);
+    // Labor Day
+    out.push(nth_weekday(year, 9, Weekday::Mon, 1)?);
+    // Columbus Day
+    out.push(nth_weekday(year, 10, Weekday::Mon, 2)?);
+    // Veterans Day
+    out.push(observed(ymd(year, 11, 11)?)?);
+    // Thanksgiving Day
+    out.push(nth_weekday(year, 11, Weekday::Thu, 4)?);
+    out.push(observed(ymd(year, 12, 25)?)?);
+    Ok(())
+}
+
+fn uk_england_holidays(year: i32, out: &mut Vec<NaiveDate>) -> PolarsResult<()> {
+    let easter = easter_sunday(year)?;
+
+    // New Year's Day is substituted by the following Monday.
+    let new_year = ymd(year, 1, 1)?;
+    out.push(match new_year.weekday() {
+        Weekday::Sat => ymd(year, 1, 3)?,
+        Weekday::Sun => ymd(year, 1, 2)?,
+        _ => new_year,
+    });
+    // Good Friday and Easter Monday
+    out.push(add_days(easter, -2)?);
+    out.push(add_days(easter, 1)?);
+    // Early May, Spring and Summer bank holidays
+    out.push(nth_weekday(year, 5, Weekday::Mon, 1)?);
+    out.push(last_weekday(year, 5, Weekday::Mon)?);
+    out.push(last_weekday(year, 8, Weekday::Mon)?);
+    // Christmas Day and Boxing Day are substituted by the next weekdays that aren't holidays.
+    let (christmas, boxing_day) = match ymd(year, 12, 25)?.weekday() {
+        Weekday::Fri => (25, 28),
+        Weekday::Sat => (27, 28),
+        Weekday::Sun => (27, 26),
+        _ => (25, 26),
+    };
+    out.push(ymd(year, 12, christmas)?);
+    out.push(ymd(year, 12, boxing_day)?);
+    Ok(())
+}
+
+fn target2_holidays(year: i32, out: &mut Vec<NaiveDate>) -> PolarsResult<()> {
+    let easter = easter_sunday(year)?;
+
+    out.push(ymd(year, 1, 1)?);
+    // Good Friday and Easter Monday
+    out.push(add_days(easter, -2)?);
+    out.push(add_days(easter, 1)?);
+    // Labour Day
+    out.push(ymd(year, 5, 1)?);
+    out.push(ymd(year, 12, 25)?);
+    out.push(ymd(year, 12, 26)?);
+    Ok(())
+}
+
+fn out_of_range(year: i32) -> PolarsError {
+    polars_err!(InvalidOperation: "holidays of year {} are out of the range of dates", year)
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> PolarsResult<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| out_of_range(year))
+}
+
+fn add_days(date: NaiveDate, days: i64) -> PolarsResult<NaiveDate> {
+    date.checked_add_signed(chrono::TimeDelta::days(days))
+        .ok_or_else(|| out_of_range(date.year()))
+}
+
+/// The `n`th `weekday` of the given month, counting from 1.
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> PolarsResult<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).ok_or_else(|| out_of_range(year))
+}
+
+/// The last `weekday` of the given month.
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> PolarsResult<NaiveDate> {
+    match NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5) {
+        Some(date) => Ok(date),
+        None => nth_weekday(year, month, weekday, 4),
+    }
+}
+
+/// The date of Easter Sunday in the Gregorian calendar, computed with the anonymous Gregorian
+/// algorithm (Meeus/Jones/Butcher).
+fn easter_sunday(year: i32) -> PolarsResult<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ymd(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn days(dates: &[(i32, u32, u32)]) -> Vec<i32> {
+        dates
+            .iter()
+            .map(|&(year, month, day)| {
+                ymd(year, month, day).unwrap().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_us_federal() {
+        assert_eq!(
+            holiday_calendar("US_FEDERAL", 2024, 2024).unwrap(),
+            days(&[
+                (2024, 1, 1),
+                (2024, 1, 15),
+                (2024, 2, 19),
+                (2024, 5, 27),
+                (2024, 6, 19),
+                (2024, 7, 4),
+                (2024, 9, 2),
+                (2024, 10, 14),
+                (2024, 11, 11),
+                (2024, 11, 28),
+                (2024, 12, 25),
+            ])
+        );
+        // New Year's Day 2022 is a Saturday and so is observed in 2021.
+        let holidays = HolidayCalendar::UsFederal.holidays(2022, 2022).unwrap();
+        assert_eq!(holidays[0], days(&[(2021, 12, 31)])[0]);
+        // Juneteenth 2022 is a Sunday.
+        assert!(holidays.contains(&days(&[(2022, 6, 20)])[0]));
+        assert_eq!(
+            HolidayCalendar::UsFederal
+                .holidays(2020, 2020)
+                .unwrap()
+                .len(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_uk_england() {
+        assert_eq!(
+            holiday_calendar("uk_eng", 2024, 2024).unwrap(),
+            days(&[
+                (2024, 1, 1),
+                (2024, 3, 29),
+                (2024, 4, 1),
+                (2024, 5, 6),
+                (2024, 5, 27),
+                (2024, 8, 26),
+                (2024, 12, 25),
+                (2024, 12, 26),
+            ])
+        );
+        let holidays = HolidayCalendar::UkEngland.holidays(2021, 2022).unwrap();
+        // Christmas 2021 is a Saturday, Christmas 2022 a Sunday.
+        assert!(holidays.ends_with(&days(&[(2022, 12, 26), (2022, 12, 27)])));
+        assert!(holidays.contains(&days(&[(2021, 12, 27)])[0]));
+        assert!(holidays.contains(&days(&[(2021, 12, 28)])[0]));
+        // New Year's Day 2022 is a Saturday.
+        assert!(holidays.contains(&days(&[(2022, 1, 3)])[0]));
+    }
+
+    #[test]
+    fn test_target2() {
+        assert_eq!(
+            holiday_calendar("TARGET2", 2025, 2025).unwrap(),
+            days(&[
+                (2025, 1, 1),
+                (2025, 4, 18),
+                (2025, 4, 21),
+                (2025, 5, 1),
+                (2025, 12, 25),
+                (2025, 12, 26),
+            ])
+        );
+        assert!(holiday_calendar("NYSE", 2025, 2025).is_err());
+        assert!(
+            HolidayCalendar::Target2
+                .holidays(2025, 2024)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_years() {
+        for calendar in [
+            HolidayCalendar::UsFederal,
+            HolidayCalendar::UkEngland,
+            HolidayCalendar::Target2,
+        ] {
+            assert!(calendar.holidays(MIN_HOLIDAY_YEAR - 1, 2000).is_err());
+            assert!(calendar.holidays(2000, MAX_HOLIDAY_YEAR + 1).is_err());
+            assert!(calendar.holidays(i32::MIN, i32::MAX).is_err());
+            assert!(
+                calendar
+                    .holidays(MAX_HOLIDAY_YEAR, MAX_HOLIDAY_YEAR)
+                    .is_ok()
+            );
+            assert!(
+                calendar
+                    .holidays(MIN_HOLIDAY_YEAR, MIN_HOLIDAY_YEAR)
+                    .is_ok()
+            );
+        }
+    }
+}
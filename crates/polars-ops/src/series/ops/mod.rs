@@ -2,7 +2,11 @@
 mod abs;
 mod arg_min_max;
 mod bitwise;
-#[cfg(feature = "business")]
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
 mod business;
 mod clip;
 #[cfg(feature = "cum_agg")]
@@ -19,6 +23,12 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+mod holiday_calendar;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -68,7 +78,11 @@ mod various;
 pub use abs::*;
 pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
-#[cfg(feature = "business")]
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
 pub use business::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
@@ -85,6 +99,12 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+pub use holiday_calendar::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -5,18 +5,131 @@ pub struct DateLikeNameSpace(pub(crate) Expr);
 
 impl DateLikeNameSpace {
     /// Add a given number of business days.
-    #[cfg(feature = "business")]
+    ///
+    /// Datetimes keep their time of day, and only their date is rolled and stepped. For time
+    /// zone aware datetimes this is the local time of day, so across a DST transition the elapsed
+    /// time isn't a whole number of days. It is an error if the resulting local time doesn't
+    /// exist or is ambiguous.
+    #[cfg(feature = "add_business_days")]
     pub fn add_business_days(
         self,
         n: Expr,
         week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        holidays: impl Into<Holidays>,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::AddBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
+                roll,
+                unit: BusinessDayCountUnit::Days,
+                per_row_week_mask: false,
+            }),
+            &[n],
+            false,
+            None,
+        )
+    }
+
+    /// Add a given number of business days or, with [`BusinessDayCountUnit::Weeks`], business
+    /// weeks.
+    ///
+    /// A business week is as many business days as there are in `week_mask`, so whole weeks
+    /// land on the same day of the week unless holidays are skipped.
+    #[cfg(feature = "add_business_days")]
+    pub fn add_business_days_in_unit(
+        self,
+        n: Expr,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+        roll: Roll,
+        unit: BusinessDayCountUnit,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::AddBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
+                roll,
+                unit,
+                per_row_week_mask: false,
+            }),
+            &[n],
+            false,
+            None,
+        )
+    }
+
+    /// Add a given number of business days, with a separate week mask for each row.
+    ///
+    /// `week_masks` must evaluate to boolean lists of length 7 starting on Monday, or to integer
+    /// bitmasks where bit 0 is Monday and bit 6 is Sunday.
+    #[cfg(feature = "add_business_days")]
+    pub fn add_business_days_with_week_masks(
+        self,
+        n: Expr,
+        week_masks: Expr,
+        holidays: impl Into<Holidays>,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::AddBusinessDay {
+                // Unused, the week masks are taken from `week_masks` instead.
+                week_mask: [true; 7],
+                holidays: holidays.into(),
+                roll,
+                unit: BusinessDayCountUnit::Days,
+                per_row_week_mask: true,
+            }),
+            &[n, week_masks],
+            false,
+            None,
+        )
+    }
+
+    /// Add a given number of business days, with the holidays taken from the `holidays`
+    /// expression, which is evaluated at execution time.
+    ///
+    /// Null holidays are ignored. In a group-by or window context the expression is evaluated
+    /// per group, so each group only takes the holidays of its own rows into account.
+    #[cfg(feature = "add_business_days")]
+    pub fn add_business_days_with_holidays(
+        self,
+        n: Expr,
+        week_mask: [bool; 7],
+        holidays: Expr,
         roll: Roll,
+        unit: BusinessDayCountUnit,
     ) -> Expr {
         self.0.map_many_private(
             FunctionExpr::Business(BusinessFunction::AddBusinessDay {
                 week_mask,
-                holidays,
+                holidays: Holidays::default(),
+                roll,
+                unit,
+                per_row_week_mask: false,
+            }),
+            &[n, holidays],
+            false,
+            None,
+        )
+    }
+
+    /// Subtract a given number of business days.
+    ///
+    /// Non-business start days are rolled according to `roll` before subtracting.
+    #[cfg(feature = "add_business_days")]
+    pub fn sub_business_days(
+        self,
+        n: Expr,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::SubBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
                 roll,
             }),
             &[n],
@@ -25,6 +138,30 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Subtract a given number of business days, with the holidays taken from the `holidays`
+    /// expression, which is evaluated at execution time.
+    ///
+    /// See [`DateLikeNameSpace::add_business_days_with_holidays`].
+    #[cfg(feature = "add_business_days")]
+    pub fn sub_business_days_with_holidays(
+        self,
+        n: Expr,
+        week_mask: [bool; 7],
+        holidays: Expr,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::SubBusinessDay {
+                week_mask,
+                holidays: Holidays::default(),
+                roll,
+            }),
+            &[n, holidays],
+            false,
+            None,
+        )
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -85,16 +222,176 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Year))
     }
 
+    /// Get the next business day after each day.
+    ///
+    /// If `inclusive`, business days are their own next business day.
+    #[cfg(feature = "add_business_days")]
+    pub fn next_business_day(
+        self,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+        inclusive: bool,
+    ) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::NextBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
+                inclusive,
+            }))
+    }
+
+    /// Get the previous business day before each day.
+    ///
+    /// If `inclusive`, business days are their own previous business day.
+    #[cfg(feature = "add_business_days")]
+    pub fn previous_business_day(
+        self,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+        inclusive: bool,
+    ) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::PreviousBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
+                inclusive,
+            },
+        ))
+    }
+
+    /// Get the last business day of the month of each day, i.e. the last business day on or
+    /// before the end of its month.
+    #[cfg(feature = "add_business_days")]
+    pub fn business_month_end(self, week_mask: [bool; 7], holidays: impl Into<Holidays>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::BusinessMonthEnd {
+                week_mask,
+                holidays: holidays.into(),
+            }))
+    }
+
+    /// Get the first business day of the month of each day, i.e. the first business day on or
+    /// after the start of its month.
+    #[cfg(feature = "add_business_days")]
+    pub fn business_month_begin(self, week_mask: [bool; 7], holidays: impl Into<Holidays>) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::BusinessMonthBegin {
+                week_mask,
+                holidays: holidays.into(),
+            },
+        ))
+    }
+
     /// Determine whether days are business days.
-    #[cfg(feature = "business")]
-    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+    ///
+    /// Datetimes are truncated to the date of their local time first, in their own time zone for
+    /// time zone aware datetimes.
+    #[cfg(feature = "is_business_day")]
+    pub fn is_business_day(self, week_mask: [bool; 7], holidays: impl Into<Holidays>) -> Expr {
         self.0
             .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
                 week_mask,
-                holidays,
+                holidays: holidays.into(),
+                with_reason: false,
             }))
     }
 
+    /// Determine whether days are business days, with the holidays taken from the `holidays`
+    /// expression, which is evaluated at execution time.
+    ///
+    /// See [`DateLikeNameSpace::add_business_days_with_holidays`].
+    #[cfg(feature = "is_business_day")]
+    pub fn is_business_day_with_holidays(self, week_mask: [bool; 7], holidays: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                week_mask,
+                holidays: Holidays::default(),
+                with_reason: false,
+            }),
+            &[holidays],
+            false,
+            None,
+        )
+    }
+
+    /// Determine whether days are holidays.
+    ///
+    /// Unlike [`DateLikeNameSpace::is_business_day`], this doesn't take the week mask into
+    /// account: holidays on weekends are holidays as well.
+    #[cfg(feature = "is_business_day")]
+    pub fn is_holiday(self, holidays: impl Into<Holidays>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsHoliday {
+                holidays: holidays.into(),
+            }))
+    }
+
+    /// Determine whether days are weekend days, i.e. excluded by `week_mask`.
+    ///
+    /// Unlike [`DateLikeNameSpace::is_business_day`], this doesn't take holidays into account.
+    #[cfg(feature = "is_business_day")]
+    pub fn is_weekend(self, week_mask: [bool; 7]) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsWeekend {
+                week_mask,
+            }))
+    }
+
+    /// Count the business days until the next holiday at or after each day.
+    ///
+    /// A holiday is zero business days away from itself. Days without a holiday at or after
+    /// them are null.
+    #[cfg(feature = "is_business_day")]
+    pub fn business_days_until_holiday(
+        self,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+    ) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::BusinessDaysUntilHoliday {
+                week_mask,
+                holidays: holidays.into(),
+            },
+        ))
+    }
+
+    /// Determine why days are or aren't business days.
+    ///
+    /// Returns `"business"`, `"weekend"` or `"holiday"` for each day.
+    #[cfg(feature = "is_business_day")]
+    pub fn business_day_reason(self, week_mask: [bool; 7], holidays: impl Into<Holidays>) -> Expr {
+        self.0
+            .map_private(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
+                week_mask,
+                holidays: holidays.into(),
+                with_reason: true,
+            }))
+    }
+
+    /// Classify days as `"business"`, `"weekend"` or `"holiday"`.
+    ///
+    /// Days excluded by `week_mask` are classified as `"weekend"`, even if they are holidays. This
+    /// is the same as [`DateLikeNameSpace::business_day_reason`].
+    #[cfg(feature = "is_business_day")]
+    pub fn business_day_kind(self, week_mask: [bool; 7], holidays: impl Into<Holidays>) -> Expr {
+        self.business_day_reason(week_mask, holidays)
+    }
+
+    /// Determine which business day of its month each day is, counting from 1.
+    #[cfg(feature = "is_business_day")]
+    pub fn business_day_of_month(
+        self,
+        week_mask: [bool; 7],
+        holidays: impl Into<Holidays>,
+    ) -> Expr {
+        self.0.map_private(FunctionExpr::Business(
+            BusinessFunction::BusinessDayOfMonth {
+                week_mask,
+                holidays: holidays.into(),
+            },
+        ))
+    }
+
     // Compute whether the year of a Date/Datetime is a leap year.
     pub fn is_leap_year(self) -> Expr {
         self.0
@@ -1,7 +1,22 @@
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 use polars_core::prelude::*;
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
+use polars_ops::prelude::BusinessDayCountUnit;
+#[cfg(feature = "add_business_days")]
 use polars_ops::prelude::Roll;
+#[cfg(feature = "business_day_count")]
+use polars_ops::prelude::{BusinessPeriod, ClosedInterval};
+use polars_ops::prelude::{Holidays, MAX_HOLIDAY_DAYS};
+#[cfg(feature = "business_day_count")]
+use polars_time::Duration;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,36 +25,536 @@ use crate::map_as_slice;
 use crate::prelude::ColumnsUdf;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum BusinessFunction {
-    #[cfg(feature = "business")]
+    #[cfg(feature = "business_day_count")]
     BusinessDayCount {
         week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        holidays: Holidays,
+        closed: ClosedInterval,
+        unit: BusinessDayCountUnit,
+        hours_per_day: Option<f64>,
+        /// If set, the last input holds a week mask per row, which takes the place of
+        /// `week_mask`.
+        per_row_week_mask: bool,
+        /// If set, the start of every row is this day and the first input is the end.
+        anchor: Option<BusinessDayAnchor>,
     },
-    #[cfg(feature = "business")]
+    /// Count the calendar days, weekend days and holidays between two dates as a struct.
+    #[cfg(feature = "business_day_count")]
+    BusinessDayCountBreakdown {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        closed: ClosedInterval,
+    },
+    /// Count the business days between two dates per calendar period, as a list of structs.
+    #[cfg(feature = "business_day_count")]
+    BusinessDayCountByPeriod {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        period: BusinessPeriod,
+    },
+    /// Count the business days between two datetimes, including the fractions of partial days.
+    #[cfg(feature = "business_day_count")]
+    BusinessDayCountFractional {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+    },
+    /// Count the business hours between two datetimes as `Float64`.
+    ///
+    /// Only the time of day between `start` and `end` of each business day counts, so the
+    /// partial first and last days are clipped to these working hours.
+    #[cfg(feature = "business_day_count")]
+    BusinessHourCount {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        /// The time of day at which business hours start.
+        start: Duration,
+        /// The time of day at which business hours end.
+        end: Duration,
+    },
+    #[cfg(feature = "add_business_days")]
     AddBusinessDay {
         week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        holidays: Holidays,
         roll: Roll,
+        /// Whether the second input is a number of business days or business weeks.
+        unit: BusinessDayCountUnit,
+        /// If set, the last input holds a week mask per row, which takes the place of
+        /// `week_mask`.
+        per_row_week_mask: bool,
     },
-    #[cfg(feature = "business")]
+    #[cfg(feature = "add_business_days")]
+    SubBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        roll: Roll,
+    },
+    #[cfg(feature = "add_business_days")]
+    NextBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        inclusive: bool,
+    },
+    #[cfg(feature = "add_business_days")]
+    PreviousBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        inclusive: bool,
+    },
+    /// Get the last business day on or before the end of the month of each day.
+    #[cfg(feature = "add_business_days")]
+    BusinessMonthEnd {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+    },
+    /// Get the first business day on or after the start of the month of each day.
+    #[cfg(feature = "add_business_days")]
+    BusinessMonthBegin {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+    },
+    #[cfg(feature = "is_business_day")]
     IsBusinessDay {
         week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        holidays: Holidays,
+        /// Return why each day is or isn't a business day instead of a boolean.
+        with_reason: bool,
+    },
+    /// Determine whether days are holidays, regardless of the day of the week.
+    #[cfg(feature = "is_business_day")]
+    IsHoliday { holidays: Holidays },
+    /// Determine whether days are excluded by the week mask, regardless of holidays.
+    #[cfg(feature = "is_business_day")]
+    IsWeekend { week_mask: [bool; 7] },
+    /// Count the business days until the next holiday at or after each day.
+    #[cfg(feature = "is_business_day")]
+    BusinessDaysUntilHoliday {
+        week_mask: [bool; 7],
+        holidays: Holidays,
     },
+    #[cfg(feature = "is_business_day")]
+    BusinessDayOfMonth {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+    },
+    #[cfg(feature = "add_business_days")]
+    BusinessDateRange {
+        week_mask: [bool; 7],
+        holidays: Holidays,
+        step: u32,
+    },
+}
+
+/// The day a [`BusinessFunction::BusinessDayCount`] counts from for every row.
+#[cfg(feature = "business_day_count")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BusinessDayAnchor {
+    /// A fixed day, in days since the UNIX epoch.
+    Date(i32),
+    /// Today's date in the local time zone, determined when the expression is executed.
+    Today,
+}
+
+#[cfg(feature = "business_day_count")]
+impl BusinessDayAnchor {
+    /// The anchor in days since the UNIX epoch.
+    fn to_days(self) -> i32 {
+        match self {
+            Self::Date(days) => days,
+            Self::Today => {
+                use chrono::Datelike;
+                use polars_core::utils::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+
+                chrono::Local::now().date_naive().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+            },
+        }
+    }
+}
+
+impl BusinessFunction {
+    /// The week mask that applies to all rows, if any.
+    fn week_mask(&self) -> Option<&[bool; 7]> {
+        use BusinessFunction::*;
+        match self {
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCount {
+                per_row_week_mask: true,
+                ..
+            } => None,
+            #[cfg(feature = "add_business_days")]
+            AddBusinessDay {
+                per_row_week_mask: true,
+                ..
+            } => None,
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCount { week_mask, .. }
+            | BusinessDayCountBreakdown { week_mask, .. }
+            | BusinessDayCountByPeriod { week_mask, .. }
+            | BusinessDayCountFractional { week_mask, .. }
+            | BusinessHourCount { week_mask, .. } => Some(week_mask),
+            #[cfg(feature = "add_business_days")]
+            AddBusinessDay { week_mask, .. }
+            | SubBusinessDay { week_mask, .. }
+            | NextBusinessDay { week_mask, .. }
+            | PreviousBusinessDay { week_mask, .. }
+            | BusinessMonthEnd { week_mask, .. }
+            | BusinessMonthBegin { week_mask, .. }
+            | BusinessDateRange { week_mask, .. } => Some(week_mask),
+            // Every day being a weekend day is fine when only testing for weekends.
+            #[cfg(feature = "is_business_day")]
+            IsHoliday { .. } | IsWeekend { .. } => None,
+            #[cfg(feature = "is_business_day")]
+            IsBusinessDay { week_mask, .. }
+            | BusinessDaysUntilHoliday { week_mask, .. }
+            | BusinessDayOfMonth { week_mask, .. } => Some(week_mask),
+        }
+    }
+
+    /// Check that the week mask, if any, has at least one business day and that the holidays
+    /// contain at most [`MAX_HOLIDAY_DAYS`] days.
+    ///
+    /// Without business days, rolling to or stepping over business days would never end. The
+    /// holidays are expanded to individual days when the function is executed.
+    pub fn validate(&self) -> PolarsResult<()> {
+        if let Some(week_mask) = self.week_mask() {
+            polars_ensure!(
+                week_mask.iter().any(|&x| x),
+                InvalidOperation: "`week_mask` must contain at least one business day in {}", self
+            );
+        }
+        if let Some(holidays) = self.holidays() {
+            let n_days = holidays.n_days();
+            polars_ensure!(
+                n_days <= MAX_HOLIDAY_DAYS,
+                InvalidOperation: "`holidays` may contain at most {} days, got {} in {}",
+                MAX_HOLIDAY_DAYS, n_days, self
+            );
+        }
+        Ok(())
+    }
+
+    /// The literal holidays, if any.
+    fn holidays(&self) -> Option<&Holidays> {
+        use BusinessFunction::*;
+        match self {
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCount { holidays, .. }
+            | BusinessDayCountBreakdown { holidays, .. }
+            | BusinessDayCountByPeriod { holidays, .. }
+            | BusinessDayCountFractional { holidays, .. }
+            | BusinessHourCount { holidays, .. } => Some(holidays),
+            #[cfg(feature = "add_business_days")]
+            AddBusinessDay { holidays, .. }
+            | SubBusinessDay { holidays, .. }
+            | NextBusinessDay { holidays, .. }
+            | PreviousBusinessDay { holidays, .. }
+            | BusinessMonthEnd { holidays, .. }
+            | BusinessMonthBegin { holidays, .. }
+            | BusinessDateRange { holidays, .. } => Some(holidays),
+            #[cfg(feature = "is_business_day")]
+            IsBusinessDay { holidays, .. }
+            | IsHoliday { holidays }
+            | BusinessDaysUntilHoliday { holidays, .. }
+            | BusinessDayOfMonth { holidays, .. } => Some(holidays),
+            #[cfg(feature = "is_business_day")]
+            IsWeekend { .. } => None,
+        }
+    }
+
+    /// Create a [`BusinessFunction::BusinessDayCount`] with its week mask given as weekday names.
+    ///
+    /// See [`week_mask_from_weekday_names`] for the accepted names.
+    #[cfg(feature = "business_day_count")]
+    pub fn new_business_day_count(
+        weekdays: &[&str],
+        holidays: impl Into<Holidays>,
+        closed: ClosedInterval,
+        unit: BusinessDayCountUnit,
+        hours_per_day: Option<f64>,
+    ) -> PolarsResult<Self> {
+        let function = Self::BusinessDayCount {
+            week_mask: week_mask_from_weekday_names(weekdays)?,
+            holidays: holidays.into(),
+            closed,
+            unit,
+            hours_per_day,
+            per_row_week_mask: false,
+            anchor: None,
+        };
+        function.validate()?;
+        Ok(function)
+    }
+
+    /// Create a [`BusinessFunction::AddBusinessDay`] with its week mask given as weekday names.
+    ///
+    /// See [`week_mask_from_weekday_names`] for the accepted names.
+    #[cfg(feature = "add_business_days")]
+    pub fn new_add_business_day(
+        weekdays: &[&str],
+        holidays: impl Into<Holidays>,
+        roll: Roll,
+    ) -> PolarsResult<Self> {
+        let function = Self::AddBusinessDay {
+            week_mask: week_mask_from_weekday_names(weekdays)?,
+            holidays: holidays.into(),
+            roll,
+            unit: BusinessDayCountUnit::Days,
+            per_row_week_mask: false,
+        };
+        function.validate()?;
+        Ok(function)
+    }
+
+    /// Create a [`BusinessFunction::IsBusinessDay`] with its week mask given as weekday names.
+    ///
+    /// See [`week_mask_from_weekday_names`] for the accepted names.
+    #[cfg(feature = "is_business_day")]
+    pub fn new_is_business_day(
+        weekdays: &[&str],
+        holidays: impl Into<Holidays>,
+    ) -> PolarsResult<Self> {
+        let function = Self::IsBusinessDay {
+            week_mask: week_mask_from_weekday_names(weekdays)?,
+            holidays: holidays.into(),
+            with_reason: false,
+        };
+        function.validate()?;
+        Ok(function)
+    }
+}
+
+/// Build a week mask from the names of the business days of the week.
+///
+/// Names are matched case-insensitively and can be abbreviated to their first three letters,
+/// e.g. `["Mon", "Tue", "Wed", "Thu", "Fri"]`. The resulting mask starts on Monday, so index 0
+/// is Monday and index 6 is Sunday.
+pub fn week_mask_from_weekday_names(weekdays: &[&str]) -> PolarsResult<[bool; 7]> {
+    const WEEKDAYS: [&str; 7] = [
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+        "sunday",
+    ];
+
+    let mut week_mask = [false; 7];
+    for name in weekdays {
+        let lowercase = name.to_lowercase();
+        let Some(idx) = WEEKDAYS
+            .iter()
+            .position(|weekday| lowercase == *weekday || lowercase == weekday[..3])
+        else {
+            polars_bail!(InvalidOperation: "unknown weekday name: '{}'", name);
+        };
+        polars_ensure!(
+            !week_mask[idx],
+            InvalidOperation: "weekday '{}' was given more than once", name
+        );
+        week_mask[idx] = true;
+    }
+    Ok(week_mask)
+}
+
+impl Hash for BusinessFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        use BusinessFunction::*;
+        std::mem::discriminant(self).hash(state);
+        match self {
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCount {
+                week_mask,
+                holidays,
+                closed,
+                unit,
+                hours_per_day,
+                per_row_week_mask,
+                anchor,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                closed.hash(state);
+                unit.hash(state);
+                hours_per_day.map(f64::to_bits).hash(state);
+                per_row_week_mask.hash(state);
+                anchor.hash(state);
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountBreakdown {
+                week_mask,
+                holidays,
+                closed,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                closed.hash(state);
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountByPeriod {
+                week_mask,
+                holidays,
+                period,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                period.hash(state);
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessHourCount {
+                week_mask,
+                holidays,
+                start,
+                end,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                start.hash(state);
+                end.hash(state);
+            },
+            #[cfg(feature = "add_business_days")]
+            AddBusinessDay {
+                week_mask,
+                holidays,
+                roll,
+                unit,
+                per_row_week_mask,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                roll.hash(state);
+                unit.hash(state);
+                per_row_week_mask.hash(state);
+            },
+            #[cfg(feature = "add_business_days")]
+            SubBusinessDay {
+                week_mask,
+                holidays,
+                roll,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                roll.hash(state);
+            },
+            #[cfg(feature = "add_business_days")]
+            NextBusinessDay {
+                week_mask,
+                holidays,
+                inclusive,
+            }
+            | PreviousBusinessDay {
+                week_mask,
+                holidays,
+                inclusive,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                inclusive.hash(state);
+            },
+            #[cfg(feature = "add_business_days")]
+            BusinessMonthEnd {
+                week_mask,
+                holidays,
+            }
+            | BusinessMonthBegin {
+                week_mask,
+                holidays,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+            },
+            #[cfg(feature = "is_business_day")]
+            IsBusinessDay {
+                week_mask,
+                holidays,
+                with_reason,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                with_reason.hash(state);
+            },
+            #[cfg(feature = "is_business_day")]
+            IsHoliday { holidays } => {
+                holidays.hash(state);
+            },
+            #[cfg(feature = "is_business_day")]
+            IsWeekend { week_mask } => {
+                week_mask.hash(state);
+            },
+            #[cfg(feature = "is_business_day")]
+            BusinessDayOfMonth {
+                week_mask,
+                holidays,
+            }
+            | BusinessDaysUntilHoliday {
+                week_mask,
+                holidays,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountFractional {
+                week_mask,
+                holidays,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+            },
+            #[cfg(feature = "add_business_days")]
+            BusinessDateRange {
+                week_mask,
+                holidays,
+                step,
+            } => {
+                week_mask.hash(state);
+                holidays.hash(state);
+                step.hash(state);
+            },
+        }
+    }
 }
 
 impl Display for BusinessFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use BusinessFunction::*;
         let s = match self {
-            #[cfg(feature = "business")]
+            #[cfg(feature = "business_day_count")]
             &BusinessDayCount { .. } => "business_day_count",
-            #[cfg(feature = "business")]
+            #[cfg(feature = "business_day_count")]
+            &BusinessDayCountBreakdown { .. } => "business_day_count_breakdown",
+            #[cfg(feature = "business_day_count")]
+            &BusinessDayCountByPeriod { .. } => "business_day_count_by_period",
+            #[cfg(feature = "business_day_count")]
+            &BusinessDayCountFractional { .. } => "business_day_count_fractional",
+            #[cfg(feature = "business_day_count")]
+            &BusinessHourCount { .. } => "business_hour_count",
+            #[cfg(feature = "add_business_days")]
             &AddBusinessDay { .. } => "add_business_days",
-            #[cfg(feature = "business")]
+            #[cfg(feature = "add_business_days")]
+            &SubBusinessDay { .. } => "sub_business_days",
+            #[cfg(feature = "add_business_days")]
+            &NextBusinessDay { .. } => "next_business_day",
+            #[cfg(feature = "add_business_days")]
+            &PreviousBusinessDay { .. } => "previous_business_day",
+            #[cfg(feature = "add_business_days")]
+            &BusinessMonthEnd { .. } => "business_month_end",
+            #[cfg(feature = "add_business_days")]
+            &BusinessMonthBegin { .. } => "business_month_begin",
+            #[cfg(feature = "is_business_day")]
             &IsBusinessDay { .. } => "is_business_day",
+            #[cfg(feature = "is_business_day")]
+            &IsHoliday { .. } => "is_holiday",
+            #[cfg(feature = "is_business_day")]
+            &IsWeekend { .. } => "is_weekend",
+            #[cfg(feature = "is_business_day")]
+            &BusinessDaysUntilHoliday { .. } => "business_days_until_holiday",
+            #[cfg(feature = "is_business_day")]
+            &BusinessDayOfMonth { .. } => "business_day_of_month",
+            #[cfg(feature = "add_business_days")]
+            &BusinessDateRange { .. } => "business_date_range",
         };
         write!(f, "{s}")
     }
@@ -48,75 +563,780 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
     fn from(func: BusinessFunction) -> Self {
         use BusinessFunction::*;
         match func {
-            #[cfg(feature = "business")]
+            #[cfg(feature = "business_day_count")]
             BusinessDayCount {
                 week_mask,
                 holidays,
+                closed,
+                unit,
+                hours_per_day,
+                per_row_week_mask,
+                anchor,
             } => {
-                map_as_slice!(business_day_count, week_mask, &holidays)
+                let holidays = holidays.to_days();
+                map_as_slice!(
+                    business_day_count,
+                    week_mask,
+                    &holidays,
+                    closed,
+                    unit,
+                    hours_per_day,
+                    per_row_week_mask,
+                    anchor
+                )
             },
-            #[cfg(feature = "business")]
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountBreakdown {
+                week_mask,
+                holidays,
+                closed,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_day_count_breakdown, week_mask, &holidays, closed)
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountByPeriod {
+                week_mask,
+                holidays,
+                period,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_day_count_by_period, week_mask, &holidays, period)
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessDayCountFractional {
+                week_mask,
+                holidays,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_day_count_fractional, week_mask, &holidays)
+            },
+            #[cfg(feature = "business_day_count")]
+            BusinessHourCount {
+                week_mask,
+                holidays,
+                start,
+                end,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_hour_count, week_mask, &holidays, start, end)
+            },
+            #[cfg(feature = "add_business_days")]
             AddBusinessDay {
                 week_mask,
                 holidays,
                 roll,
+                unit,
+                per_row_week_mask,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(
+                    add_business_days,
+                    week_mask,
+                    &holidays,
+                    roll,
+                    unit,
+                    per_row_week_mask
+                )
+            },
+            #[cfg(feature = "add_business_days")]
+            SubBusinessDay {
+                week_mask,
+                holidays,
+                roll,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(sub_business_days, week_mask, &holidays, roll)
+            },
+            #[cfg(feature = "add_business_days")]
+            NextBusinessDay {
+                week_mask,
+                holidays,
+                inclusive,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(next_business_day, week_mask, &holidays, inclusive)
+            },
+            #[cfg(feature = "add_business_days")]
+            PreviousBusinessDay {
+                week_mask,
+                holidays,
+                inclusive,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(previous_business_day, week_mask, &holidays, inclusive)
+            },
+            #[cfg(feature = "add_business_days")]
+            BusinessMonthEnd {
+                week_mask,
+                holidays,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_month_end, week_mask, &holidays)
+            },
+            #[cfg(feature = "add_business_days")]
+            BusinessMonthBegin {
+                week_mask,
+                holidays,
             } => {
-                map_as_slice!(add_business_days, week_mask, &holidays, roll)
+                let holidays = holidays.to_days();
+                map_as_slice!(business_month_begin, week_mask, &holidays)
             },
-            #[cfg(feature = "business")]
+            #[cfg(feature = "is_business_day")]
             IsBusinessDay {
                 week_mask,
                 holidays,
+                with_reason,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(is_business_day, week_mask, &holidays, with_reason)
+            },
+            #[cfg(feature = "is_business_day")]
+            IsHoliday { holidays } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(is_holiday, &holidays)
+            },
+            #[cfg(feature = "is_business_day")]
+            IsWeekend { week_mask } => map_as_slice!(is_weekend, week_mask),
+            #[cfg(feature = "is_business_day")]
+            BusinessDaysUntilHoliday {
+                week_mask,
+                holidays,
             } => {
-                map_as_slice!(is_business_day, week_mask, &holidays)
+                let holidays = holidays.to_days();
+                map_as_slice!(business_days_until_holiday, week_mask, &holidays)
+            },
+            #[cfg(feature = "is_business_day")]
+            BusinessDayOfMonth {
+                week_mask,
+                holidays,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_day_of_month, week_mask, &holidays)
+            },
+            #[cfg(feature = "add_business_days")]
+            BusinessDateRange {
+                week_mask,
+                holidays,
+                step,
+            } => {
+                let holidays = holidays.to_days();
+                map_as_slice!(business_date_range, week_mask, &holidays, step)
             },
         }
     }
 }
 
-#[cfg(feature = "business")]
+#[cfg(feature = "business_day_count")]
+#[allow(clippy::too_many_arguments)]
 pub(super) fn business_day_count(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+    per_row_week_mask: bool,
+    anchor: Option<BusinessDayAnchor>,
+) -> PolarsResult<Column> {
+    let (s, week_masks) = match s.split_last() {
+        Some((week_masks, s)) if per_row_week_mask => (s, Some(week_masks)),
+        _ => (s, None),
+    };
+    let anchor_column;
+    let (start, s) = match anchor {
+        Some(anchor) => {
+            anchor_column = Column::new_scalar(
+                s[0].name().clone(),
+                Scalar::new(DataType::Date, AnyValue::Date(anchor.to_days())),
+                1,
+            );
+            (&anchor_column, s)
+        },
+        None => (&s[0], &s[1..]),
+    };
+    let end = &s[0];
+    let holidays = with_holiday_column(holidays, s.get(1))?;
+    // Per-row week masks take the place of `week_mask`.
+    if let Some(week_masks) = week_masks {
+        return polars_ops::prelude::business_day_count_with_week_masks(
+            start.as_materialized_series(),
+            end.as_materialized_series(),
+            week_masks.as_materialized_series(),
+            &holidays,
+            closed,
+            unit,
+            hours_per_day,
+        )
+        .map(Column::from);
+    }
+    // `polars_ops` broadcasts a single value, so a scalar bound, e.g. a fixed deadline, is
+    // passed as is instead of being materialized to the full length.
+    let out = polars_ops::prelude::business_day_count_in_unit(
+        &single_value_or_materialized(start),
+        &single_value_or_materialized(end),
+        week_mask,
+        &holidays,
+        closed,
+        unit,
+        hours_per_day,
+    )?
+    .with_name(start.name().clone());
+    let length = if start.len() == 1 {
+        end.len()
+    } else {
+        start.len()
+    };
+    let out = Column::from(out);
+    if out.len() == length {
+        Ok(out)
+    } else {
+        // Both bounds are scalars.
+        Ok(out.new_from_index(0, length))
+    }
+}
+
+/// The single value of a scalar column, or the materialized values of any other column.
+#[cfg(feature = "business_day_count")]
+fn single_value_or_materialized(column: &Column) -> Cow<'_, Series> {
+    match column.as_scalar_column() {
+        Some(scalar) => Cow::Owned(scalar.as_single_value_series()),
+        None => Cow::Borrowed(column.as_materialized_series()),
+    }
+}
+
+/// Add the non-null days of a holiday column to the literal holidays.
+///
+/// The result need not be sorted nor deduplicated, this is taken care of by `polars_ops`.
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+fn extend_holidays(holidays: &[i32], holiday_column: &Column) -> PolarsResult<Vec<i32>> {
+    let days = holiday_column
+        .as_materialized_series()
+        .cast(&DataType::Date)?;
+    let days = days.date()?;
+    let mut out = Vec::with_capacity(holidays.len() + days.len() - days.null_count());
+    out.extend_from_slice(holidays);
+    out.extend(days.physical().iter().flatten());
+    Ok(out)
+}
+
+/// The literal holidays, extended with the days of `holiday_column` if the holidays are also
+/// taken from an input.
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+fn with_holiday_column<'a>(
+    holidays: &'a [i32],
+    holiday_column: Option<&Column>,
+) -> PolarsResult<Cow<'a, [i32]>> {
+    Ok(match holiday_column {
+        Some(holiday_column) => Cow::Owned(extend_holidays(holidays, holiday_column)?),
+        None => Cow::Borrowed(holidays),
+    })
+}
+
+#[cfg(feature = "business_day_count")]
+pub(super) fn business_day_count_breakdown(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    closed: ClosedInterval,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
-    polars_ops::prelude::business_day_count(
+    polars_ops::prelude::business_day_count_breakdown(
         start.as_materialized_series(),
         end.as_materialized_series(),
         week_mask,
         holidays,
+        closed,
     )
     .map(Column::from)
 }
 
-#[cfg(feature = "business")]
+#[cfg(feature = "business_day_count")]
+pub(super) fn business_day_count_by_period(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    period: BusinessPeriod,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_day_count_by_period(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        week_mask,
+        holidays,
+        period,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "business_day_count")]
+pub(super) fn business_day_count_fractional(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_day_count_fractional(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        week_mask,
+        holidays,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "business_day_count")]
+pub(super) fn business_hour_count(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    day_start: Duration,
+    day_end: Duration,
+) -> PolarsResult<Column> {
+    for duration in [day_start, day_end] {
+        polars_ensure!(
+            duration.months() == 0 && !duration.negative(),
+            InvalidOperation: "business hours must be given as a non-negative duration without months, got {}",
+            duration
+        );
+    }
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_hour_count(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        week_mask,
+        holidays,
+        day_start.duration_ns(),
+        day_end.duration_ns(),
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "add_business_days")]
 pub(super) fn add_business_days(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
     roll: Roll,
+    unit: BusinessDayCountUnit,
+    per_row_week_mask: bool,
 ) -> PolarsResult<Column> {
+    let (s, week_masks) = match s.split_last() {
+        Some((week_masks, s)) if per_row_week_mask => (s, Some(week_masks)),
+        _ => (s, None),
+    };
     let start = &s[0];
     let n = &s[1];
-    polars_ops::prelude::add_business_days(
+    let holidays = with_holiday_column(holidays, s.get(2))?;
+    // Per-row week masks take the place of `week_mask`.
+    if let Some(week_masks) = week_masks {
+        polars_ensure!(
+            unit == BusinessDayCountUnit::Days,
+            InvalidOperation: "business weeks can't be added with a week mask per row"
+        );
+        return polars_ops::prelude::add_business_days_with_week_masks(
+            start.as_materialized_series(),
+            n.as_materialized_series(),
+            week_masks.as_materialized_series(),
+            &holidays,
+            roll,
+        )
+        .map(Column::from);
+    }
+    polars_ops::prelude::add_business_days_in_unit(
         start.as_materialized_series(),
         n.as_materialized_series(),
         week_mask,
-        holidays,
+        &holidays,
+        roll,
+        unit,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "add_business_days")]
+pub(super) fn sub_business_days(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let n = &s[1];
+    let holidays = with_holiday_column(holidays, s.get(2))?;
+    polars_ops::prelude::sub_business_days(
+        start.as_materialized_series(),
+        n.as_materialized_series(),
+        week_mask,
+        &holidays,
         roll,
     )
     .map(Column::from)
 }
 
-#[cfg(feature = "business")]
+#[cfg(feature = "add_business_days")]
+pub(super) fn next_business_day(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    inclusive: bool,
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::next_business_day(
+        dates.as_materialized_series(),
+        week_mask,
+        holidays,
+        inclusive,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "add_business_days")]
+pub(super) fn previous_business_day(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    inclusive: bool,
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::previous_business_day(
+        dates.as_materialized_series(),
+        week_mask,
+        holidays,
+        inclusive,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "add_business_days")]
+pub(super) fn business_month_end(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::business_month_end(dates.as_materialized_series(), week_mask, holidays)
+        .map(Column::from)
+}
+
+#[cfg(feature = "add_business_days")]
+pub(super) fn business_month_begin(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::business_month_begin(dates.as_materialized_series(), week_mask, holidays)
+        .map(Column::from)
+}
+
+#[cfg(feature = "is_business_day")]
 pub(super) fn is_business_day(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    with_reason: bool,
 ) -> PolarsResult<Column> {
+    let dates = s[0].as_materialized_series();
+    let holidays = with_holiday_column(holidays, s.get(1))?;
+    if with_reason {
+        polars_ops::prelude::business_day_reason(dates, week_mask, &holidays)
+    } else {
+        polars_ops::prelude::is_business_day(dates, week_mask, &holidays)
+    }
+    .map(Column::from)
+}
+
+#[cfg(feature = "is_business_day")]
+pub(super) fn is_holiday(s: &[Column], holidays: &[i32]) -> PolarsResult<Column> {
     let dates = &s[0];
-    polars_ops::prelude::is_business_day(dates.as_materialized_series(), week_mask, holidays)
+    polars_ops::prelude::is_holiday(dates.as_materialized_series(), holidays).map(Column::from)
+}
+
+#[cfg(feature = "is_business_day")]
+pub(super) fn is_weekend(s: &[Column], week_mask: [bool; 7]) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::is_weekend(dates.as_materialized_series(), week_mask).map(Column::from)
+}
+
+#[cfg(feature = "is_business_day")]
+pub(super) fn business_days_until_holiday(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::business_days_until_holiday(
+        dates.as_materialized_series(),
+        week_mask,
+        holidays,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "is_business_day")]
+pub(super) fn business_day_of_month(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Column> {
+    let dates = &s[0];
+    polars_ops::prelude::business_day_of_month(dates.as_materialized_series(), week_mask, holidays)
         .map(Column::from)
 }
+
+#[cfg(feature = "add_business_days")]
+pub(super) fn business_date_range(
+    s: &[Column],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    step: u32,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_date_range(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        week_mask,
+        holidays,
+        step,
+    )
+    .map(Column::from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_week_mask_from_weekday_names() {
+        let week_mask =
+            week_mask_from_weekday_names(&["Mon", "tuesday", "WED", "Thu", "Friday"]).unwrap();
+        assert_eq!(week_mask, [true, true, true, true, true, false, false]);
+        assert_eq!(
+            week_mask_from_weekday_names(&["Sun"]).unwrap(),
+            [false, false, false, false, false, false, true]
+        );
+        assert!(week_mask_from_weekday_names(&["Mon", "Monday"]).is_err());
+        assert!(week_mask_from_weekday_names(&["Mo"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "business")]
+    fn test_reject_week_mask_without_business_days() {
+        let function = BusinessFunction::AddBusinessDay {
+            week_mask: [false; 7],
+            holidays: Holidays::default(),
+            roll: Roll::Forward,
+            unit: BusinessDayCountUnit::Days,
+            per_row_week_mask: false,
+        };
+        assert!(function.validate().is_err());
+        assert!(BusinessFunction::new_add_business_day(&[], vec![], Roll::Forward).is_err());
+        assert!(
+            BusinessFunction::new_business_day_count(
+                &[],
+                vec![],
+                ClosedInterval::Left,
+                BusinessDayCountUnit::Days,
+                None
+            )
+            .is_err()
+        );
+        assert!(BusinessFunction::new_is_business_day(&["Mon"], vec![]).is_ok());
+
+        // The week mask of a per-row count is a placeholder.
+        let function = BusinessFunction::BusinessDayCount {
+            week_mask: [false; 7],
+            holidays: Holidays::default(),
+            closed: ClosedInterval::Left,
+            unit: BusinessDayCountUnit::Days,
+            hours_per_day: None,
+            per_row_week_mask: true,
+            anchor: None,
+        };
+        assert!(function.validate().is_ok());
+
+        // Testing for weekends doesn't roll over business days.
+        let function = BusinessFunction::IsWeekend {
+            week_mask: [false; 7],
+        };
+        assert!(function.validate().is_ok());
+
+        // The error surfaces when the schema of the expression is resolved.
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Date)]);
+        let expr = crate::dsl::col("a")
+            .dt()
+            .is_business_day([false; 7], vec![]);
+        assert!(
+            expr.to_field(&schema, crate::prelude::Context::Default)
+                .is_err()
+        );
+        let expr = crate::dsl::col("a").dt().is_business_day([true; 7], vec![]);
+        assert!(
+            expr.to_field(&schema, crate::prelude::Context::Default)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "is_business_day")]
+    fn test_reject_too_many_holidays() {
+        let holidays = |end| Holidays {
+            singles: vec![],
+            ranges: vec![(0, end)],
+        };
+        let function = BusinessFunction::IsHoliday {
+            holidays: holidays(i32::MAX),
+        };
+        assert!(function.validate().is_err());
+        let function = BusinessFunction::IsHoliday {
+            holidays: holidays(MAX_HOLIDAY_DAYS as i32 - 1),
+        };
+        assert!(function.validate().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "business_day_count")]
+    fn test_business_day_count_scalar_bounds() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let count = |start: Column, end: Column| {
+            business_day_count(
+                &[start, end],
+                week_mask,
+                &[19731],
+                ClosedInterval::Left,
+                BusinessDayCountUnit::Days,
+                None,
+                false,
+                None,
+            )
+            .unwrap()
+        };
+        let scalar = |name: &str, date: Option<i32>, length| {
+            let value = match date {
+                Some(date) => AnyValue::Date(date),
+                None => AnyValue::Null,
+            };
+            Column::new_scalar(name.into(), Scalar::new(DataType::Date, value), length)
+        };
+        let dates = Int32Chunked::from_slice_options(
+            "dates".into(),
+            &[Some(19720), None, Some(19727), Some(19740)],
+        )
+        .into_date()
+        .into_column();
+
+        // A scalar bound gives the same result as the materialized bound.
+        for deadline in [Some(19734), None] {
+            let deadline = scalar("deadline", deadline, dates.len());
+            let materialized = deadline.as_materialized_series().clone().into_column();
+            let expected = count(dates.clone(), materialized.clone());
+            let out = count(dates.clone(), deadline.clone());
+            assert_eq!(
+                out.as_materialized_series(),
+                expected.as_materialized_series()
+            );
+            let expected = count(materialized, dates.clone());
+            let out = count(deadline, dates.clone());
+            assert_eq!(out.name(), "deadline");
+            assert_eq!(
+                out.as_materialized_series(),
+                expected.as_materialized_series()
+            );
+        }
+
+        // Two scalar bounds result in a scalar of the full length.
+        let out = count(
+            scalar("start", Some(19727), 3),
+            scalar("end", Some(19734), 3),
+        );
+        assert_eq!(out.len(), 3);
+        assert!(out.as_scalar_column().is_some());
+        assert_eq!(out.get(2).unwrap(), AnyValue::Int32(4));
+
+        // An anchor takes the place of the start.
+        let out = business_day_count(
+            std::slice::from_ref(&dates),
+            week_mask,
+            &[19731],
+            ClosedInterval::Left,
+            BusinessDayCountUnit::Days,
+            None,
+            false,
+            Some(BusinessDayAnchor::Date(19727)),
+        )
+        .unwrap();
+        let expected = count(scalar("start", Some(19727), dates.len()), dates.clone());
+        assert_eq!(out.name(), "dates");
+        assert_eq!(
+            out.as_materialized_series().i32().unwrap().to_vec(),
+            expected.as_materialized_series().i32().unwrap().to_vec()
+        );
+    }
+    #[test]
+    #[cfg(all(feature = "add_business_days", feature = "is_business_day"))]
+    fn test_holiday_columns() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let dates = |name: &str, dates: &[Option<i32>]| {
+            Int32Chunked::from_slice_options(name.into(), dates)
+                .into_date()
+                .into_column()
+        };
+        let to_vec = |column: Column| {
+            column
+                .as_materialized_series()
+                .to_physical_repr()
+                .i32()
+                .unwrap()
+                .to_vec()
+        };
+        // 2024-01-05 (Friday), with a holiday on 2024-01-08 (Monday) in the holiday column and
+        // on 2024-01-09 in the literal holidays.
+        let start = dates("start", &[Some(19727)]);
+        let n = Column::new("n".into(), [1i32]);
+        let holiday_column = dates("holidays", &[Some(19730), None]);
+
+        let add = |s: &[Column], holidays: &[i32], per_row_week_mask| {
+            let out = add_business_days(
+                s,
+                week_mask,
+                holidays,
+                Roll::Raise,
+                BusinessDayCountUnit::Days,
+                per_row_week_mask,
+            );
+            to_vec(out.unwrap())
+        };
+        let s = [start.clone(), n.clone(), holiday_column.clone()];
+        assert_eq!(add(&s, &[], false), [Some(19731)]);
+        assert_eq!(add(&s, &[19731], false), [Some(19732)]);
+        // The week masks of the rows come after the holiday column.
+        let week_masks = Column::new("week_masks".into(), [0b0011111i32]);
+        let s = [start, n.clone(), holiday_column.clone(), week_masks];
+        assert_eq!(add(&s, &[], true), [Some(19731)]);
+
+        let s = [dates("start", &[Some(19731)]), n, holiday_column.clone()];
+        let out = sub_business_days(&s, week_mask, &[], Roll::Raise).unwrap();
+        assert_eq!(to_vec(out), [Some(19727)]);
+
+        let s = [dates("dates", &[Some(19727), Some(19730)]), holiday_column];
+        let out = is_business_day(&s, week_mask, &[], false).unwrap();
+        assert_eq!(
+            out.bool().unwrap().into_iter().collect::<Vec<_>>(),
+            [Some(true), Some(false)]
+        );
+    }
+}
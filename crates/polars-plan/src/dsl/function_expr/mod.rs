@@ -9,7 +9,11 @@ mod binary;
 mod bitwise;
 mod boolean;
 mod bounds;
-#[cfg(feature = "business")]
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
 mod business;
 #[cfg(feature = "dtype-categorical")]
 pub mod cat;
@@ -97,8 +101,14 @@ pub use self::binary::BinaryFunction;
 #[cfg(feature = "bitwise")]
 pub use self::bitwise::BitwiseFunction;
 pub use self::boolean::BooleanFunction;
-#[cfg(feature = "business")]
-pub use self::business::BusinessFunction;
+#[cfg(feature = "business_day_count")]
+pub use self::business::BusinessDayAnchor;
+#[cfg(any(
+    feature = "business_day_count",
+    feature = "add_business_days",
+    feature = "is_business_day"
+))]
+pub use self::business::{BusinessFunction, week_mask_from_weekday_names};
 #[cfg(feature = "dtype-categorical")]
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
@@ -139,7 +149,11 @@ pub enum FunctionExpr {
 
     // Other expressions
     Boolean(BooleanFunction),
-    #[cfg(feature = "business")]
+    #[cfg(any(
+        feature = "business_day_count",
+        feature = "add_business_days",
+        feature = "is_business_day"
+    ))]
     Business(BusinessFunction),
     #[cfg(feature = "abs")]
     Abs,
@@ -390,7 +404,11 @@ impl Hash for FunctionExpr {
 
             // Other expressions
             Boolean(f) => f.hash(state),
-            #[cfg(feature = "business")]
+            #[cfg(any(
+                feature = "business_day_count",
+                feature = "add_business_days",
+                feature = "is_business_day"
+            ))]
             Business(f) => f.hash(state),
             Pow(f) => f.hash(state),
             #[cfg(feature = "index_of")]
@@ -628,7 +646,11 @@ impl Display for FunctionExpr {
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
-            #[cfg(feature = "business")]
+            #[cfg(any(
+                feature = "business_day_count",
+                feature = "add_business_days",
+                feature = "is_business_day"
+            ))]
             Business(func) => return write!(f, "{func}"),
             #[cfg(feature = "abs")]
             Abs => "abs",
@@ -900,7 +922,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
 
             // Other expressions
             Boolean(func) => func.into(),
-            #[cfg(feature = "business")]
+            #[cfg(any(
+                feature = "business_day_count",
+                feature = "add_business_days",
+                feature = "is_business_day"
+            ))]
             Business(func) => func.into(),
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
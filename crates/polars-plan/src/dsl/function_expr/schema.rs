@@ -31,11 +31,76 @@ impl FunctionExpr {
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
-            #[cfg(feature = "business")]
-            Business(func) => match func {
-                BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
-                BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
-                BusinessFunction::IsBusinessDay { .. } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(any(
+                feature = "business_day_count",
+                feature = "add_business_days",
+                feature = "is_business_day"
+            ))]
+            Business(func) => {
+                func.validate()?;
+                match func {
+                    #[cfg(feature = "business_day_count")]
+                    BusinessFunction::BusinessDayCount {
+                        unit,
+                        hours_per_day,
+                        ..
+                    } => match (unit, hours_per_day) {
+                        (BusinessDayCountUnit::Days, None) => mapper.with_dtype(DataType::Int32),
+                        _ => mapper.with_dtype(DataType::Float64),
+                    },
+                    #[cfg(feature = "business_day_count")]
+                    BusinessFunction::BusinessDayCountBreakdown { .. } => {
+                        mapper.with_dtype(DataType::Struct(
+                            ["calendar_days", "weekend_days", "holiday_days"]
+                                .into_iter()
+                                .map(|name| {
+                                    Field::new(PlSmallStr::from_static(name), DataType::Int32)
+                                })
+                                .collect(),
+                        ))
+                    },
+                    #[cfg(feature = "business_day_count")]
+                    BusinessFunction::BusinessDayCountByPeriod { .. } => mapper.with_dtype(
+                        polars_ops::prelude::business_day_count_by_period_dtype(),
+                    ),
+                    #[cfg(feature = "business_day_count")]
+                    BusinessFunction::BusinessDayCountFractional { .. }
+                    | BusinessFunction::BusinessHourCount { .. } => {
+                        mapper.with_dtype(DataType::Float64)
+                    },
+                    #[cfg(feature = "add_business_days")]
+                    BusinessFunction::AddBusinessDay { .. }
+                    | BusinessFunction::SubBusinessDay { .. } => mapper.with_same_dtype(),
+                    #[cfg(feature = "add_business_days")]
+                    BusinessFunction::NextBusinessDay { .. }
+                    | BusinessFunction::PreviousBusinessDay { .. }
+                    | BusinessFunction::BusinessMonthEnd { .. }
+                    | BusinessFunction::BusinessMonthBegin { .. } => mapper.with_same_dtype(),
+                    #[cfg(feature = "is_business_day")]
+                    BusinessFunction::IsBusinessDay { with_reason, .. } => {
+                        if *with_reason {
+                            mapper.with_dtype(DataType::String)
+                        } else {
+                            mapper.with_dtype(DataType::Boolean)
+                        }
+                    },
+                    #[cfg(feature = "is_business_day")]
+                    BusinessFunction::IsHoliday { .. } | BusinessFunction::IsWeekend { .. } => {
+                        mapper.with_dtype(DataType::Boolean)
+                    },
+                    #[cfg(feature = "is_business_day")]
+                    BusinessFunction::BusinessDaysUntilHoliday { .. } => {
+                        mapper.with_dtype(DataType::Int32)
+                    },
+                    #[cfg(feature = "is_business_day")]
+                    BusinessFunction::BusinessDayOfMonth { .. } => {
+                        mapper.with_dtype(DataType::Int32)
+                    },
+                    #[cfg(feature = "add_business_days")]
+                    BusinessFunction::BusinessDateRange { .. } => {
+                        mapper.with_dtype(DataType::List(Box::new(DataType::Date)))
+                    },
+                }
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -1,11 +1,38 @@
-use super::*;
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+use polars_time::Duration;
 
 #[cfg(feature = "dtype-date")]
+use super::*;
+
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
 pub fn business_day_count(
     start: Expr,
     end: Expr,
     week_mask: [bool; 7],
-    holidays: Vec<i32>,
+    holidays: impl Into<Holidays>,
+) -> Expr {
+    business_day_count_with_options(
+        start,
+        end,
+        week_mask,
+        holidays,
+        ClosedInterval::Left,
+        BusinessDayCountUnit::Days,
+        None,
+    )
+}
+
+/// Like [`business_day_count`], but with the `closed` side of the interval, the `unit` to
+/// count in, and for counting in hours, the `hours_per_day`.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_with_options(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
 ) -> Expr {
     let input = vec![start, end];
 
@@ -13,7 +40,291 @@ pub fn business_day_count(
         input,
         function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
             week_mask,
-            holidays,
+            holidays: holidays.into(),
+            closed,
+            unit,
+            hours_per_day,
+            per_row_week_mask: false,
+            anchor: None,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Like [`business_day_count_with_options`], but with the interval given as a single struct
+/// column with `start` and `end` fields.
+///
+/// Resolving the schema errors if `interval` isn't a struct with both fields.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_interval(
+    interval: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> Expr {
+    let start = interval.clone().struct_().field_by_name("start");
+    let end = interval.struct_().field_by_name("end");
+    business_day_count_with_options(start, end, week_mask, holidays, closed, unit, hours_per_day)
+}
+
+/// Like [`business_day_count_with_options`], but counting from a single `anchor` day, in days
+/// since the UNIX epoch, to every day of `end`.
+///
+/// Without an `anchor`, the count starts at today's date in the local time zone, determined
+/// when the expression is executed.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_from_anchor(
+    end: Expr,
+    anchor: Option<i32>,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> Expr {
+    let anchor = anchor.map_or(BusinessDayAnchor::Today, BusinessDayAnchor::Date);
+    let input = vec![end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
+            week_mask,
+            holidays: holidays.into(),
+            closed,
+            unit,
+            hours_per_day,
+            per_row_week_mask: false,
+            anchor: Some(anchor),
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Like [`business_day_count_with_options`], but with additional holidays taken from the
+/// `holidays` expression, which is evaluated at execution time.
+///
+/// Null holidays are ignored. In a group-by or window context the expression is evaluated per
+/// group, so each group only takes the holidays of its own rows into account. Adding,
+/// subtracting and testing for business days take holidays from an expression with the
+/// `*_with_holidays` methods of the `dt` namespace.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_with_holidays(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: Expr,
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> Expr {
+    let input = vec![start, end, holidays];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
+            week_mask,
+            holidays: Holidays::default(),
+            closed,
+            unit,
+            hours_per_day,
+            per_row_week_mask: false,
+            anchor: None,
+        }),
+        options: FunctionOptions {
+            // The holidays aren't aligned with the rows of `start` and `end`, so the function
+            // must see all of them at once.
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Like [`business_day_count_with_options`], but with a separate week mask for each row, taken
+/// from the `week_masks` expression.
+///
+/// `week_masks` must evaluate to boolean lists of length 7 starting on Monday, or to integer
+/// bitmasks where bit 0 is Monday and bit 6 is Sunday. A null week mask results in a null.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_with_week_masks(
+    start: Expr,
+    end: Expr,
+    week_masks: Expr,
+    holidays: impl Into<Holidays>,
+    closed: ClosedInterval,
+    unit: BusinessDayCountUnit,
+    hours_per_day: Option<f64>,
+) -> Expr {
+    let input = vec![start, end, week_masks];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
+            // Unused, the week masks are taken from `week_masks` instead.
+            week_mask: [true; 7],
+            holidays: holidays.into(),
+            closed,
+            unit,
+            hours_per_day,
+            per_row_week_mask: true,
+            anchor: None,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Count the calendar days, weekend days and holidays between `start` and `end` in one pass.
+///
+/// The result is a struct with `Int32` fields `calendar_days`, `weekend_days` and
+/// `holiday_days`. A null `start` or `end` results in a null struct.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_breakdown(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    closed: ClosedInterval,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCountBreakdown {
+            week_mask,
+            holidays: holidays.into(),
+            closed,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Count the business days between `start` and `end` per calendar `period`.
+///
+/// The result is a list with a struct for every period that overlaps `[start, end)`, with the
+/// fields `period_start` (`Date`) and `business_days` (`Int32`).
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_by_period(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    period: BusinessPeriod,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCountByPeriod {
+            week_mask,
+            holidays: holidays.into(),
+            period,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Count the business days between `start` and `end`, including the fractions of the partial
+/// days that `start` and `end` fall on, as `Float64`.
+///
+/// Partial days that are weekends or holidays count as zero.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_day_count_fractional(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDayCountFractional {
+            week_mask,
+            holidays: holidays.into(),
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Count the business hours between `start` and `end`, as `Float64`.
+///
+/// `day_start` and `day_end` are the times of day at which the business hours of every business
+/// day start and end, as durations since midnight without months. They must satisfy
+/// `0 <= day_start < day_end <= 24h`. Only the time between them counts, so a `start` or `end`
+/// outside of business hours is clamped to the nearest business hour, and weekends and holidays
+/// count as zero. If `end` is before `start`, the count is negative.
+#[cfg(all(feature = "business_day_count", feature = "dtype-date"))]
+pub fn business_hour_count(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    day_start: Duration,
+    day_end: Duration,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessHourCount {
+            week_mask,
+            holidays: holidays.into(),
+            start: day_start,
+            end: day_end,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Generate the business days between `start` and `end`, both inclusive, as a list per row.
+///
+/// `step` is the number of business days between consecutive dates and defaults to 1.
+#[cfg(all(feature = "add_business_days", feature = "dtype-date"))]
+pub fn business_date_range(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: impl Into<Holidays>,
+    step: Option<u32>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDateRange {
+            week_mask,
+            holidays: holidays.into(),
+            step: step.unwrap_or(1),
         }),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
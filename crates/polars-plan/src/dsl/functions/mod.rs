@@ -2,7 +2,7 @@
 //!
 //! Functions on expressions that might be useful.
 mod arity;
-#[cfg(feature = "business")]
+#[cfg(any(feature = "business_day_count", feature = "add_business_days"))]
 mod business;
 #[cfg(feature = "dtype-struct")]
 mod coerce;
@@ -21,7 +21,10 @@ mod syntactic_sugar;
 mod temporal;
 
 pub use arity::*;
-#[cfg(all(feature = "business", feature = "dtype-date"))]
+#[cfg(all(
+    any(feature = "business_day_count", feature = "add_business_days"),
+    feature = "dtype-date"
+))]
 pub use business::*;
 #[cfg(feature = "dtype-struct")]
 pub use coerce::*;
@@ -1043,9 +1043,30 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
             "raise" => Roll::Raise,
             "forward" => Roll::Forward,
             "backward" => Roll::Backward,
+            "nearest_forward" => Roll::Nearest {
+                prefer_forward: true,
+            },
+            "nearest_backward" => Roll::Nearest {
+                prefer_forward: false,
+            },
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`roll` must be one of {{'raise', 'forward', 'backward', 'nearest_forward', 'nearest_backward'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<BusinessDayCountUnit> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "days" => BusinessDayCountUnit::Days,
+            "weeks" => BusinessDayCountUnit::Weeks,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`roll` must be one of {{'raise', 'forward', 'backward'}}, got {v}",
+                    "`unit` must be one of {{'days', 'weeks'}}, got {v}",
                 )));
             },
         };
@@ -1053,6 +1074,39 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<BusinessPeriod> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "week" => BusinessPeriod::Week,
+            "month" => BusinessPeriod::Month,
+            "quarter" => BusinessPeriod::Quarter,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`period` must be one of {{'week', 'month', 'quarter'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<Holidays> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // Holidays are passed as days since the UNIX epoch, either single days or inclusive
+        // `(first, last)` ranges.
+        let mut holidays = Holidays::default();
+        for item in ob.try_iter()? {
+            let item = item?;
+            if let Ok(day) = item.extract::<i32>() {
+                holidays.singles.push(day);
+            } else {
+                holidays.ranges.push(item.extract::<(i32, i32)>()?);
+            }
+        }
+        Ok(Wrap(holidays))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -10,13 +10,84 @@ impl PyExpr {
         &self,
         n: PyExpr,
         week_mask: [bool; 7],
-        holidays: Vec<i32>,
+        holidays: Wrap<Holidays>,
         roll: Wrap<Roll>,
+        unit: Wrap<BusinessDayCountUnit>,
     ) -> Self {
         self.inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
+            .add_business_days_in_unit(n.inner, week_mask, holidays.0, roll.0, unit.0)
+            .into()
+    }
+
+    fn dt_add_business_days_with_week_masks(
+        &self,
+        n: PyExpr,
+        week_masks: PyExpr,
+        holidays: Wrap<Holidays>,
+        roll: Wrap<Roll>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .add_business_days_with_week_masks(n.inner, week_masks.inner, holidays.0, roll.0)
+            .into()
+    }
+
+    fn dt_sub_business_days(
+        &self,
+        n: PyExpr,
+        week_mask: [bool; 7],
+        holidays: Wrap<Holidays>,
+        roll: Wrap<Roll>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .sub_business_days(n.inner, week_mask, holidays.0, roll.0)
+            .into()
+    }
+
+    fn dt_next_business_day(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Wrap<Holidays>,
+        inclusive: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .next_business_day(week_mask, holidays.0, inclusive)
+            .into()
+    }
+
+    fn dt_previous_business_day(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Wrap<Holidays>,
+        inclusive: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .previous_business_day(week_mask, holidays.0, inclusive)
+            .into()
+    }
+
+    fn dt_business_month_end(&self, week_mask: [bool; 7], holidays: Wrap<Holidays>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .business_month_end(week_mask, holidays.0)
+            .into()
+    }
+
+    fn dt_business_month_begin(&self, week_mask: [bool; 7], holidays: Wrap<Holidays>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .business_month_begin(week_mask, holidays.0)
             .into()
     }
 
@@ -142,11 +213,42 @@ impl PyExpr {
     fn dt_year(&self) -> Self {
         self.inner.clone().dt().year().into()
     }
-    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Vec<i32>) -> Self {
+    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Wrap<Holidays>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .is_business_day(week_mask, holidays.0)
+            .into()
+    }
+    fn dt_is_holiday(&self, holidays: Wrap<Holidays>) -> Self {
+        self.inner.clone().dt().is_holiday(holidays.0).into()
+    }
+    fn dt_is_weekend(&self, week_mask: [bool; 7]) -> Self {
+        self.inner.clone().dt().is_weekend(week_mask).into()
+    }
+    fn dt_business_days_until_holiday(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Wrap<Holidays>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .business_days_until_holiday(week_mask, holidays.0)
+            .into()
+    }
+    fn dt_business_day_reason(&self, week_mask: [bool; 7], holidays: Wrap<Holidays>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .business_day_reason(week_mask, holidays.0)
+            .into()
+    }
+    fn dt_business_day_of_month(&self, week_mask: [bool; 7], holidays: Wrap<Holidays>) -> Self {
         self.inner
             .clone()
             .dt()
-            .is_business_day(week_mask, holidays)
+            .business_day_of_month(week_mask, holidays.0)
             .into()
     }
     fn dt_is_leap_year(&self) -> Self {
@@ -1,16 +1,146 @@
 use polars::lazy::dsl;
+use polars::prelude::{BusinessDayCountUnit, BusinessPeriod, Duration, Holidays};
+use polars_ops::series::ClosedInterval;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 
 #[pyfunction]
+#[pyo3(signature = (start, end, week_mask, holidays, closed, unit, hours_per_day=None))]
 pub fn business_day_count(
     start: PyExpr,
     end: PyExpr,
     week_mask: [bool; 7],
-    holidays: Vec<i32>,
+    holidays: Wrap<Holidays>,
+    closed: Wrap<ClosedInterval>,
+    unit: Wrap<BusinessDayCountUnit>,
+    hours_per_day: Option<f64>,
 ) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    dsl::business_day_count_with_options(
+        start,
+        end,
+        week_mask,
+        holidays.0,
+        closed.0,
+        unit.0,
+        hours_per_day,
+    )
+    .into()
+}
+
+#[pyfunction]
+#[pyo3(signature = (start, end, week_mask, holidays, closed, unit, hours_per_day=None))]
+pub fn business_day_count_with_holidays(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: PyExpr,
+    closed: Wrap<ClosedInterval>,
+    unit: Wrap<BusinessDayCountUnit>,
+    hours_per_day: Option<f64>,
+) -> PyExpr {
+    dsl::business_day_count_with_holidays(
+        start.inner,
+        end.inner,
+        week_mask,
+        holidays.inner,
+        closed.0,
+        unit.0,
+        hours_per_day,
+    )
+    .into()
+}
+
+#[pyfunction]
+#[pyo3(signature = (start, end, week_masks, holidays, closed, unit, hours_per_day=None))]
+pub fn business_day_count_with_week_masks(
+    start: PyExpr,
+    end: PyExpr,
+    week_masks: PyExpr,
+    holidays: Wrap<Holidays>,
+    closed: Wrap<ClosedInterval>,
+    unit: Wrap<BusinessDayCountUnit>,
+    hours_per_day: Option<f64>,
+) -> PyExpr {
+    dsl::business_day_count_with_week_masks(
+        start.inner,
+        end.inner,
+        week_masks.inner,
+        holidays.0,
+        closed.0,
+        unit.0,
+        hours_per_day,
+    )
+    .into()
+}
+
+#[pyfunction]
+pub fn business_day_count_breakdown(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: Wrap<Holidays>,
+    closed: Wrap<ClosedInterval>,
+) -> PyExpr {
+    dsl::business_day_count_breakdown(start.inner, end.inner, week_mask, holidays.0, closed.0)
+        .into()
+}
+
+#[pyfunction]
+pub fn business_day_count_by_period(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: Wrap<Holidays>,
+    period: Wrap<BusinessPeriod>,
+) -> PyExpr {
+    dsl::business_day_count_by_period(start.inner, end.inner, week_mask, holidays.0, period.0)
+        .into()
+}
+
+#[pyfunction]
+pub fn business_day_count_fractional(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: Wrap<Holidays>,
+) -> PyExpr {
+    dsl::business_day_count_fractional(start.inner, end.inner, week_mask, holidays.0).into()
+}
+
+#[pyfunction]
+pub fn business_hour_count(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: Wrap<Holidays>,
+    day_start: &str,
+    day_end: &str,
+) -> PyResult<PyExpr> {
+    let day_start = Duration::try_parse(day_start).map_err(PyPolarsErr::from)?;
+    let day_end = Duration::try_parse(day_end).map_err(PyPolarsErr::from)?;
+    Ok(dsl::business_hour_count(
+        start.inner,
+        end.inner,
+        week_mask,
+        holidays.0,
+        day_start,
+        day_end,
+    )
+    .into())
+}
+
+#[pyfunction]
+pub fn business_date_range(
+    start: PyExpr,
+    end: PyExpr,
+    week_mask: [bool; 7],
+    holidays: Wrap<Holidays>,
+    step: u32,
+) -> PyExpr {
+    dsl::business_date_range(start.inner, end.inner, week_mask, holidays.0, Some(step)).into()
 }
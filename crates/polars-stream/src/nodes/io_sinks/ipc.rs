@@ -83,6 +83,7 @@ impl SinkNode for IpcSinkNode {
 
         let options = WriteOptions {
             compression: self.write_options.compression.map(Into::into),
+            ..Default::default()
         };
 
         let chunk_size = self.write_options.chunk_size;
@@ -170,6 +171,8 @@ impl SinkNode for IpcSinkNode {
             let mut dictionary_tracker = DictionaryTracker {
                 dictionaries: Default::default(),
                 cannot_replace: false,
+                allow_delta: false,
+                replacements: 0,
             };
 
             struct CurrentColumn {
@@ -269,6 +272,7 @@ impl SinkNode for IpcSinkNode {
                         variadic_buffer_counts,
                         buffers,
                         nodes,
+                        None,
                         &mut encoded_data,
                     );
 
@@ -0,0 +1,431 @@
+use super::*;
+
+#[test]
+fn written_rows_and_batches() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    assert_eq!(writer.written_rows(), 0);
+    writer.write(&columns, None)?;
+    writer.write(&columns, None)?;
+    assert_eq!(writer.written_rows(), 6);
+    assert_eq!(writer.written_batches(), 2);
+    // Rows of encoded record batches are counted as well.
+    let (encoded_dictionaries, encoded_message) = writer.encode(&columns, None)?;
+    writer.write_encoded(&encoded_dictionaries, &encoded_message)?;
+    assert_eq!(writer.written_rows(), 9);
+    writer.finish()?;
+    assert_eq!(writer.written_rows(), 9);
+    assert_eq!(writer.written_batches(), 3);
+    Ok(())
+}
+
+#[test]
+fn write_min_record_batch_size() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    // Every chunk is 12 bytes, so two chunks are coalesced into one record batch.
+    let options = WriteOptions {
+        min_record_batch_size: Some(24),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    for _ in 0..5 {
+        writer.write(&columns, None)?;
+    }
+    assert_eq!(writer.written_batches(), 2);
+    writer.finish()?;
+    assert_eq!(writer.written_batches(), 3);
+    assert_eq!(writer.written_rows(), 15);
+
+    let batches = read_batches(writer.into_inner())?;
+    let lengths = batches.iter().map(|batch| batch.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![6, 6, 3]);
+    let expected = Int32Array::from_slice([1, 2, 3, 1, 2, 3]);
+    assert_eq!(batches[0].arrays()[0].as_ref(), &expected as &dyn Array);
+
+    // Without the option every chunk is its own record batch.
+    let data = write(&vec![columns; 5], &schema, None, None)?;
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.blocks.len(), 5);
+    Ok(())
+}
+
+#[test]
+fn write_max_body_length() -> PolarsResult<()> {
+    let array = Int64Array::from_vec((0..64).collect()).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(64, schema.clone(), vec![array])?;
+
+    let options = WriteOptions {
+        max_body_length: Some(128),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    assert!(
+        writer
+            .written_blocks()
+            .iter()
+            .all(|block| block.body_length <= 128)
+    );
+
+    let batches = read_batches(writer.into_inner())?;
+    assert_eq!(batches.len(), 4);
+    let arrays = batches
+        .iter()
+        .map(|batch| batch.arrays()[0].as_ref())
+        .collect::<Vec<_>>();
+    let array = arrow::compute::concatenate::concatenate(&arrays)?;
+    assert_eq!(array, columns.arrays()[0]);
+    Ok(())
+}
+
+#[test]
+fn write_max_body_length_single_row() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["a".repeat(256)]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(1, schema.clone(), vec![array])?;
+
+    let options = WriteOptions {
+        max_body_length: Some(128),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    assert!(writer.write(&columns, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_max_body_length_dictionary() -> PolarsResult<()> {
+    let values = Utf8Array::<i64>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let keys = PrimitiveArray::from_vec((0..64).map(|i| i % 2).collect());
+    let dictionary = DictionaryArray::try_new(dtype, keys, values.boxed())?.boxed();
+    let strings = |last: &str| {
+        let mut strings = vec!["x"; 63];
+        strings.push(last);
+        Utf8Array::<i32>::from_slice(strings).boxed()
+    };
+    let schema = Arc::new(ArrowSchema::from_iter([
+        Field::new(
+            PlSmallStr::from_static("a"),
+            dictionary.dtype().clone(),
+            true,
+        ),
+        Field::new(PlSmallStr::from_static("b"), ArrowDataType::Utf8, true),
+    ]));
+
+    let options = WriteOptions {
+        max_body_length: Some(256),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    let offset = writer.current_offset();
+
+    // The last row doesn't fit, so none of the record batches nor the dictionary is written.
+    let long = "y".repeat(256);
+    let columns =
+        RecordBatchT::try_new(64, schema.clone(), vec![dictionary.clone(), strings(&long)])?;
+    assert!(writer.write(&columns, None).is_err());
+    assert_eq!(writer.current_offset(), offset);
+    assert!(writer.written_blocks().is_empty());
+    assert!(writer.dictionary_tracker().dictionaries.is_empty());
+
+    // The record batches of a split chunk share a single dictionary batch.
+    let columns = RecordBatchT::try_new(64, schema.clone(), vec![dictionary, strings("x")])?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    assert!(writer.written_blocks().len() > 1);
+    assert!(
+        writer
+            .written_blocks()
+            .iter()
+            .all(|block| block.body_length <= 256)
+    );
+
+    let batches = read_batches(writer.into_inner())?;
+    let mut offset = 0;
+    for batch in &batches {
+        for (array, expected) in batch.arrays().iter().zip(columns.arrays()) {
+            assert_eq!(array, &expected.sliced(offset, batch.len()));
+        }
+        offset += batch.len();
+    }
+    assert_eq!(offset, columns.len());
+    Ok(())
+}
+
+#[test]
+fn write_mismatched_chunk() -> PolarsResult<()> {
+    let array = Int32Array::from_slice([1, 2, 3]).boxed();
+    let schema = prep_schema(array.as_ref());
+
+    let options = WriteOptions {
+        validate_schema: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    let offset = writer.current_offset();
+
+    // Wrong type.
+    let other = Int64Array::from_slice([1, 2, 3]).boxed();
+    let other_schema = prep_schema(other.as_ref());
+    let columns = RecordBatchT::try_new(3, other_schema, vec![other])?;
+    let err = writer.write(&columns, None).unwrap_err();
+    assert!(err.to_string().contains("column 0 ('a')"));
+
+    // Wrong number of columns.
+    let two_fields = Arc::new(ArrowSchema::from_iter([
+        Field::new(PlSmallStr::from_static("a"), ArrowDataType::Int32, true),
+        Field::new(PlSmallStr::from_static("b"), ArrowDataType::Int32, true),
+    ]));
+    let columns = RecordBatchT::try_new(3, two_fields, vec![array.clone(), array.clone()])?;
+    assert!(writer.write(&columns, None).is_err());
+
+    assert_eq!(writer.current_offset(), offset);
+    assert_eq!(writer.into_inner().len(), offset);
+
+    // Differences in nullability are allowed.
+    let non_nullable = Arc::new(ArrowSchema::from_iter([Field::new(
+        PlSmallStr::from_static("a"),
+        ArrowDataType::Int32,
+        false,
+    )]));
+    let columns = RecordBatchT::try_new(3, non_nullable, vec![array.clone()])?;
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+
+    // Without validation the chunk is written as is.
+    let options = WriteOptions {
+        validate_schema: false,
+        ..Default::default()
+    };
+    let other = Int64Array::from_slice([1, 2, 3]).boxed();
+    let columns = RecordBatchT::try_new(3, prep_schema(other.as_ref()), vec![other])?;
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&columns, None)?;
+    Ok(())
+}
+
+#[test]
+fn write_all_chunks() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let options = WriteOptions {
+        validate_schema: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    // Writing no chunks writes nothing.
+    let offset = writer.current_offset();
+    writer.write_all(&[], None)?;
+    assert_eq!(writer.current_offset(), offset);
+
+    writer.write_all(&[columns.clone(), columns.clone()], None)?;
+    assert_eq!(writer.written_batches(), 2);
+
+    // The index of the failing chunk is added to the error, and the chunks before it remain
+    // written.
+    let other = Int64Array::from_slice([1, 2, 3]).boxed();
+    let other = RecordBatchT::try_new(3, prep_schema(other.as_ref()), vec![other])?;
+    let err = writer
+        .write_all([&columns, &other, &columns], None)
+        .unwrap_err();
+    assert!(err.to_string().contains("failed to write chunk 1"));
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::SchemaMismatch(_))
+    ));
+    assert_eq!(writer.written_batches(), 3);
+    writer.finish()?;
+
+    let batches = read_batches(writer.into_inner())?;
+    assert_eq!(batches, vec![columns; 3]);
+    Ok(())
+}
+
+#[test]
+fn write_encoded_to_file_and_stream() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?;
+    let schema = prep_schema(&dictionary);
+    let columns = RecordBatchT::try_new(2, schema.clone(), vec![dictionary.clone().boxed()])?;
+    let assert_batches = |batches: Vec<RecordBatchT<Box<dyn Array>>>| {
+        assert_eq!(batches.len(), 2);
+        for batch in batches {
+            let read = batch.arrays()[0]
+                .as_any()
+                .downcast_ref::<DictionaryArray<i32>>()
+                .unwrap();
+            assert_eq!(read.keys(), dictionary.keys());
+            assert_eq!(read.values(), dictionary.values());
+        }
+    };
+
+    // Encode the chunks once and write them to both writers.
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+    let options = WriteOptions::default();
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: true,
+        allow_delta: false,
+        replacements: 0,
+    };
+    let mut file_writer =
+        FileWriter::try_new(vec![], schema.clone(), Some(ipc_fields.clone()), options)?;
+    let mut stream_writer = StreamWriter::new(vec![], options);
+    stream_writer.start(&schema, Some(ipc_fields.clone()))?;
+    for _ in 0..2 {
+        let (encoded_dictionaries, encoded_message) =
+            encode_chunk(&columns, &ipc_fields, &mut dictionary_tracker, &options)?;
+        file_writer.write_encoded(&encoded_dictionaries, &encoded_message)?;
+        stream_writer.write_encoded(&encoded_dictionaries, &encoded_message)?;
+    }
+    assert_eq!(file_writer.written_dictionary_blocks().len(), 1);
+    file_writer.finish()?;
+    stream_writer.finish()?;
+
+    let batches = read_batches(file_writer.into_inner())?;
+    assert_batches(batches);
+
+    let mut reader = Cursor::new(stream_writer.into_inner());
+    let metadata = read_stream_metadata(&mut reader)?;
+    let batches = StreamReader::new(reader, metadata, None)
+        .map(|state| state.map(StreamState::unwrap))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_batches(batches);
+    Ok(())
+}
+
+#[test]
+fn write_externally_encoded() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?;
+    let schema = prep_schema(&dictionary);
+    let columns = RecordBatchT::try_new(2, schema.clone(), vec![dictionary.clone().boxed()])?;
+
+    // The messages are written to `file` by the caller, only the header and footer by the
+    // writer.
+    let options = WriteOptions::default();
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    let mut file = std::mem::take(writer.get_mut());
+    for _ in 0..2 {
+        let (encoded_dictionaries, encoded_message) = writer.encode(&columns, None)?;
+        for encoded in encoded_dictionaries.iter().chain([&encoded_message]) {
+            write_message(&mut file, encoded, options.alignment)?;
+        }
+        let block = writer.record_encoded(&encoded_dictionaries, &encoded_message, 2)?;
+        assert_eq!(
+            (block.offset + block.meta_data_length as i64 + block.body_length) as usize,
+            file.len()
+        );
+    }
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    assert_eq!(writer.written_rows(), 4);
+    writer.finish()?;
+    file.extend(writer.into_inner());
+
+    let batches = read_batches(file)?;
+    assert_eq!(batches.len(), 2);
+    for batch in batches {
+        let read = batch.arrays()[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        assert_eq!(read.keys(), dictionary.keys());
+        assert_eq!(read.values(), dictionary.values());
+    }
+
+    // Bytes written by the caller can't be part of the checksum.
+    let options = WriteOptions {
+        write_checksum: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    let (encoded_dictionaries, encoded_message) = writer.encode(&columns, None)?;
+    assert!(
+        writer
+            .record_encoded(&encoded_dictionaries, &encoded_message, 2)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn write_projected() -> PolarsResult<()> {
+    let a = Int32Array::from_slice([1, 2]).boxed();
+    let b = Utf8Array::<i32>::from_slice(["x", "y"]).boxed();
+    let c = Int64Array::from_slice([3, 4]).boxed();
+    let field = |name: &'static str, array: &dyn Array| {
+        Field::new(PlSmallStr::from_static(name), array.dtype().clone(), true)
+    };
+    let full_schema = Arc::new(ArrowSchema::from_iter([
+        field("a", a.as_ref()),
+        field("b", b.as_ref()),
+        field("c", c.as_ref()),
+    ]));
+    let chunk = RecordBatchT::try_new(2, full_schema, vec![a, b.clone(), c.clone()])?;
+    let schema = Arc::new(ArrowSchema::from_iter([
+        field("c", c.as_ref()),
+        field("b", b.as_ref()),
+    ]));
+
+    let options = WriteOptions {
+        validate_schema: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write_projected(&chunk, &[2, 1], None)?;
+    assert!(writer.write_projected(&chunk, &[2, 3], None).is_err());
+    assert!(writer.write_projected(&chunk, &[2, 2], None).is_err());
+    assert!(writer.write_projected(&chunk, &[1, 2], None).is_err());
+    assert!(writer.write_projected(&chunk, &[2], None).is_err());
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let batches =
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![RecordBatchT::try_new(2, schema, vec![c, b])?]);
+    Ok(())
+}
+
+#[test]
+fn skip_empty_batches() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+    let empty = RecordBatchT::try_new(
+        0,
+        schema.clone(),
+        vec![Int32Array::new_empty(ArrowDataType::Int32).boxed()],
+    )?;
+
+    for skip_empty_batches in [false, true] {
+        let options = WriteOptions {
+            skip_empty_batches,
+            ..Default::default()
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        writer.write(&empty, None)?;
+        writer.write(&columns, None)?;
+        writer.write(&empty, None)?;
+        writer.finish()?;
+        let expected = if skip_empty_batches {
+            vec![columns.clone()]
+        } else {
+            vec![empty.clone(), columns.clone(), empty.clone()]
+        };
+        assert_eq!(writer.written_blocks().len(), expected.len());
+
+        let batches = read_batches(writer.into_inner())?;
+        assert_eq!(batches, expected);
+    }
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+#[test]
+fn write_compression_level() -> PolarsResult<()> {
+    let array = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(4, schema.clone(), vec![array])?;
+
+    let options = WriteOptions {
+        compression: Some(Compression::ZSTD),
+        compression_level: Some(19),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let level = metadata
+        .custom_schema_metadata
+        .as_ref()
+        .and_then(|md| md.get(IPC_COMPRESSION_LEVEL_KEY))
+        .map(|level| level.as_str());
+    assert_eq!(level, Some("19"));
+
+    let reader = FileReader::new(reader, metadata, None, None);
+    let batches = reader.collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![columns]);
+    Ok(())
+}
+
+#[test]
+fn write_effective_compression() -> PolarsResult<()> {
+    use arrow::io::ipc::format::ipc::{CompressionType, MessageHeaderRef};
+
+    let array = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(4, schema.clone(), vec![array])?;
+
+    for (compression, expected) in [
+        (None, None),
+        (Some(Compression::LZ4), Some(CompressionType::Lz4Frame)),
+        (Some(Compression::ZSTD), Some(CompressionType::Zstd)),
+    ] {
+        let options = WriteOptions {
+            compression,
+            ..Default::default()
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        assert_eq!(writer.effective_compression(), compression);
+        writer.write(&columns, None)?;
+        let block = &writer.written_blocks()[0];
+        // Skip the continuation marker and the message length.
+        let start = block.offset as usize + 8;
+        let end = block.offset as usize + block.meta_data_length as usize;
+        writer.finish()?;
+
+        // The codec is recorded in the written record batch message.
+        let data = writer.into_inner();
+        let message = MessageRef::read_as_root(&data[start..end]).unwrap();
+        let Some(MessageHeaderRef::RecordBatch(batch)) = message.header().unwrap() else {
+            panic!("expected a record batch message");
+        };
+        let codec = batch
+            .compression()
+            .unwrap()
+            .map(|compression| compression.codec().unwrap());
+        assert_eq!(codec, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_invalid_compression_level() -> PolarsResult<()> {
+    let array = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let schema = prep_schema(array.as_ref());
+
+    let options = WriteOptions {
+        compression: Some(Compression::LZ4),
+        compression_level: Some(100),
+        ..Default::default()
+    };
+    assert!(FileWriter::try_new(vec![], schema.clone(), None, options).is_err());
+
+    let options = WriteOptions {
+        compression: None,
+        compression_level: Some(1),
+        ..Default::default()
+    };
+    assert!(FileWriter::try_new(vec![], schema, None, options).is_err());
+    Ok(())
+}
+
+#[test]
+fn parallel_compression() -> PolarsResult<()> {
+    let schema = Arc::new(ArrowSchema::from_iter([
+        Field::new(PlSmallStr::from_static("a"), ArrowDataType::Int32, true),
+        Field::new(PlSmallStr::from_static("b"), ArrowDataType::Utf8View, true),
+        Field::new(PlSmallStr::from_static("c"), ArrowDataType::Float64, true),
+    ]));
+    let columns = RecordBatchT::try_new(
+        4,
+        schema.clone(),
+        vec![
+            Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+            Utf8ViewArray::from_slice([
+                Some("a"),
+                Some("a string longer than twelve bytes"),
+                None,
+                Some("c"),
+            ])
+            .boxed(),
+            Float64Array::from_slice([1.0, 2.0, 3.0, 4.0]).boxed(),
+        ],
+    )?;
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let sequential = WriteOptions {
+            compression: Some(compression),
+            ..Default::default()
+        };
+        let parallel = WriteOptions {
+            parallel_compression: true,
+            ..sequential
+        };
+
+        let mut expected = EncodedData::default();
+        encode_record_batch(&columns, &sequential, None, &mut expected);
+        let on_pool = WriteOptions {
+            compression_thread_pool: Some(CompressionThreadPool(&polars_core::POOL)),
+            ..parallel
+        };
+
+        for options in [&parallel, &on_pool] {
+            let mut encoded = EncodedData::default();
+            encode_record_batch(&columns, options, None, &mut encoded);
+            assert_eq!(encoded.ipc_message, expected.ipc_message);
+            assert_eq!(encoded.arrow_data, expected.arrow_data);
+        }
+
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, parallel)?;
+        writer.write(&columns, None)?;
+        writer.finish()?;
+        let batches = read_batches(writer.into_inner())?;
+        assert_eq!(batches, vec![columns.clone()]);
+    }
+    Ok(())
+}
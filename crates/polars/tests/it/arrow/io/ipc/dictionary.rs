@@ -0,0 +1,183 @@
+use super::*;
+
+#[test]
+fn write_dictionary_replacement() -> PolarsResult<()> {
+    let dictionary = |values: &[&str]| -> PolarsResult<Box<dyn Array>> {
+        let values = Utf8Array::<i32>::from_slice(values);
+        let dtype =
+            ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+        Ok(
+            DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?
+                .boxed(),
+        )
+    };
+    let first = dictionary(&["a", "b"])?;
+    let second = dictionary(&["c", "d"])?;
+    let schema = prep_schema(first.as_ref());
+    let first = RecordBatchT::try_new(2, schema.clone(), vec![first])?;
+    let second = RecordBatchT::try_new(2, schema.clone(), vec![second])?;
+
+    // By default replacing a dictionary is an error.
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, WriteOptions::default())?;
+    writer.write(&first, None)?;
+    assert!(writer.write(&second, None).is_err());
+
+    let options = WriteOptions {
+        allow_dictionary_replacement: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&first, None)?;
+    writer.write(&first, None)?;
+    assert_eq!(writer.dictionary_replacements(), 0);
+    writer.write(&second, None)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 2);
+    assert_eq!(writer.dictionary_replacements(), 1);
+    writer.finish()?;
+    Ok(())
+}
+
+#[test]
+fn write_delta_dictionary() -> PolarsResult<()> {
+    let dictionary = |keys: Vec<i32>, values: &[&str]| -> PolarsResult<Box<dyn Array>> {
+        let values = Utf8Array::<i32>::from_slice(values);
+        let dtype =
+            ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+        Ok(
+            DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(keys), values.boxed())?
+                .boxed(),
+        )
+    };
+    let first = dictionary(vec![1, 0], &["a", "b"])?;
+    let second = dictionary(vec![2, 0], &["a", "b", "c"])?;
+    let schema = prep_schema(first.as_ref());
+    let first = RecordBatchT::try_new(2, schema.clone(), vec![first])?;
+    let second = RecordBatchT::try_new(2, schema.clone(), vec![second])?;
+
+    let options = WriteOptions {
+        delta_dictionaries: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, None, options)?;
+    writer.write(&first, None)?;
+    writer.write(&second, None)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 2);
+    assert_eq!(writer.dictionary_replacements(), 0);
+    writer.finish()?;
+
+    // The delta is appended to the dictionary that was read before.
+    let mut reader = writer.into_inner();
+    reader.set_position(0);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches =
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+    let read = |i: usize| {
+        batches[i].arrays()[0]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap()
+            .clone()
+    };
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+    assert_eq!(read(0).keys(), &PrimitiveArray::from_vec(vec![1, 0]));
+    assert_eq!(read(0).values(), &values);
+    assert_eq!(read(1).keys(), &PrimitiveArray::from_vec(vec![2, 0]));
+    assert_eq!(read(1).values(), &values);
+    Ok(())
+}
+
+#[test]
+fn seed_dictionaries() -> PolarsResult<()> {
+    let dictionary = |keys: Vec<i32>, values: &[&str]| -> PolarsResult<Box<dyn Array>> {
+        let values = Utf8Array::<i32>::from_slice(values);
+        let dtype =
+            ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+        Ok(
+            DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(keys), values.boxed())?
+                .boxed(),
+        )
+    };
+    let first = dictionary(vec![1, 0], &["a", "b"])?;
+    let schema = prep_schema(first.as_ref());
+    let first = RecordBatchT::try_new(2, schema.clone(), vec![first])?;
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, Default::default())?;
+    writer.write(&first, None)?;
+    let dictionaries = writer.dictionary_tracker().dictionaries.clone();
+    writer.finish()?;
+
+    // The seeded dictionary is written first and isn't written again by a chunk using it.
+    let second = dictionary(vec![0, 0, 1], &["a", "b"])?;
+    let second = RecordBatchT::try_new(3, schema.clone(), vec![second])?;
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, Default::default())?;
+    writer.seed_dictionaries(&dictionaries)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    writer.write(&second, None)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    assert!(writer.seed_dictionaries(&dictionaries).is_err());
+    // Dictionaries can't be replaced, so a chunk with a different dictionary errors.
+    let other = dictionary(vec![0], &["c"])?;
+    let other = RecordBatchT::try_new(1, schema.clone(), vec![other])?;
+    assert!(writer.write(&other, None).is_err());
+    writer.finish()?;
+
+    let batches = read_batches(writer.into_inner())?;
+    let read = batches[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(read.keys(), &PrimitiveArray::from_vec(vec![0, 0, 1]));
+    assert_eq!(
+        read.values(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]).boxed()
+    );
+    Ok(())
+}
+
+#[test]
+fn write_dictionary_mode() -> PolarsResult<()> {
+    let array = Int32Array::from_slice([1, 2, 3]).boxed();
+    let schema = prep_schema(array.as_ref());
+
+    for (allow_dictionary_replacement, delta_dictionaries, mode) in [
+        (false, false, "none"),
+        (false, true, "delta"),
+        (true, false, "replace"),
+    ] {
+        let options = WriteOptions {
+            allow_dictionary_replacement,
+            delta_dictionaries,
+            write_dictionary_mode: true,
+            ..Default::default()
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        assert_eq!(writer.dictionary_tracker().dictionary_mode(), mode);
+        writer.finish()?;
+        let file = writer.into_inner();
+        let get_mode = |metadata: Option<&Metadata>| {
+            metadata
+                .and_then(|md| md.get(IPC_DICTIONARY_MODE_KEY))
+                .map(|mode| mode.to_string())
+        };
+
+        // The mode is written to the schema message that follows the header, as well as to the
+        // schema in the footer.
+        let metadata = read_stream_metadata(&mut Cursor::new(&file[8..]))?;
+        assert_eq!(
+            get_mode(metadata.custom_schema_metadata.as_ref()).as_deref(),
+            Some(mode)
+        );
+        let metadata = read_file_metadata(&mut Cursor::new(&file))?;
+        assert_eq!(
+            get_mode(metadata.custom_schema_metadata.as_deref()).as_deref(),
+            Some(mode)
+        );
+    }
+
+    // By default, no mode is written.
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.finish()?;
+    let metadata = read_file_metadata(&mut Cursor::new(writer.into_inner()))?;
+    assert!(metadata.custom_schema_metadata.is_none());
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use super::*;
+
+#[test]
+fn concat_files() -> PolarsResult<()> {
+    let first = prep_chunk(Int32Array::from_slice([1, 2, 3]).boxed())?;
+    let second = prep_chunk(Int32Array::from([Some(4), None]).boxed())?;
+    let mut inputs = [
+        Cursor::new(write(
+            &[first.clone()],
+            &Arc::new(first.schema().clone()),
+            None,
+            None,
+        )?),
+        Cursor::new(write(
+            &[second.clone(), second.clone()],
+            &Arc::new(second.schema().clone()),
+            None,
+            None,
+        )?),
+    ];
+    let mut result = vec![];
+    concat_ipc_files(&mut result, &mut inputs)?;
+    assert_eq!(read_batches(result)?, vec![first, second.clone(), second]);
+
+    // The dictionary ids of later files are remapped to those of the first file.
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary = |keys: Vec<i32>, values: &Utf8Array<i32>| {
+        DictionaryArray::try_new(
+            dtype.clone(),
+            PrimitiveArray::from_vec(keys),
+            values.clone().boxed(),
+        )
+        .map(|array| array.boxed())
+    };
+    let first = prep_chunk(dictionary(vec![1, 0], &values)?)?;
+    let second = prep_chunk(dictionary(vec![0, 0, 1], &values)?)?;
+    let ipc_fields = vec![IpcField {
+        fields: vec![IpcField {
+            fields: vec![],
+            dictionary_id: None,
+        }],
+        dictionary_id: Some(3),
+    }];
+    let mut inputs = [
+        Cursor::new(write(
+            &[first.clone()],
+            &Arc::new(first.schema().clone()),
+            None,
+            None,
+        )?),
+        Cursor::new(write(
+            &[second.clone()],
+            &Arc::new(second.schema().clone()),
+            Some(ipc_fields),
+            None,
+        )?),
+    ];
+    let mut result = vec![];
+    concat_ipc_files(&mut result, &mut inputs)?;
+    let keys = read_batches(result)?
+        .iter()
+        .map(|batch| {
+            let read = batch.arrays()[0]
+                .as_any()
+                .downcast_ref::<DictionaryArray<i32>>()
+                .unwrap();
+            assert_eq!(read.values(), &values.clone().boxed());
+            read.keys().clone()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        vec![
+            PrimitiveArray::from_vec(vec![1, 0]),
+            PrimitiveArray::from_vec(vec![0, 0, 1])
+        ]
+    );
+
+    // Different dictionaries can't be combined without re-encoding.
+    let other = prep_chunk(dictionary(vec![0], &Utf8Array::<i32>::from_slice(["c"]))?)?;
+    let mut inputs = [
+        Cursor::new(write(
+            &[first.clone()],
+            &Arc::new(first.schema().clone()),
+            None,
+            None,
+        )?),
+        Cursor::new(write(
+            &[other.clone()],
+            &Arc::new(other.schema().clone()),
+            None,
+            None,
+        )?),
+    ];
+    assert!(concat_ipc_files(vec![], &mut inputs).is_err());
+
+    // Neither can different schemas.
+    let other = prep_chunk(Int64Array::from_slice([1]).boxed())?;
+    let mut inputs = [
+        Cursor::new(write(
+            &[first.clone()],
+            &Arc::new(first.schema().clone()),
+            None,
+            None,
+        )?),
+        Cursor::new(write(
+            &[other.clone()],
+            &Arc::new(other.schema().clone()),
+            None,
+            None,
+        )?),
+    ];
+    assert!(concat_ipc_files(vec![], &mut inputs).is_err());
+    Ok(())
+}
+
+#[test]
+fn multi_table_file() -> PolarsResult<()> {
+    use arrow::io::ipc::read::read_multi_table_index;
+
+    let numbers = prep_chunk(Int32Array::from_slice([1, 2, 3]).boxed())?;
+    let names = prep_chunk(Utf8Array::<i32>::from_slice(["a", "b"]).boxed())?;
+
+    let mut writer = MultiTableWriter::new(vec![]);
+    for (name, batch) in [("numbers", &numbers), ("names", &names)] {
+        let schema = Arc::new(batch.schema().clone());
+        let mut table = writer.start_table(name.into(), schema, None, WriteOptions::default())?;
+        table.write(batch, None)?;
+        table.write(batch, None)?;
+        table.finish()?;
+    }
+    assert!(
+        writer
+            .start_table(
+                "names".into(),
+                Arc::new(names.schema().clone()),
+                None,
+                WriteOptions::default()
+            )
+            .is_err()
+    );
+    let data = writer.finish()?;
+
+    let mut reader = Cursor::new(data);
+    let index = read_multi_table_index(&mut reader)?;
+    assert_eq!(
+        index
+            .tables
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        ["numbers", "names"]
+    );
+    for (name, batch) in [("names", names), ("numbers", numbers)] {
+        let mut table = index.open_table(&mut reader, name)?;
+        let metadata = read_file_metadata(&mut table)?;
+        let batches =
+            FileReader::new(table, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(batches, vec![batch.clone(), batch]);
+    }
+    assert!(index.open_table(&mut reader, "missing").is_err());
+    Ok(())
+}
+
+#[test]
+fn partitioned_writer() -> PolarsResult<()> {
+    let numbers = prep_chunk(Int32Array::from_slice([1, 2, 3]).boxed())?;
+    let names = prep_chunk(Utf8Array::<i32>::from_slice(["a", "b"]).boxed())?;
+
+    let mut created = vec![];
+    let mut writer = PartitionedIpcWriter::new(
+        |key: &&str| {
+            created.push(key.to_string());
+            vec![]
+        },
+        WriteOptions {
+            validate_schema: true,
+            ..Default::default()
+        },
+    );
+    assert!(writer.is_empty());
+    writer.write("numbers", &numbers)?;
+    writer.write("names", &names)?;
+    writer.write("numbers", &numbers)?;
+    assert_eq!(writer.len(), 2);
+    assert_eq!(
+        writer.keys().copied().collect::<Vec<_>>(),
+        ["numbers", "names"]
+    );
+    assert_eq!(writer.get(&"numbers").unwrap().written_batches(), 2);
+    assert!(writer.get(&"missing").is_none());
+
+    // The schema of a partition is fixed by its first record batch.
+    assert!(writer.write("names", &numbers).is_err());
+
+    let files = writer.finish_all()?;
+    assert_eq!(created, ["numbers", "names"]);
+    for ((key, data), expected) in files.into_iter().zip([
+        ("numbers", vec![numbers.clone(), numbers]),
+        ("names", vec![names]),
+    ]) {
+        assert_eq!(key, expected.0);
+        let batches = read_batches(data)?;
+        assert_eq!(batches, expected.1);
+    }
+    Ok(())
+}
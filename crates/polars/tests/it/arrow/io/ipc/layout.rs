@@ -0,0 +1,512 @@
+use super::*;
+
+#[test]
+fn written_blocks_match_footer() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["aa", "bb", "cc", "dd"]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(4, schema.clone(), vec![array])?;
+
+    let options = WriteOptions {
+        compression: Some(Compression::ZSTD),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&columns, None)?;
+    assert_eq!(writer.written_blocks().len(), 1);
+    writer.write(&columns, None)?;
+    let blocks = writer.written_blocks().to_vec();
+    assert!(writer.written_dictionary_blocks().is_empty());
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(blocks, metadata.blocks);
+    assert_eq!(
+        blocks[1].offset,
+        blocks[0].offset + blocks[0].meta_data_length as i64 + blocks[0].body_length
+    );
+    Ok(())
+}
+
+#[test]
+fn write_encoded_record_batch_returns_block() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    let mut encoded_message = EncodedData::default();
+    encode_record_batch(
+        &columns,
+        &WriteOptions::default(),
+        None,
+        &mut encoded_message,
+    );
+
+    let offset = writer.current_offset();
+    let block = writer.write_encoded_record_batch(&encoded_message)?;
+    assert_eq!(block.offset, offset as i64);
+    assert_eq!(
+        writer.current_offset(),
+        offset + block.meta_data_length as usize + block.body_length as usize
+    );
+    assert_eq!(writer.written_blocks(), &[block]);
+    Ok(())
+}
+
+#[test]
+fn write_checksum() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let options = WriteOptions {
+        write_checksum: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let mut data = writer.into_inner();
+    assert!(
+        read_footer_metadata(&data)
+            .iter()
+            .any(|(key, _)| key == IPC_CHECKSUM_KEY)
+    );
+    verify_ipc_checksum(&mut Cursor::new(&data))?;
+
+    // Corrupting the body is detected.
+    data[100] ^= 1;
+    assert!(verify_ipc_checksum(&mut Cursor::new(&data)).is_err());
+
+    // Without the option no checksum is written.
+    let data = write(&[columns], &schema, None, None)?;
+    assert!(verify_ipc_checksum(&mut Cursor::new(&data)).is_err());
+    Ok(())
+}
+
+#[test]
+fn on_block() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?;
+    let schema = prep_schema(&dictionary);
+    let columns = RecordBatchT::try_new(2, schema.clone(), vec![dictionary.boxed()])?;
+
+    let blocks = Arc::new(std::sync::Mutex::new(vec![]));
+    let mut writer = FileWriter::new(vec![], schema, None, WriteOptions::default());
+    let on_block_blocks = blocks.clone();
+    writer.set_on_block(move |kind, block| on_block_blocks.lock().unwrap().push((kind, *block)));
+    writer.start()?;
+    writer.write(&columns, None)?;
+    writer.write(&columns, None)?;
+
+    let dictionary_blocks = writer.written_dictionary_blocks();
+    let record_blocks = writer.written_blocks();
+    assert_eq!(
+        *blocks.lock().unwrap(),
+        vec![
+            (BlockKind::Dictionary, dictionary_blocks[0]),
+            (BlockKind::Record, record_blocks[0]),
+            (BlockKind::Record, record_blocks[1]),
+        ]
+    );
+    writer.finish()?;
+    Ok(())
+}
+
+#[test]
+fn bytes_written() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["aa", "bb", "cc"]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+
+    let new_writer = || FileWriter::new(vec![], schema.clone(), None, WriteOptions::default());
+
+    let writer = new_writer();
+    assert_eq!(writer.bytes_written(), 0);
+
+    let mut writer = new_writer();
+    writer.start()?;
+    let bytes_written = writer.bytes_written();
+    assert_eq!(bytes_written, writer.into_inner().len() as u64);
+
+    let mut writer = new_writer();
+    writer.start()?;
+    let scratches = writer.get_scratches();
+    writer.set_scratches(scratches);
+    writer.write(&columns, None)?;
+    let bytes_written = writer.bytes_written();
+    assert_eq!(bytes_written, writer.into_inner().len() as u64);
+
+    let mut writer = new_writer();
+    writer.start()?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let bytes_written = writer.bytes_written();
+    assert_eq!(bytes_written, writer.into_inner().len() as u64);
+    Ok(())
+}
+
+#[test]
+fn estimate_size() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary = DictionaryArray::try_new(
+        dtype,
+        PrimitiveArray::from_vec(vec![1, 0, 1]),
+        values.boxed(),
+    )?;
+    let arrays = vec![
+        Int64Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8ViewArray::from_slice_values(["aa", "a long string that is not inlined", "cc"]).boxed(),
+        dictionary.boxed(),
+    ];
+    let schema = Arc::new(ArrowSchema::from_iter(
+        arrays.iter().zip(["a", "b", "c"]).map(|(array, name)| {
+            Field::new(PlSmallStr::from_static(name), array.dtype().clone(), true)
+        }),
+    ));
+    let columns = RecordBatchT::try_new(3, schema.clone(), arrays)?;
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+
+    for compression in [None, Some(Compression::LZ4), Some(Compression::ZSTD)] {
+        let options = WriteOptions {
+            compression,
+            ..Default::default()
+        };
+        let estimate = estimate_ipc_size(&columns, &ipc_fields, &options)?;
+
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        let offset = writer.current_offset();
+        writer.write(&columns, None)?;
+        let size = writer.current_offset() - offset;
+
+        if compression.is_none() {
+            assert_eq!(estimate, size);
+        } else {
+            assert!(estimate >= size);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn estimate_size_max_body_length() -> PolarsResult<()> {
+    let values = Utf8Array::<i64>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let keys = PrimitiveArray::from_vec((0..64).map(|i| i % 2).collect());
+    let arrays = vec![
+        DictionaryArray::try_new(dtype, keys, values.boxed())?.boxed(),
+        Int64Array::from_vec((0..64).collect()).boxed(),
+    ];
+    let schema = Arc::new(ArrowSchema::from_iter(arrays.iter().zip(["a", "b"]).map(
+        |(array, name)| Field::new(PlSmallStr::from_static(name), array.dtype().clone(), true),
+    )));
+    let columns = RecordBatchT::try_new(64, schema.clone(), arrays)?;
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+
+    // The chunk is split into several record batches, each with its own metadata.
+    let options = WriteOptions {
+        max_body_length: Some(256),
+        ..Default::default()
+    };
+    let estimate = estimate_ipc_size(&columns, &ipc_fields, &options)?;
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    let offset = writer.current_offset();
+    writer.write(&columns, None)?;
+    assert!(writer.written_blocks().len() > 1);
+    assert_eq!(estimate, writer.current_offset() - offset);
+
+    // How compressed chunks are split depends on how well they compress.
+    let options = WriteOptions {
+        compression: Some(Compression::LZ4),
+        ..options
+    };
+    assert!(estimate_ipc_size(&columns, &ipc_fields, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn estimate_size_compression_ratio() -> PolarsResult<()> {
+    let array = Int64Array::from_vec(vec![0; 4096]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(4096, schema.clone(), vec![array])?;
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+    let estimate = |options: &WriteOptions, compressed_size| {
+        estimate_ipc_size_with(&columns, &ipc_fields, options, compressed_size)
+    };
+
+    // Without compression the ratio doesn't apply.
+    let options = WriteOptions::default();
+    assert_eq!(
+        estimate(&options, CompressedSizeEstimate::Ratio(0.5))?,
+        estimate_ipc_size(&columns, &ipc_fields, &options)?
+    );
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let options = WriteOptions {
+            compression: Some(compression),
+            ..Default::default()
+        };
+        let worst_case = estimate(&options, CompressedSizeEstimate::WorstCase)?;
+        assert_eq!(
+            worst_case,
+            estimate_ipc_size(&columns, &ipc_fields, &options)?
+        );
+        // A ratio is capped by the worst case.
+        assert!(estimate(&options, CompressedSizeEstimate::Ratio(2.0))? <= worst_case);
+        let small = estimate(&options, CompressedSizeEstimate::Ratio(0.01))?;
+        assert!(small < worst_case);
+
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        let offset = writer.current_offset();
+        writer.write(&columns, None)?;
+        let size = writer.current_offset() - offset;
+        assert!(size <= worst_case);
+
+        for ratio in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(estimate(&options, CompressedSizeEstimate::Ratio(ratio)).is_err());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn write_alignment() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["aa", "bb", "cc"]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+
+    for alignment in [8, 64, 128] {
+        let options = WriteOptions {
+            alignment,
+            ..Default::default()
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        writer.write(&columns, None)?;
+        writer.write(&columns, None)?;
+        assert!(
+            writer
+                .written_blocks()
+                .iter()
+                .all(|block| block.offset % alignment as i64 == 0)
+        );
+        writer.finish()?;
+
+        let data = writer.into_inner();
+        let mut reader = Cursor::new(&data);
+        let metadata = read_file_metadata(&mut reader)?;
+        // The buffers within the bodies are aligned as well.
+        for block in &metadata.blocks {
+            let mut scratch = vec![];
+            reader.set_position(block.offset as u64 + 8);
+            let length = block.meta_data_length as usize - 8;
+            scratch.resize(length, 0);
+            std::io::Read::read_exact(&mut reader, &mut scratch)?;
+            let message = MessageRef::read_as_root(&scratch).unwrap();
+            let Some(arrow::io::ipc::format::ipc::MessageHeaderRef::RecordBatch(batch)) =
+                message.header().unwrap()
+            else {
+                panic!("expected a record batch");
+            };
+            for buffer in batch.buffers().unwrap().unwrap() {
+                assert_eq!(buffer.offset() % alignment as i64, 0);
+            }
+        }
+        let batches =
+            FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(batches, vec![columns.clone(), columns.clone()]);
+    }
+
+    for alignment in [1, 3, 4] {
+        let options = WriteOptions {
+            alignment,
+            ..Default::default()
+        };
+        assert!(FileWriter::try_new(vec![], schema.clone(), None, options).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn page_align_buffers() -> PolarsResult<()> {
+    use arrow::io::ipc::format::ipc::MessageHeaderRef;
+
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+
+    for compression in [None, Some(Compression::LZ4)] {
+        let options = WriteOptions {
+            compression,
+            page_align_buffers: true,
+            alignment: IPC_PAGE_SIZE,
+            ..Default::default()
+        };
+        let mut encoded = EncodedData::default();
+        encode_record_batch(&columns, &options, None, &mut encoded);
+        let message = MessageRef::read_as_root(&encoded.ipc_message).unwrap();
+        let Some(MessageHeaderRef::RecordBatch(batch)) = message.header().unwrap() else {
+            unreachable!()
+        };
+        let buffers = batch.buffers().unwrap().unwrap();
+        assert_eq!(buffers.len(), 3);
+        for buffer in buffers.iter() {
+            assert_eq!(buffer.offset() as usize % IPC_PAGE_SIZE, 0);
+        }
+        assert_eq!(message.body_length().unwrap() as usize, 3 * IPC_PAGE_SIZE);
+        assert_eq!(encoded.arrow_data.len(), 3 * IPC_PAGE_SIZE);
+        let estimate = estimate_ipc_size(
+            &columns,
+            &default_ipc_fields(schema.iter_values()),
+            &options,
+        )?;
+
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        let offset = writer.current_offset();
+        writer.write(&columns, None)?;
+        let size = writer.current_offset() - offset;
+        if compression.is_none() {
+            assert_eq!(estimate, size);
+        } else {
+            assert!(estimate >= size);
+        }
+        let block = writer.written_blocks()[0];
+        assert_eq!(
+            (block.offset + block.meta_data_length as i64) as usize % IPC_PAGE_SIZE,
+            0
+        );
+        writer.finish()?;
+        let batches = read_batches(writer.into_inner())?;
+        assert_eq!(batches, vec![columns.clone()]);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_footer_padding() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let options = WriteOptions {
+        footer_padding: Some(1 << 10),
+        write_checksum: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+    let footer_end = data.len() - 6;
+    let footer_len = i32::from_le_bytes(data[footer_end - 4..footer_end].try_into().unwrap());
+    assert_eq!(footer_len, 1 << 10);
+
+    let mut reader = Cursor::new(data);
+    verify_ipc_checksum(&mut reader)?;
+    reader.set_position(0);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches =
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![columns.clone()]);
+
+    let options = WriteOptions {
+        footer_padding: Some(16),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&columns, None)?;
+    assert!(writer.finish().is_err());
+    Ok(())
+}
+
+#[test]
+fn write_returns_file_layout() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let dictionary =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?;
+    let schema = prep_schema(&dictionary);
+    let columns = RecordBatchT::try_new(2, schema.clone(), vec![dictionary.boxed()])?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    writer.write(&columns, None)?;
+    let layout = writer.finish()?;
+    assert_eq!(layout.dictionary_blocks.len(), 1);
+    assert_eq!(layout.record_blocks.len(), 2);
+    assert_eq!(layout.record_blocks, writer.written_blocks());
+    let data = writer.into_inner();
+    assert_eq!(layout.file_length, data.len() as u64);
+
+    // The layout matches the footer.
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.blocks, layout.record_blocks);
+    assert_eq!(metadata.size, layout.file_length);
+    Ok(())
+}
+
+#[test]
+fn write_without_eos() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let write = |write_eos: bool, write_checksum: bool| -> PolarsResult<Vec<u8>> {
+        let options = WriteOptions {
+            write_eos,
+            write_checksum,
+            ..Default::default()
+        };
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        writer.write(&columns, None)?;
+        writer.finish()?;
+        Ok(writer.into_inner())
+    };
+    assert_eq!(write(true, false)?.len(), write(false, false)?.len() + 8);
+
+    for write_eos in [true, false] {
+        let data = write(write_eos, true)?;
+        verify_ipc_checksum(&mut Cursor::new(&data))?;
+        let batches = read_batches(data)?;
+        assert_eq!(batches, vec![columns.clone()]);
+    }
+
+    // With the marker, the body of the file after the 8 bytes of the leading magic reads as a
+    // stream that ends before the footer.
+    let data = write(true, false)?;
+    let mut reader = Cursor::new(&data[8..]);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let batches = StreamReader::new(reader, metadata, None)
+        .map(|state| state.map(StreamState::unwrap))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![columns.clone()]);
+    Ok(())
+}
+
+#[test]
+fn write_with_capacity_hint() -> PolarsResult<()> {
+    let array = Int64Array::from_slice([1, 2, 3]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+    let options = WriteOptions::default();
+    let estimate = estimate_ipc_size(
+        &columns,
+        &default_ipc_fields(schema.iter_values()),
+        &options,
+    )?;
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.with_capacity_hint(estimate);
+    // The reserved capacity is reused.
+    assert!(writer.with_capacity_hint(estimate));
+    let capacity = writer.get_mut().capacity();
+    writer.write(&columns, None)?;
+    assert_eq!(writer.get_mut().capacity(), capacity);
+
+    // Cursors reserve after their position.
+    let mut buffer = vec![0; 16];
+    let mut cursor = Cursor::new(&mut buffer);
+    cursor.set_position(8);
+    let mut writer = FileWriter::try_new(cursor, schema, None, options)?;
+    writer.with_capacity_hint(estimate);
+    let capacity = writer.get_mut().get_ref().capacity();
+    assert!(capacity >= writer.current_offset() + 8 + estimate);
+    writer.write(&columns, None)?;
+    assert_eq!(writer.get_mut().get_ref().capacity(), capacity);
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use super::*;
+
+#[test]
+fn write_field_metadata() -> PolarsResult<()> {
+    let metadata =
+        |pairs: &[(&'static str, &'static str)]| {
+            Metadata::from_iter(pairs.iter().map(|&(key, value)| {
+                (PlSmallStr::from_static(key), PlSmallStr::from_static(value))
+            }))
+        };
+    let numbers = Int32Array::from_slice([1, 2, 3]).boxed();
+    let item = Field::new(PlSmallStr::from_static("item"), ArrowDataType::Int32, true)
+        .with_metadata(metadata(&[("unit", "s")]));
+    let lists = ListArray::<i32>::try_new(
+        ArrowDataType::List(Box::new(item)),
+        vec![0, 1, 1, 3].try_into()?,
+        Int32Array::from_slice([4, 5, 6]).boxed(),
+        None,
+    )?
+    .boxed();
+    let schema = Arc::new(ArrowSchema::from_iter([
+        Field::new(PlSmallStr::from_static("a"), numbers.dtype().clone(), true).with_metadata(
+            metadata(&[("unit", "m"), ("description", "distance travelled")]),
+        ),
+        Field::new(PlSmallStr::from_static("b"), lists.dtype().clone(), true),
+    ]));
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![numbers, lists])?;
+    round_trip(columns.clone(), schema.clone(), None, None)?;
+
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&schema, None)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, *schema);
+    Ok(())
+}
+
+#[test]
+fn write_footer_metadata() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::new(vec![], schema.clone(), None, WriteOptions::default());
+    writer.set_custom_schema_metadata(Arc::new(Metadata::from_iter([(
+        PlSmallStr::from_static("key"),
+        PlSmallStr::from_static("value"),
+    )])));
+    writer.start()?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    assert_eq!(
+        read_footer_metadata(&data),
+        vec![("key".to_string(), "value".to_string())]
+    );
+
+    // Without metadata the footer has no custom metadata.
+    let data = write(&[columns], &schema, None, None)?;
+    assert!(read_footer_metadata(&data).is_empty());
+    Ok(())
+}
+
+#[test]
+fn write_custom_footer_metadata() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::new(vec![], schema, None, WriteOptions::default());
+    writer.set_custom_schema_metadata(Arc::new(Metadata::from_iter([(
+        PlSmallStr::from_static("schema_key"),
+        PlSmallStr::from_static("schema_value"),
+    )])));
+    writer.start()?;
+    writer.write(&columns, None)?;
+    // Footer metadata may be set after the batches have been written.
+    writer.set_custom_footer_metadata(Arc::new(Metadata::from_iter([(
+        PlSmallStr::from_static("footer_key"),
+        PlSmallStr::from_static("footer_value"),
+    )])));
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    assert_eq!(
+        read_footer_metadata(&data),
+        vec![("footer_key".to_string(), "footer_value".to_string())]
+    );
+    let metadata = read_file_metadata(&mut Cursor::new(data))?;
+    let schema_metadata = metadata.custom_schema_metadata.unwrap();
+    assert_eq!(
+        schema_metadata.get("schema_key").map(|v| v.as_str()),
+        Some("schema_value")
+    );
+    Ok(())
+}
+
+/// Reads the `custom_metadata` of the message at `block`.
+fn read_message_metadata(data: &[u8], block: &Block) -> Vec<(String, String)> {
+    // Skip the continuation marker and the message length.
+    let start = block.offset as usize + 8;
+    let end = block.offset as usize + block.meta_data_length as usize;
+    let message = MessageRef::read_as_root(&data[start..end]).unwrap();
+    message
+        .custom_metadata()
+        .unwrap()
+        .map(|kvs| {
+            kvs.iter()
+                .map(|kv| {
+                    let kv = kv.unwrap();
+                    (
+                        kv.key().unwrap().unwrap().to_string(),
+                        kv.value().unwrap().unwrap().to_string(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn write_record_batch_metadata() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    let metadata = Metadata::from_iter([(
+        PlSmallStr::from_static("sequence_id"),
+        PlSmallStr::from_static("0"),
+    )]);
+    writer.write_with_metadata(&columns, None, &metadata)?;
+    writer.write(&columns, None)?;
+    let blocks = writer.written_blocks().to_vec();
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    assert_eq!(
+        read_message_metadata(&data, &blocks[0]),
+        vec![("sequence_id".to_string(), "0".to_string())]
+    );
+    assert!(read_message_metadata(&data, &blocks[1]).is_empty());
+
+    let batches = read_batches(data)?;
+    assert_eq!(batches, vec![columns.clone(), columns]);
+    Ok(())
+}
+
+#[test]
+fn write_schema_evolution() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let schema_evolution = SchemaEvolution {
+        fingerprint: u64::MAX,
+        parent: Some(1),
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    writer.set_schema_evolution(schema_evolution);
+    let data = writer.finish_into_inner()?;
+    let metadata = read_file_metadata(&mut Cursor::new(data))?;
+    assert_eq!(metadata.schema_evolution()?, Some(schema_evolution));
+
+    let data = write(&[columns], &schema, None, None)?;
+    let metadata = read_file_metadata(&mut Cursor::new(data))?;
+    assert_eq!(metadata.schema_evolution()?, None);
+    Ok(())
+}
+
+#[test]
+fn write_statistics() -> PolarsResult<()> {
+    let schema = ArrowSchema::from_iter([
+        Field::new(PlSmallStr::from_static("a"), ArrowDataType::Int32, true),
+        Field::new(PlSmallStr::from_static("b"), ArrowDataType::Utf8, true),
+    ]);
+    let schema = Arc::new(schema);
+    let chunk = |a: Int32Array, b: Utf8Array<i32>| {
+        RecordBatchT::try_new(a.len(), schema.clone(), vec![a.boxed(), b.boxed()])
+    };
+
+    let options = WriteOptions {
+        collect_statistics: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(
+        &chunk(
+            Int32Array::from([Some(3), None, Some(1)]),
+            Utf8Array::from([Some("b"), Some("\"q\""), None]),
+        )?,
+        None,
+    )?;
+    writer.write(
+        &chunk(
+            Int32Array::from([Some(-2), Some(3)]),
+            Utf8Array::from([Some("b"), Some("c")]),
+        )?,
+        None,
+    )?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    assert_eq!(
+        read_footer_metadata(&data),
+        vec![(
+            IPC_STATISTICS_KEY.to_string(),
+            concat!(
+                r#"{"num_rows":5,"columns":["#,
+                r#"{"name":"a","null_count":1,"min":-2,"max":3,"distinct_count":3},"#,
+                r#"{"name":"b","null_count":1,"min":"\"q\"","max":"c","distinct_count":3}"#,
+                "]}"
+            )
+            .to_string()
+        )]
+    );
+    Ok(())
+}
+
+#[test]
+fn write_late_schema_metadata() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    // The row count is only known once all batches have been written.
+    writer.set_custom_schema_metadata(Arc::new(Metadata::from_iter([(
+        PlSmallStr::from_static("num_rows"),
+        PlSmallStr::from(writer.written_rows().to_string()),
+    )])));
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let schema_metadata = metadata.custom_schema_metadata.clone().unwrap();
+    assert_eq!(
+        schema_metadata.get("num_rows").map(|v| v.as_str()),
+        Some("3")
+    );
+    let batches =
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![columns]);
+    Ok(())
+}
@@ -2,14 +2,27 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow::array::*;
-use arrow::datatypes::{ArrowSchema, ArrowSchemaRef, Field};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, Metadata};
 use arrow::io::ipc::IpcField;
-use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use arrow::io::ipc::format::ipc::planus::ReadAsRoot;
+use arrow::io::ipc::format::ipc::{Block, FooterRef, MessageRef};
+use arrow::io::ipc::read::{
+    FileReader, StreamReader, StreamState, read_file_metadata, read_stream_metadata,
+    verify_ipc_checksum,
+};
 use arrow::io::ipc::write::*;
 use arrow::record_batch::RecordBatchT;
 use polars::prelude::PlSmallStr;
 use polars_error::*;
 
+mod batches;
+mod compression;
+mod dictionary;
+mod files;
+mod layout;
+mod metadata;
+mod writer;
+
 pub(crate) fn write(
     batches: &[RecordBatchT<Box<dyn Array>>],
     schema: &ArrowSchemaRef,
@@ -17,7 +30,10 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> PolarsResult<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        ..Default::default()
+    };
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -58,6 +74,51 @@ fn prep_schema(array: &dyn Array) -> ArrowSchemaRef {
     )]))
 }
 
+/// A chunk of a single column `a` with the values of `array`.
+fn prep_chunk(array: Box<dyn Array>) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
+    let schema = prep_schema(array.as_ref());
+    RecordBatchT::try_new(array.len(), schema, vec![array])
+}
+
+/// A chunk of a single `Int32` column with the values 1, 2 and 3, and its schema.
+fn int32_chunk() -> PolarsResult<(ArrowSchemaRef, RecordBatchT<Box<dyn Array>>)> {
+    let array = Int32Array::from_slice([1, 2, 3]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+    Ok((schema, columns))
+}
+
+/// Reads all record batches of an IPC file.
+fn read_batches<T: AsRef<[u8]>>(data: T) -> PolarsResult<Vec<RecordBatchT<Box<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    FileReader::new(reader, metadata, None, None).collect()
+}
+
+/// Reads the `custom_metadata` of the footer of an IPC file.
+fn read_footer_metadata(data: &[u8]) -> Vec<(String, String)> {
+    // The file ends in the footer, its length (i32) and the magic (6 bytes).
+    let footer_end = data.len() - 10;
+    let footer_len = i32::from_le_bytes(data[footer_end..footer_end + 4].try_into().unwrap());
+    let footer_data = &data[footer_end - footer_len as usize..footer_end];
+    let footer = FooterRef::read_as_root(footer_data).unwrap();
+    footer
+        .custom_metadata()
+        .unwrap()
+        .map(|kvs| {
+            kvs.iter()
+                .map(|kv| {
+                    let kv = kv.unwrap();
+                    (
+                        kv.key().unwrap().unwrap().to_string(),
+                        kv.value().unwrap().unwrap().to_string(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[test]
 fn write_boolean() -> PolarsResult<()> {
     let array = BooleanArray::from([Some(true), Some(false), None, Some(true)]).boxed();
@@ -83,3 +144,82 @@ fn write_binview() -> PolarsResult<()> {
     let columns = RecordBatchT::try_new(array.len(), schema.clone(), vec![array])?;
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
+
+#[test]
+fn write_decimal256() -> PolarsResult<()> {
+    use arrow::types::i256;
+
+    let array = PrimitiveArray::<i256>::from([
+        Some(i256::from_words(0, 12345)),
+        None,
+        Some(i256::from_words(-1, -12345)),
+        Some(i256::from_words(i128::MAX, i128::MIN)),
+    ])
+    .to(ArrowDataType::Decimal256(76, 2));
+    let schema = prep_schema(&array);
+    let columns = RecordBatchT::try_new(4, schema.clone(), vec![array.clone().boxed()])?;
+
+    for compression in [None, Some(Compression::LZ4), Some(Compression::ZSTD)] {
+        let data = write(&[columns.clone()], &schema, None, compression)?;
+        let mut reader = Cursor::new(data);
+        let metadata = read_file_metadata(&mut reader)?;
+        assert_eq!(metadata.schema, schema);
+        let batches =
+            FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(batches.len(), 1);
+        // Array equality doesn't support 256-bit integers, so the arrays are compared by parts.
+        let read = batches[0].arrays()[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i256>>()
+            .unwrap();
+        assert_eq!(read.dtype(), array.dtype());
+        assert_eq!(read.values(), array.values());
+        assert_eq!(read.validity(), array.validity());
+    }
+    Ok(())
+}
+
+#[test]
+fn read_run_end_encoded_schema() {
+    use arrow::io::ipc::format::ipc;
+    use arrow::io::ipc::read::deserialize_schema;
+
+    let field = |name: &str, type_| ipc::Field {
+        name: Some(name.to_string()),
+        nullable: true,
+        type_: Some(type_),
+        dictionary: None,
+        children: Some(vec![]),
+        custom_metadata: None,
+    };
+    let run_ends = field(
+        "run_ends",
+        ipc::Type::Int(Box::new(ipc::Int {
+            bit_width: 32,
+            is_signed: true,
+        })),
+    );
+    let values = field("values", ipc::Type::Utf8(Box::new(ipc::Utf8 {})));
+    let mut ree = field(
+        "a",
+        ipc::Type::RunEndEncoded(Box::new(ipc::RunEndEncoded {})),
+    );
+    ree.children = Some(vec![run_ends, values]);
+    let message = ipc::Message {
+        version: ipc::MetadataVersion::V5,
+        header: Some(ipc::MessageHeader::Schema(Box::new(ipc::Schema {
+            endianness: ipc::Endianness::Little,
+            fields: Some(vec![ree]),
+            custom_metadata: None,
+            features: None,
+        }))),
+        body_length: 0,
+        custom_metadata: None,
+    };
+    let mut builder = ipc::planus::Builder::new();
+    let data = builder.finish(&message, None);
+
+    // Run-end encoded arrays aren't supported, which is reported instead of panicking.
+    let err = deserialize_schema(data).unwrap_err();
+    assert!(err.to_string().contains("run-end encoded"));
+}
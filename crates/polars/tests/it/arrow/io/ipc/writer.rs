@@ -0,0 +1,440 @@
+use super::*;
+
+#[test]
+fn append_to_existing_file() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let data = write(&[columns.clone()], &schema, None, None)?;
+
+    let mut writer =
+        FileWriter::try_from_existing(Cursor::new(data), schema.as_ref(), Default::default())?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+
+    let mut reader = writer.into_inner();
+    reader.set_position(0);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches =
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(batches, vec![columns.clone(), columns]);
+    Ok(())
+}
+
+#[test]
+fn append_to_existing_file_schema_mismatch() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+    let data = write(&[columns], &schema, None, None)?;
+
+    let other_schema = prep_schema(&Utf8Array::<i32>::from_slice(["a"]));
+    let result =
+        FileWriter::try_from_existing(Cursor::new(data), other_schema.as_ref(), Default::default());
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn append_to_existing_file_statistics() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+    let options = WriteOptions {
+        collect_statistics: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+    assert_eq!(read_footer_metadata(&data).len(), 1);
+
+    // The statistics of the existing record batches can't be extended.
+    let result = FileWriter::try_from_existing(Cursor::new(data.clone()), schema.as_ref(), options);
+    assert!(result.is_err());
+
+    // Without collecting statistics, the outdated statistics are dropped with the old footer.
+    let mut writer =
+        FileWriter::try_from_existing(Cursor::new(data), schema.as_ref(), Default::default())?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let data = writer.into_inner().into_inner();
+    assert!(read_footer_metadata(&data).is_empty());
+    assert_eq!(read_batches(data)?, vec![columns.clone(), columns]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_async() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["aa", "bb", "cc"]).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(3, schema.clone(), vec![array])?;
+
+    let mut writer =
+        AsyncFileWriter::try_new(vec![], schema.clone(), None, Default::default()).await?;
+    writer.write(&columns, None).await?;
+    writer.write(&columns, None).await?;
+    writer.finish().await?;
+    assert!(writer.finish().await.is_err());
+    let data = writer.into_inner();
+
+    let expected = write(&[columns.clone(), columns], &schema, None, None)?;
+    assert_eq!(data, expected);
+    Ok(())
+}
+
+#[test]
+fn abort_file() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    let blocks = writer.written_blocks().to_vec();
+    let data = writer.abort()?;
+
+    // Nothing is written after the last record batch, so there is no footer.
+    let last = blocks.last().unwrap();
+    assert_eq!(
+        data.len() as i64,
+        last.offset + last.meta_data_length as i64 + last.body_length
+    );
+    assert!(read_file_metadata(&mut Cursor::new(data)).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_error_kinds() -> PolarsResult<()> {
+    let array = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(array.dtype().clone()), false);
+    let first = DictionaryArray::try_new(
+        dtype.clone(),
+        PrimitiveArray::from_vec(vec![1, 0]),
+        array.boxed(),
+    )?
+    .boxed();
+    let second = DictionaryArray::try_new(
+        dtype,
+        PrimitiveArray::from_vec(vec![1, 0]),
+        Utf8Array::<i32>::from_slice(["c", "d"]).boxed(),
+    )?
+    .boxed();
+    let schema = prep_schema(first.as_ref());
+    let first = RecordBatchT::try_new(2, schema.clone(), vec![first])?;
+    let second = RecordBatchT::try_new(2, schema.clone(), vec![second])?;
+
+    let mut writer = FileWriter::new(vec![], schema.clone(), None, WriteOptions::default());
+    let err = writer.write(&first, None).unwrap_err();
+    assert!(matches!(err, PolarsError::ComputeError(_)));
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::NotStarted { operation }) if operation == "write"
+    ));
+    assert!(err.to_string().contains("must be started"));
+
+    writer.start()?;
+    let err = writer.start().unwrap_err();
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::AlreadyStarted)
+    ));
+
+    writer.write(&first, None)?;
+    let err = writer.write(&second, None).unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::DictionaryReplacementForbidden { dict_id: 0 })
+    ));
+
+    let other = Int64Array::from_slice([1, 2]).boxed();
+    let other_schema = prep_schema(other.as_ref());
+    let columns = RecordBatchT::try_new(2, other_schema, vec![other])?;
+    let err = writer.write(&columns, None).unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::SchemaMismatch(msg)) if msg.contains("column 0 ('a')")
+    ));
+
+    writer.finish()?;
+    let err = writer.finish().unwrap_err();
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::AlreadyFinished { operation }) if operation == "finish"
+    ));
+
+    // Errors unrelated to IPC writing are not matched.
+    let err = polars_err!(ComputeError: "other");
+    assert!(IpcWriteError::from_polars_error(&err).is_none());
+    let err = polars_err!(oos = "other");
+    assert!(IpcWriteError::from_polars_error(&err).is_none());
+
+    // Failures of the inner writer keep their kind.
+    struct BrokenPipe;
+    impl std::io::Write for BrokenPipe {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let err = FileWriter::try_new(BrokenPipe, schema, None, WriteOptions::default())
+        .err()
+        .unwrap();
+    assert!(matches!(
+        &err,
+        PolarsError::IO { error, .. } if error.kind() == std::io::ErrorKind::BrokenPipe
+    ));
+    assert!(matches!(
+        IpcWriteError::from_polars_error(&err),
+        Some(IpcWriteError::Io(err)) if err.kind() == std::io::ErrorKind::BrokenPipe
+    ));
+    Ok(())
+}
+
+#[test]
+fn finish_into_inner() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+    let expected = write(&[columns.clone()], &schema, None, None)?;
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    assert_eq!(writer.finish_into_inner()?, expected);
+
+    // An already finished writer is not finished again.
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    assert_eq!(writer.finish_into_inner()?, expected);
+    Ok(())
+}
+
+#[test]
+fn flush_file() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let options = WriteOptions {
+        min_record_batch_size: Some(1 << 20),
+        ..Default::default()
+    };
+    let mut writer = FileWriter::new(vec![], schema, None, options);
+    // Flushing before the file is started writes nothing.
+    writer.flush()?;
+    assert!(writer.get_mut().is_empty());
+    writer.start()?;
+    writer.write(&columns, None)?;
+    assert_eq!(writer.written_batches(), 0);
+    // Flushing writes the staged chunks and keeps the file open.
+    writer.flush()?;
+    assert_eq!(writer.written_batches(), 1);
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    assert!(writer.flush().is_err());
+
+    let batches = read_batches(writer.into_inner())?;
+    assert_eq!(batches, vec![columns.clone(), columns]);
+    Ok(())
+}
+
+#[test]
+fn write_options_builder() -> PolarsResult<()> {
+    assert_eq!(WriteOptions::builder().build(), WriteOptions::default());
+
+    let options = WriteOptionsBuilder::new()
+        .compression(Some(Compression::ZSTD))
+        .compression_level(Some(3))
+        .max_body_length(Some(1 << 10))
+        .build();
+    assert_eq!(
+        options,
+        WriteOptions {
+            compression: Some(Compression::ZSTD),
+            compression_level: Some(3),
+            max_body_length: Some(1 << 10),
+            ..Default::default()
+        }
+    );
+
+    let (schema, columns) = int32_chunk()?;
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let batches = read_batches(writer.into_inner())?;
+    assert_eq!(batches, vec![columns]);
+    Ok(())
+}
+
+#[test]
+fn write_buffered() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+
+    let mut writer =
+        FileWriter::try_new_buffered_with_capacity(16, vec![], schema, None, Default::default())?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+    let batches = read_batches(writer.into_inner_unbuffered()?)?;
+    assert_eq!(batches, vec![columns]);
+    Ok(())
+}
+
+#[test]
+fn checkpoint_file() -> PolarsResult<()> {
+    let (schema, columns) = int32_chunk()?;
+    let read = |data: &[u8]| {
+        verify_ipc_checksum(&mut Cursor::new(data))?;
+        let mut reader = Cursor::new(data);
+        let metadata = read_file_metadata(&mut reader)?;
+        FileReader::new(reader, metadata, None, None).collect::<PolarsResult<Vec<_>>>()
+    };
+
+    let options = WriteOptions {
+        write_checksum: true,
+        ..Default::default()
+    };
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, None, options)?;
+    writer.write(&columns, None)?;
+    writer.checkpoint()?;
+    assert_eq!(read(writer.get_mut().get_ref())?, vec![columns.clone()]);
+
+    writer.write(&columns, None)?;
+    // A large footer, which the final footer is smaller than.
+    writer.set_custom_footer_metadata(Arc::new(Metadata::from_iter([(
+        PlSmallStr::from_static("padding"),
+        PlSmallStr::from("x".repeat(256)),
+    )])));
+    writer.checkpoint()?;
+    assert_eq!(
+        read(writer.get_mut().get_ref())?,
+        vec![columns.clone(), columns.clone()]
+    );
+
+    writer.set_custom_footer_metadata(Arc::new(Metadata::default()));
+    writer.finish()?;
+    let data = writer.into_inner().into_inner();
+    assert_eq!(read(&data)?, vec![columns.clone(), columns]);
+    Ok(())
+}
+
+#[test]
+fn checkpoint_interrupted_write() -> PolarsResult<()> {
+    use std::io::{Seek, SeekFrom, Write};
+
+    /// A file whose writing process crashes once `budget` more bytes have been written.
+    struct Crashing {
+        file: Cursor<Vec<u8>>,
+        budget: usize,
+    }
+
+    impl Write for Crashing {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.budget == 0 {
+                return Err(std::io::ErrorKind::Other.into());
+            }
+            let written = self.file.write(&buf[..buf.len().min(self.budget)])?;
+            self.budget -= written;
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Seek for Crashing {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.file.seek(pos)
+        }
+    }
+
+    let columns = prep_chunk(Int32Array::from_vec((0..1024).collect()).boxed())?;
+    let schema = Arc::new(columns.schema().clone());
+    let file = Crashing {
+        file: Cursor::new(vec![]),
+        budget: usize::MAX,
+    };
+    let mut writer = FileWriter::try_new(file, schema, None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    writer.checkpoint()?;
+    let checkpoint = writer.get_mut().file.get_ref().clone();
+    assert_eq!(read_batches(&checkpoint)?, vec![columns.clone()]);
+
+    // The process crashes halfway through the next record batch, which is written over the
+    // footer of the checkpoint, so the first record batch can't be read anymore either.
+    let offset = writer.current_offset();
+    writer.get_mut().budget = 2048;
+    assert!(writer.write(&columns, None).is_err());
+    let data = writer.get_mut().file.get_ref();
+    assert_eq!(data.len(), offset + 2048);
+    assert!(read_batches(data).is_err());
+    Ok(())
+}
+
+#[test]
+fn writer_schema_and_ipc_fields() -> PolarsResult<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let array =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?;
+    let schema = prep_schema(&array);
+
+    let writer = FileWriter::try_new(vec![], schema.clone(), None, WriteOptions::default())?;
+    assert_eq!(writer.schema(), &schema);
+    // The IPC fields are derived from the schema if not given.
+    assert_eq!(
+        writer.ipc_fields(),
+        default_ipc_fields(schema.iter_values()).as_slice()
+    );
+    assert_eq!(writer.ipc_fields()[0].dictionary_id, Some(0));
+
+    let ipc_fields = vec![IpcField {
+        fields: vec![],
+        dictionary_id: Some(5),
+    }];
+    let writer = FileWriter::try_new(
+        vec![],
+        schema,
+        Some(ipc_fields.clone()),
+        WriteOptions::default(),
+    )?;
+    assert_eq!(writer.ipc_fields(), ipc_fields.as_slice());
+    Ok(())
+}
+
+#[test]
+fn reset_file_writer() -> PolarsResult<()> {
+    // Comparing dictionaries of `Utf8Array<i32>` values isn't implemented.
+    let values = Utf8Array::<i64>::from_slice(["a", "b"]);
+    let dtype = ArrowDataType::Dictionary(i32::KEY_TYPE, Box::new(values.dtype().clone()), false);
+    let array =
+        DictionaryArray::try_new(dtype, PrimitiveArray::from_vec(vec![1, 0]), values.boxed())?
+            .boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(2, schema.clone(), vec![array])?;
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, WriteOptions::default())?;
+    writer.write(&columns, None)?;
+    writer.finish()?;
+
+    // Without the dictionaries, the second file writes them again with its first batch.
+    let first = writer.reset(vec![], false);
+    writer.start()?;
+    assert!(writer.written_dictionary_blocks().is_empty());
+    writer.write(&columns, None)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    writer.finish()?;
+    assert_eq!(writer.written_batches(), 2);
+    let second = writer.reset(vec![], true);
+
+    // With the dictionaries, they are seeded and the record batch reuses them.
+    writer.start()?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    writer.write(&columns, None)?;
+    assert_eq!(writer.written_dictionary_blocks().len(), 1);
+    writer.finish()?;
+    let third = writer.into_inner();
+
+    assert_eq!(first, second);
+    for file in [first, second, third] {
+        let batches = read_batches(file)?;
+        assert_eq!(batches, vec![columns.clone()]);
+    }
+    Ok(())
+}
@@ -137,6 +137,31 @@ fn test_write_with_compression() {
     }
 }
 
+#[test]
+fn test_write_with_compression_level() {
+    let mut df = create_df();
+
+    for (compression, level) in [(IpcCompression::LZ4, 9), (IpcCompression::ZSTD, 19)] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .with_compression_level(Some(level))
+            .finish(&mut df)
+            .expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert!(df.equals(&df_read));
+    }
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let result = IpcWriter::new(&mut buf)
+        .with_compression(Some(IpcCompression::ZSTD))
+        .with_compression_level(Some(100))
+        .finish(&mut df);
+    assert!(result.is_err());
+}
+
 #[test]
 fn write_and_read_ipc_empty_series() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
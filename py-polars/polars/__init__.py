@@ -91,7 +91,12 @@ from polars.functions import (
     arctan2d,
     arg_sort_by,
     arg_where,
+    business_date_range,
     business_day_count,
+    business_day_count_breakdown,
+    business_day_count_by_period,
+    business_day_count_fractional,
+    business_hour_count,
     coalesce,
     col,
     collect_all,
@@ -325,7 +330,12 @@ __all__ = [
     # polars.functions
     "align_frames",
     "arg_where",
+    "business_date_range",
     "business_day_count",
+    "business_day_count_breakdown",
+    "business_day_count_by_period",
+    "business_day_count_fractional",
+    "business_hour_count",
     "concat",
     "date_range",
     "date_ranges",
@@ -102,6 +102,8 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+BusinessDayUnit: TypeAlias = Literal["days", "weeks"]
+BusinessPeriod: TypeAlias = Literal["week", "month", "quarter"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
@@ -128,7 +130,9 @@ PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
-Roll: TypeAlias = Literal["raise", "forward", "backward"]
+Roll: TypeAlias = Literal[
+    "raise", "forward", "backward", "nearest_forward", "nearest_backward"
+]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
 SizeUnit: TypeAlias = Literal[
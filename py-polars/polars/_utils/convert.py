@@ -24,7 +24,7 @@ from polars._utils.constants import (
 )
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Iterable, Sequence
     from datetime import date, tzinfo
     from decimal import Decimal
 
@@ -80,6 +80,18 @@ def date_to_int(d: date) -> int:
     return (d - EPOCH_DATE).days
 
 
+def holidays_to_int(
+    holidays: Iterable[date | tuple[date, date]],
+) -> list[int | tuple[int, int]]:
+    """Convert holidays, given as dates or inclusive ranges of dates, to integers."""
+    return [
+        (date_to_int(holiday[0]), date_to_int(holiday[1]))
+        if isinstance(holiday, tuple)
+        else date_to_int(holiday)
+        for holiday in holidays
+    ]
+
+
 def time_to_int(t: time) -> int:
     """Convert a Python time object to an integer."""
     t = t.replace(tzinfo=timezone.utc)
@@ -5,7 +5,7 @@ from typing import TYPE_CHECKING
 
 import polars._reexport as pl
 from polars import functions as F
-from polars._utils.convert import parse_as_duration_string
+from polars._utils.convert import holidays_to_int, parse_as_duration_string
 from polars._utils.deprecation import deprecate_function, deprecate_nonkeyword_arguments
 from polars._utils.parse import parse_into_expression, parse_into_list_of_expressions
 from polars._utils.unstable import unstable
@@ -18,6 +18,7 @@ if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import (
         Ambiguous,
+        BusinessDayUnit,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -40,9 +41,13 @@ class ExprDateTimeNameSpace:
     def add_business_days(
         self,
         n: int | IntoExpr,
-        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        week_mask: Iterable[bool] | Expr = (
+            True, True, True, True, True, False, False
+        ),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
         roll: Roll = "raise",
+        *,
+        unit: BusinessDayUnit = "days",
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -60,6 +65,8 @@ class ExprDateTimeNameSpace:
             Which days of the week to count. The default is Monday to Friday.
             If you wanted to count only Monday to Thursday, you would pass
             `(True, True, True, True, False, False, False)`.
+            Can also be an expression with a week mask per row, either a list of
+            7 booleans or an integer bitmask where bit 0 is Monday.
         holidays
             Holidays to exclude from the count. The Python package
             `python-holidays <https://github.com/vacanza/python-holidays>`_
@@ -73,12 +80,22 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `add_business_days`.
+            A `(first, last)` tuple of dates excludes all days in between,
+            including both ends.
         roll
             What to do when the start date lands on a non-business day. Options are:
 
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+            - `'nearest_forward'`: move to the nearest business day, preferring the
+              next one on ties
+            - `'nearest_backward'`: move to the nearest business day, preferring the
+              previous one on ties
+        unit : {'days', 'weeks'}
+            Whether `n` is a number of business days or of business weeks. A business
+            week is as many business days as there are in `week_mask`. Can't be
+            combined with a `week_mask` expression.
 
         Returns
         -------
@@ -150,16 +167,294 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_into_expression(n)
-        unix_epoch = dt.date(1970, 1, 1)
+        if isinstance(week_mask, pl.Expr):
+            if unit != "days":
+                msg = "`unit` must be 'days' when `week_mask` is an expression"
+                raise ValueError(msg)
+            return wrap_expr(
+                self._pyexpr.dt_add_business_days_with_week_masks(
+                    n_pyexpr, week_mask._pyexpr, holidays_to_int(holidays), roll
+                )
+            )
         return wrap_expr(
             self._pyexpr.dt_add_business_days(
-                n_pyexpr,
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-                roll,
+                n_pyexpr, week_mask, holidays_to_int(holidays), roll, unit
+            )
+        )
+
+    @unstable()
+    def sub_business_days(
+        self,
+        n: int | IntoExpr,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        roll: Roll = "raise",
+    ) -> Expr:
+        """
+        Offset backwards by `n` business days.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n
+            Number of business days to offset backwards by. Can be a single number
+            or an expression.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count, as dates or as inclusive
+            `(first, last)` ranges of dates.
+        roll
+            What to do when the start date lands on a non-business day. See
+            :meth:`add_business_days` for the options.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        See Also
+        --------
+        add_business_days
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"start": [date(2020, 1, 8), date(2020, 1, 9)]})
+        >>> df.with_columns(result=pl.col("start").dt.sub_business_days(5))
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ start      ┆ result     │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2020-01-08 ┆ 2020-01-01 │
+        │ 2020-01-09 ┆ 2020-01-02 │
+        └────────────┴────────────┘
+        """
+        n_pyexpr = parse_into_expression(n)
+        return wrap_expr(
+            self._pyexpr.dt_sub_business_days(
+                n_pyexpr, week_mask, holidays_to_int(holidays), roll
+            )
+        )
+
+    @unstable()
+    def next_business_day(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        inclusive: bool = False,
+    ) -> Expr:
+        """
+        Get the next business day after each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+        inclusive
+            Whether business days are their own next business day.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 1, 3), date(2020, 1, 4)]})
+        >>> df.with_columns(
+        ...     next=pl.col("date").dt.next_business_day(),
+        ...     next_inclusive=pl.col("date").dt.next_business_day(inclusive=True),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────────┐
+        │ date       ┆ next       ┆ next_inclusive │
+        │ ---        ┆ ---        ┆ ---            │
+        │ date       ┆ date       ┆ date           │
+        ╞════════════╪════════════╪════════════════╡
+        │ 2020-01-03 ┆ 2020-01-06 ┆ 2020-01-03     │
+        │ 2020-01-04 ┆ 2020-01-06 ┆ 2020-01-06     │
+        └────────────┴────────────┴────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_next_business_day(
+                week_mask, holidays_to_int(holidays), inclusive
+            )
+        )
+
+    @unstable()
+    def previous_business_day(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        inclusive: bool = False,
+    ) -> Expr:
+        """
+        Get the previous business day before each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+        inclusive
+            Whether business days are their own previous business day.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 1, 3), date(2020, 1, 4)]})
+        >>> df.with_columns(
+        ...     previous=pl.col("date").dt.previous_business_day(),
+        ...     previous_inclusive=pl.col("date").dt.previous_business_day(
+        ...         inclusive=True
+        ...     ),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────────────┐
+        │ date       ┆ previous   ┆ previous_inclusive │
+        │ ---        ┆ ---        ┆ ---                │
+        │ date       ┆ date       ┆ date               │
+        ╞════════════╪════════════╪════════════════════╡
+        │ 2020-01-03 ┆ 2020-01-02 ┆ 2020-01-03         │
+        │ 2020-01-04 ┆ 2020-01-03 ┆ 2020-01-03         │
+        └────────────┴────────────┴────────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_previous_business_day(
+                week_mask, holidays_to_int(holidays), inclusive
             )
         )
 
+    @unstable()
+    def business_month_end(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Expr:
+        """
+        Get the last business day of the month of each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 2, 10), date(2020, 5, 15)]})
+        >>> df.with_columns(month_end=pl.col("date").dt.business_month_end())
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ month_end  │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2020-02-10 ┆ 2020-02-28 │
+        │ 2020-05-15 ┆ 2020-05-29 │
+        └────────────┴────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_business_month_end(week_mask, holidays_to_int(holidays))
+        )
+
+    @unstable()
+    def business_month_begin(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Expr:
+        """
+        Get the first business day of the month of each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+
+        Returns
+        -------
+        Expr
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2020, 2, 10), date(2020, 5, 15)]})
+        >>> df.with_columns(month_begin=pl.col("date").dt.business_month_begin())
+        shape: (2, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ month_begin │
+        │ ---        ┆ ---         │
+        │ date       ┆ date        │
+        ╞════════════╪═════════════╡
+        │ 2020-02-10 ┆ 2020-02-03  │
+        │ 2020-05-15 ┆ 2020-05-01  │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_business_month_begin(week_mask, holidays_to_int(holidays))
+        )
+
     def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
         """
         Divide the date/datetime range into buckets.
@@ -896,7 +1191,7 @@ class ExprDateTimeNameSpace:
         self,
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
     ) -> Expr:
         """
         Determine whether each day lands on a business day.
@@ -924,6 +1219,8 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+            A `(first, last)` tuple of dates excludes all days in between,
+            including both ends.
 
         Returns
         -------
@@ -978,14 +1275,281 @@ class ExprDateTimeNameSpace:
         │ 2020-01-05 ┆ false           │
         └────────────┴─────────────────┘
         """
-        unix_epoch = dt.date(1970, 1, 1)
         return wrap_expr(
-            self._pyexpr.dt_is_business_day(
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
+            self._pyexpr.dt_is_business_day(week_mask, holidays_to_int(holidays))
+        )
+
+    @unstable()
+    def is_holiday(
+        self, holidays: Iterable[dt.date | tuple[dt.date, dt.date]]
+    ) -> Expr:
+        """
+        Determine whether each day is a holiday.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`is_business_day`, this doesn't take the week mask into
+        account: holidays on weekends are holidays as well.
+
+        Parameters
+        ----------
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 1), date(2020, 1, 3), date(2020, 1, 4)]}
+        ... )
+        >>> holidays = [date(2020, 1, 1), date(2020, 1, 4)]
+        >>> df.with_columns(is_holiday=pl.col("date").dt.is_holiday(holidays))
+        shape: (3, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ is_holiday │
+        │ ---        ┆ ---        │
+        │ date       ┆ bool       │
+        ╞════════════╪════════════╡
+        │ 2020-01-01 ┆ true       │
+        │ 2020-01-03 ┆ false      │
+        │ 2020-01-04 ┆ true       │
+        └────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_holiday(holidays_to_int(holidays)))
+
+    @unstable()
+    def is_weekend(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    ) -> Expr:
+        """
+        Determine whether each day is excluded by the week mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`is_business_day`, this doesn't take holidays into account.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to
+            Friday, so that Saturday and Sunday are the weekend.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 3), date(2020, 1, 4), date(2020, 1, 5)]}
+        ... )
+        >>> df.with_columns(is_weekend=pl.col("date").dt.is_weekend())
+        shape: (3, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ is_weekend │
+        │ ---        ┆ ---        │
+        │ date       ┆ bool       │
+        ╞════════════╪════════════╡
+        │ 2020-01-03 ┆ false      │
+        │ 2020-01-04 ┆ true       │
+        │ 2020-01-05 ┆ true       │
+        └────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_is_weekend(week_mask))
+
+    @unstable()
+    def business_days_until_holiday(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Expr:
+        """
+        Count the business days until the next holiday at or after each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A holiday is zero business days away from itself. Days without a holiday
+        at or after them are null.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 3), date(2020, 1, 6), date(2020, 1, 13)]}
+        ... )
+        >>> df.with_columns(
+        ...     until_holiday=pl.col("date").dt.business_days_until_holiday(
+        ...         holidays=[date(2020, 1, 10)]
+        ...     )
+        ... )
+        shape: (3, 2)
+        ┌────────────┬───────────────┐
+        │ date       ┆ until_holiday │
+        │ ---        ┆ ---           │
+        │ date       ┆ i32           │
+        ╞════════════╪═══════════════╡
+        │ 2020-01-03 ┆ 5             │
+        │ 2020-01-06 ┆ 4             │
+        │ 2020-01-13 ┆ null          │
+        └────────────┴───────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_business_days_until_holiday(
+                week_mask, holidays_to_int(holidays)
             )
         )
 
+    @unstable()
+    def business_day_reason(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Expr:
+        """
+        Determine why each day is or isn't a business day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`, with the values `"business"`,
+            `"weekend"` or `"holiday"`. Days excluded by `week_mask` are
+            `"weekend"`, even if they are holidays.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2020, 1, 1), date(2020, 1, 3), date(2020, 1, 4)]}
+        ... )
+        >>> df.with_columns(
+        ...     reason=pl.col("date").dt.business_day_reason(
+        ...         holidays=[date(2020, 1, 1)]
+        ...     )
+        ... )
+        shape: (3, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ reason     │
+        │ ---        ┆ ---        │
+        │ date       ┆ str        │
+        ╞════════════╪════════════╡
+        │ 2020-01-01 ┆ "holiday"  │
+        │ 2020-01-03 ┆ "business" │
+        │ 2020-01-04 ┆ "weekend"  │
+        └────────────┴────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_business_day_reason(week_mask, holidays_to_int(holidays))
+        )
+
+    @unstable()
+    def business_day_of_month(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Expr:
+        """
+        Determine which business day of its month each day is, counting from 1.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Days that aren't business days are null.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count, as dates or as inclusive
+            `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [
+        ...             date(2020, 1, 2),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 4),
+        ...             date(2020, 1, 6),
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     day_of_month=pl.col("date").dt.business_day_of_month(
+        ...         holidays=[date(2020, 1, 1)]
+        ...     )
+        ... )
+        shape: (4, 2)
+        ┌────────────┬──────────────┐
+        │ date       ┆ day_of_month │
+        │ ---        ┆ ---          │
+        │ date       ┆ i32          │
+        ╞════════════╪══════════════╡
+        │ 2020-01-02 ┆ 1            │
+        │ 2020-01-03 ┆ 2            │
+        │ 2020-01-04 ┆ null         │
+        │ 2020-01-06 ┆ 3            │
+        └────────────┴──────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_business_day_of_month(week_mask, holidays_to_int(holidays))
+        )
+
     def is_leap_year(self) -> Expr:
         """
         Determine whether the year of the underlying date is a leap year.
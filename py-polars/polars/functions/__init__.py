@@ -24,7 +24,14 @@ from polars.functions.as_datatype import (
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import (
+    business_date_range,
+    business_day_count,
+    business_day_count_breakdown,
+    business_day_count_by_period,
+    business_day_count_fractional,
+    business_hour_count,
+)
 from polars.functions.col import col
 from polars.functions.eager import align_frames, concat
 from polars.functions.escape_regex import escape_regex
@@ -123,7 +130,12 @@ __all__ = [
     "arctan2",
     "arctan2d",
     "arg_sort_by",
+    "business_date_range",
     "business_day_count",
+    "business_day_count_breakdown",
+    "business_day_count_by_period",
+    "business_day_count_fractional",
+    "business_hour_count",
     "coalesce",
     "col",
     "collect_all",
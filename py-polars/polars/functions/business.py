@@ -1,9 +1,15 @@
 from __future__ import annotations
 
 import contextlib
-from datetime import date
+from datetime import time
 from typing import TYPE_CHECKING
 
+import polars._reexport as pl
+from polars._utils.convert import (
+    holidays_to_int,
+    parse_as_duration_string,
+    time_to_int,
+)
 from polars._utils.deprecation import deprecate_nonkeyword_arguments
 from polars._utils.parse import parse_into_expression
 from polars._utils.unstable import unstable
@@ -14,9 +20,15 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     from collections.abc import Iterable
+    from datetime import date, datetime, timedelta
 
     from polars import Expr
-    from polars._typing import IntoExprColumn
+    from polars._typing import (
+        BusinessDayUnit,
+        BusinessPeriod,
+        ClosedInterval,
+        IntoExprColumn,
+    )
 
 
 @unstable()
@@ -24,8 +36,12 @@ if TYPE_CHECKING:
 def business_day_count(
     start: date | IntoExprColumn,
     end: date | IntoExprColumn,
-    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-    holidays: Iterable[date] = (),
+    week_mask: Iterable[bool] | Expr = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] | Expr = (),
+    *,
+    closed: ClosedInterval = "left",
+    unit: BusinessDayUnit = "days",
+    hours_per_day: float | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -44,6 +60,8 @@ def business_day_count(
         Which days of the week to count. The default is Monday to Friday.
         If you wanted to count only Monday to Thursday, you would pass
         `(True, True, True, True, False, False, False)`.
+        Can also be an expression with a week mask per row, either a list of
+        7 booleans or an integer bitmask where bit 0 is Monday.
     holidays
         Holidays to exclude from the count. The Python package
         `python-holidays <https://github.com/vacanza/python-holidays>`_
@@ -57,10 +75,23 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+        A `(first, last)` tuple of dates excludes all days in between, including
+        both ends. Can also be an expression, whose non-null values are all
+        excluded; in a group-by context, each group only excludes its own.
+    closed : {'left', 'right', 'both', 'none'}
+        Which ends of the interval between `start` and `end` to count.
+    unit : {'days', 'weeks'}
+        Whether to count business days, or business weeks of as many business days
+        as there are in `week_mask`.
+    hours_per_day
+        Count in business hours instead, with this many hours per business day.
+        Can only be combined with `unit='days'`.
 
     Returns
     -------
     Expr
+        Expression of data type :class:`Int32`, or :class:`Float64` when counting
+        in weeks or hours.
 
     Examples
     --------
@@ -121,15 +152,404 @@ def business_day_count(
     │ 2020-01-01 ┆ 2020-01-02 ┆ 0                  │
     │ 2020-01-02 ┆ 2020-01-10 ┆ 5                  │
     └────────────┴────────────┴────────────────────┘
+
+    To also count `end`, close the interval on both sides:
+
+    >>> df.with_columns(
+    ...     business_day_count=pl.business_day_count("start", "end", closed="both")
+    ... )
+    shape: (2, 3)
+    ┌────────────┬────────────┬────────────────────┐
+    │ start      ┆ end        ┆ business_day_count │
+    │ ---        ┆ ---        ┆ ---                │
+    │ date       ┆ date       ┆ i32                │
+    ╞════════════╪════════════╪════════════════════╡
+    │ 2020-01-01 ┆ 2020-01-02 ┆ 2                  │
+    │ 2020-01-02 ┆ 2020-01-10 ┆ 7                  │
+    └────────────┴────────────┴────────────────────┘
+
+    You can also count in business weeks, or in business hours:
+
+    >>> df.with_columns(
+    ...     weeks=pl.business_day_count("start", "end", unit="weeks"),
+    ...     hours=pl.business_day_count("start", "end", hours_per_day=8),
+    ... )
+    shape: (2, 4)
+    ┌────────────┬────────────┬───────┬───────┐
+    │ start      ┆ end        ┆ weeks ┆ hours │
+    │ ---        ┆ ---        ┆ ---   ┆ ---   │
+    │ date       ┆ date       ┆ f64   ┆ f64   │
+    ╞════════════╪════════════╪═══════╪═══════╡
+    │ 2020-01-01 ┆ 2020-01-02 ┆ 0.2   ┆ 8.0   │
+    │ 2020-01-02 ┆ 2020-01-10 ┆ 1.2   ┆ 48.0  │
+    └────────────┴────────────┴───────┴───────┘
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
-    unix_epoch = date(1970, 1, 1)
+    if isinstance(week_mask, pl.Expr):
+        if isinstance(holidays, pl.Expr):
+            msg = "`week_mask` and `holidays` can't both be expressions"
+            raise TypeError(msg)
+        return wrap_expr(
+            plr.business_day_count_with_week_masks(
+                start_pyexpr,
+                end_pyexpr,
+                week_mask._pyexpr,
+                holidays_to_int(holidays),
+                closed,
+                unit,
+                hours_per_day,
+            )
+        )
+    if isinstance(holidays, pl.Expr):
+        return wrap_expr(
+            plr.business_day_count_with_holidays(
+                start_pyexpr,
+                end_pyexpr,
+                week_mask,
+                holidays._pyexpr,
+                closed,
+                unit,
+                hours_per_day,
+            )
+        )
     return wrap_expr(
         plr.business_day_count(
             start_pyexpr,
             end_pyexpr,
             week_mask,
-            [(holiday - unix_epoch).days for holiday in holidays],
+            holidays_to_int(holidays),
+            closed,
+            unit,
+            hours_per_day,
+        )
+    )
+
+
+@unstable()
+def business_day_count_breakdown(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] = (),
+    closed: ClosedInterval = "left",
+) -> Expr:
+    """
+    Count the calendar days, weekend days and holidays between `start` and `end`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Holidays that fall on a weekend are counted as weekend days. The number of
+    business days is the number of calendar days minus the other two.
+
+    Parameters
+    ----------
+    start
+        Start dates.
+    end
+        End dates.
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+    holidays
+        Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+    closed : {'left', 'right', 'both', 'none'}
+        Which ends of the interval between `start` and `end` to count.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Struct` with the :class:`Int32` fields
+        `calendar_days`, `weekend_days` and `holiday_days`.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame({"start": [date(2020, 1, 1)], "end": [date(2020, 1, 10)]})
+    >>> df.select(
+    ...     pl.business_day_count_breakdown(
+    ...         "start", "end", holidays=[date(2020, 1, 1)]
+    ...     ).struct.unnest()
+    ... )
+    shape: (1, 3)
+    ┌───────────────┬──────────────┬──────────────┐
+    │ calendar_days ┆ weekend_days ┆ holiday_days │
+    │ ---           ┆ ---          ┆ ---          │
+    │ i32           ┆ i32          ┆ i32          │
+    ╞═══════════════╪══════════════╪══════════════╡
+    │ 9             ┆ 2            ┆ 1            │
+    └───────────────┴──────────────┴──────────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(
+        plr.business_day_count_breakdown(
+            start_pyexpr, end_pyexpr, week_mask, holidays_to_int(holidays), closed
+        )
+    )
+
+
+@unstable()
+def business_day_count_by_period(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    period: BusinessPeriod,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] = (),
+) -> Expr:
+    """
+    Count the business days between `start` and `end` per calendar period.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    start
+        Start dates.
+    end
+        End dates, not included in the count.
+    period : {'week', 'month', 'quarter'}
+        The calendar period to count per. Weeks start on Monday.
+    week_mask
+        Which days of the week to count. The default is Monday to Friday.
+    holidays
+        Holidays to exclude from the count, as dates or as inclusive
+        `(first, last)` ranges of dates.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`List` with a :class:`Struct` for every
+        period that overlaps the interval, with the fields `period_start`
+        (:class:`Date`) and `business_days` (:class:`Int32`).
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame({"start": [date(2020, 1, 27)], "end": [date(2020, 2, 5)]})
+    >>> df.select(
+    ...     pl.business_day_count_by_period("start", "end", "month")
+    ...     .explode()
+    ...     .struct.unnest()
+    ... )
+    shape: (2, 2)
+    ┌──────────────┬───────────────┐
+    │ period_start ┆ business_days │
+    │ ---          ┆ ---           │
+    │ date         ┆ i32           │
+    ╞══════════════╪═══════════════╡
+    │ 2020-01-01   ┆ 5             │
+    │ 2020-02-01   ┆ 2             │
+    └──────────────┴───────────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(
+        plr.business_day_count_by_period(
+            start_pyexpr, end_pyexpr, week_mask, holidays_to_int(holidays), period
+        )
+    )
+
+
+@unstable()
+def business_day_count_fractional(
+    start: datetime | IntoExprColumn,
+    end: datetime | IntoExprColumn,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] = (),
+) -> Expr:
+    """
+    Count the business days between `start` and `end`, including partial days.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The days that `start` and `end` fall on count for the fraction of the day
+    that is between them. Partial days that aren't business days count as zero.
+
+    Parameters
+    ----------
+    start
+        Start datetimes.
+    end
+        End datetimes.
+    week_mask
+        Which days of the week to count. The default is Monday to Friday.
+    holidays
+        Holidays to exclude from the count, as dates or as inclusive
+        `(first, last)` ranges of dates.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`.
+
+    Examples
+    --------
+    >>> from datetime import datetime
+    >>> df = pl.DataFrame(
+    ...     {"start": [datetime(2020, 1, 3, 12)], "end": [datetime(2020, 1, 6, 18)]}
+    ... )
+    >>> df.with_columns(
+    ...     business_days=pl.business_day_count_fractional("start", "end")
+    ... )
+    shape: (1, 3)
+    ┌─────────────────────┬─────────────────────┬───────────────┐
+    │ start               ┆ end                 ┆ business_days │
+    │ ---                 ┆ ---                 ┆ ---           │
+    │ datetime[μs]        ┆ datetime[μs]        ┆ f64           │
+    ╞═════════════════════╪═════════════════════╪═══════════════╡
+    │ 2020-01-03 12:00:00 ┆ 2020-01-06 18:00:00 ┆ 1.25          │
+    └─────────────────────┴─────────────────────┴───────────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(
+        plr.business_day_count_fractional(
+            start_pyexpr, end_pyexpr, week_mask, holidays_to_int(holidays)
+        )
+    )
+
+
+@unstable()
+def business_hour_count(
+    start: datetime | IntoExprColumn,
+    end: datetime | IntoExprColumn,
+    day_start: time | timedelta,
+    day_end: time | timedelta,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] = (),
+) -> Expr:
+    """
+    Count the business hours between `start` and `end`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Only the time between `day_start` and `day_end` on business days is counted.
+
+    Parameters
+    ----------
+    start
+        Start datetimes.
+    end
+        End datetimes.
+    day_start
+        Start of the business hours of each business day.
+    day_end
+        End of the business hours of each business day. Pass a timedelta of one day
+        for business hours until midnight.
+    week_mask
+        Which days of the week to count. The default is Monday to Friday.
+    holidays
+        Holidays to exclude from the count, as dates or as inclusive
+        `(first, last)` ranges of dates.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`.
+
+    Examples
+    --------
+    >>> from datetime import datetime, time
+    >>> df = pl.DataFrame(
+    ...     {"start": [datetime(2020, 1, 3, 12)], "end": [datetime(2020, 1, 6, 18)]}
+    ... )
+    >>> df.with_columns(
+    ...     business_hours=pl.business_hour_count("start", "end", time(9), time(17))
+    ... )
+    shape: (1, 3)
+    ┌─────────────────────┬─────────────────────┬────────────────┐
+    │ start               ┆ end                 ┆ business_hours │
+    │ ---                 ┆ ---                 ┆ ---            │
+    │ datetime[μs]        ┆ datetime[μs]        ┆ f64            │
+    ╞═════════════════════╪═════════════════════╪════════════════╡
+    │ 2020-01-03 12:00:00 ┆ 2020-01-06 18:00:00 ┆ 13.0           │
+    └─────────────────────┴─────────────────────┴────────────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(
+        plr.business_hour_count(
+            start_pyexpr,
+            end_pyexpr,
+            week_mask,
+            holidays_to_int(holidays),
+            _time_of_day_to_duration_string(day_start),
+            _time_of_day_to_duration_string(day_end),
         )
     )
+
+
+@unstable()
+def business_date_range(
+    start: date | IntoExprColumn,
+    end: date | IntoExprColumn,
+    *,
+    step: int = 1,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date | tuple[date, date]] = (),
+) -> Expr:
+    """
+    Generate the business days between `start` and `end`, both inclusive.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    start
+        Start dates.
+    end
+        End dates. An `end` before `start` results in an empty list.
+    step
+        Number of business days between consecutive dates.
+    week_mask
+        Which days of the week to generate. The default is Monday to Friday.
+    holidays
+        Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+        dates.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`List` of :class:`Date`.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame({"start": [date(2020, 1, 3)], "end": [date(2020, 1, 6)]})
+    >>> df.with_columns(dates=pl.business_date_range("start", "end"))
+    shape: (1, 3)
+    ┌────────────┬────────────┬──────────────────────────┐
+    │ start      ┆ end        ┆ dates                    │
+    │ ---        ┆ ---        ┆ ---                      │
+    │ date       ┆ date       ┆ list[date]               │
+    ╞════════════╪════════════╪══════════════════════════╡
+    │ 2020-01-03 ┆ 2020-01-06 ┆ [2020-01-03, 2020-01-06] │
+    └────────────┴────────────┴──────────────────────────┘
+    """
+    start_pyexpr = parse_into_expression(start)
+    end_pyexpr = parse_into_expression(end)
+    return wrap_expr(
+        plr.business_date_range(
+            start_pyexpr, end_pyexpr, week_mask, holidays_to_int(holidays), step
+        )
+    )
+
+
+def _time_of_day_to_duration_string(value: time | timedelta) -> str:
+    if isinstance(value, time):
+        return f"{time_to_int(value)}ns"
+    return parse_as_duration_string(value)
@@ -11,9 +11,10 @@ if TYPE_CHECKING:
     import datetime as dt
     from collections.abc import Iterable
 
-    from polars import Series
+    from polars import Expr, Series
     from polars._typing import (
         Ambiguous,
+        BusinessDayUnit,
         EpochTimeUnit,
         IntoExpr,
         IntoExprColumn,
@@ -43,9 +44,13 @@ class DateTimeNameSpace:
     def add_business_days(
         self,
         n: int | IntoExpr,
-        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        week_mask: Iterable[bool] | Expr = (
+            True, True, True, True, True, False, False
+        ),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
         roll: Roll = "raise",
+        *,
+        unit: BusinessDayUnit = "days",
     ) -> Series:
         """
         Offset by `n` business days.
@@ -63,6 +68,8 @@ class DateTimeNameSpace:
             Which days of the week to count. The default is Monday to Friday.
             If you wanted to count only Monday to Thursday, you would pass
             `(True, True, True, True, False, False, False)`.
+            Can also be an expression with a week mask per row, either a list of
+            7 booleans or an integer bitmask where bit 0 is Monday.
         holidays
             Holidays to exclude from the count. The Python package
             `python-holidays <https://github.com/vacanza/python-holidays>`_
@@ -76,12 +83,22 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `add_business_days`.
+            A `(first, last)` tuple of dates excludes all days in between,
+            including both ends.
         roll
             What to do when the start date lands on a non-business day. Options are:
 
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+            - `'nearest_forward'`: move to the nearest business day, preferring the
+              next one on ties
+            - `'nearest_backward'`: move to the nearest business day, preferring the
+              previous one on ties
+        unit : {'days', 'weeks'}
+            Whether `n` is a number of business days or of business weeks. A business
+            week is as many business days as there are in `week_mask`. Can't be
+            combined with a `week_mask` expression.
 
         Returns
         -------
@@ -135,6 +152,234 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def sub_business_days(
+        self,
+        n: int | IntoExpr,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        roll: Roll = "raise",
+    ) -> Series:
+        """
+        Offset backwards by `n` business days.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n
+            Number of business days to offset backwards by. Can be a single number
+            or an expression.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count, as dates or as inclusive
+            `(first, last)` ranges of dates.
+        roll
+            What to do when the start date lands on a non-business day. See
+            :meth:`add_business_days` for the options.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        See Also
+        --------
+        add_business_days
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("start", [date(2020, 1, 8), date(2020, 1, 9)])
+        >>> s.dt.sub_business_days(5)
+        shape: (2,)
+        Series: 'start' [date]
+        [
+                2020-01-01
+                2020-01-02
+        ]
+        """
+
+    @unstable()
+    def next_business_day(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        inclusive: bool = False,
+    ) -> Series:
+        """
+        Get the next business day after each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+        inclusive
+            Whether business days are their own next business day.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 4)])
+        >>> s.dt.next_business_day()
+        shape: (2,)
+        Series: '' [date]
+        [
+                2020-01-06
+                2020-01-06
+        ]
+        """
+
+    @unstable()
+    def previous_business_day(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+        inclusive: bool = False,
+    ) -> Series:
+        """
+        Get the previous business day before each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+        inclusive
+            Whether business days are their own previous business day.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 4)])
+        >>> s.dt.previous_business_day()
+        shape: (2,)
+        Series: '' [date]
+        [
+                2020-01-02
+                2020-01-03
+        ]
+        """
+
+    @unstable()
+    def business_month_end(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Series:
+        """
+        Get the last business day of the month of each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 2, 10), date(2020, 5, 15)])
+        >>> s.dt.business_month_end()
+        shape: (2,)
+        Series: '' [date]
+        [
+                2020-02-28
+                2020-05-29
+        ]
+        """
+
+    @unstable()
+    def business_month_begin(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Series:
+        """
+        Get the first business day of the month of each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to skip, as dates or as inclusive `(first, last)` ranges of
+            dates.
+
+        Returns
+        -------
+        Series
+            Data type is preserved.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 2, 10), date(2020, 5, 15)])
+        >>> s.dt.business_month_begin()
+        shape: (2,)
+        Series: '' [date]
+        [
+                2020-02-03
+                2020-05-01
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -486,7 +731,7 @@ class DateTimeNameSpace:
         self,
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
-        holidays: Iterable[dt.date] = (),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
     ) -> Series:
         """
         Determine whether each day lands on a business day.
@@ -514,6 +759,8 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+            A `(first, last)` tuple of dates excludes all days in between,
+            including both ends.
 
         Returns
         -------
@@ -556,6 +803,221 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def is_holiday(
+        self, holidays: Iterable[dt.date | tuple[dt.date, dt.date]]
+    ) -> Series:
+        """
+        Determine whether each day is a holiday.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`is_business_day`, this doesn't take the week mask into
+        account: holidays on weekends are holidays as well.
+
+        Parameters
+        ----------
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 1), date(2020, 1, 3), date(2020, 1, 4)])
+        >>> s.dt.is_holiday([date(2020, 1, 1), date(2020, 1, 4)])
+        shape: (3,)
+        Series: '' [bool]
+        [
+                true
+                false
+                true
+        ]
+        """
+
+    @unstable()
+    def is_weekend(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    ) -> Series:
+        """
+        Determine whether each day is excluded by the week mask.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Unlike :meth:`is_business_day`, this doesn't take holidays into account.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week are business days. The default is Monday to
+            Friday, so that Saturday and Sunday are the weekend.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 4), date(2020, 1, 5)])
+        >>> s.dt.is_weekend()
+        shape: (3,)
+        Series: '' [bool]
+        [
+                false
+                true
+                true
+        ]
+        """
+
+    @unstable()
+    def business_days_until_holiday(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Series:
+        """
+        Count the business days until the next holiday at or after each day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A holiday is zero business days away from itself. Days without a holiday
+        at or after them are null.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 3), date(2020, 1, 6), date(2020, 1, 13)])
+        >>> s.dt.business_days_until_holiday(holidays=[date(2020, 1, 10)])
+        shape: (3,)
+        Series: '' [i32]
+        [
+                5
+                4
+                null
+        ]
+        """
+
+    @unstable()
+    def business_day_reason(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Series:
+        """
+        Determine why each day is or isn't a business day.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays, as dates or as inclusive `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`, with the values `"business"`,
+            `"weekend"` or `"holiday"`. Days excluded by `week_mask` are
+            `"weekend"`, even if they are holidays.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series([date(2020, 1, 1), date(2020, 1, 3), date(2020, 1, 4)])
+        >>> s.dt.business_day_reason(holidays=[date(2020, 1, 1)])
+        shape: (3,)
+        Series: '' [str]
+        [
+                "holiday"
+                "business"
+                "weekend"
+        ]
+        """
+
+    @unstable()
+    def business_day_of_month(
+        self,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date | tuple[dt.date, dt.date]] = (),
+    ) -> Series:
+        """
+        Determine which business day of its month each day is, counting from 1.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Days that aren't business days are null.
+
+        Parameters
+        ----------
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count, as dates or as inclusive
+            `(first, last)` ranges of dates.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     [date(2020, 1, 2), date(2020, 1, 3), date(2020, 1, 4), date(2020, 1, 6)]
+        ... )
+        >>> s.dt.business_day_of_month(holidays=[date(2020, 1, 1)])
+        shape: (4,)
+        Series: '' [i32]
+        [
+                1
+                2
+                null
+                3
+        ]
+        """
+
     def is_leap_year(self) -> Series:
         """
         Determine whether the year of the underlying date representation is a leap year.
@@ -145,6 +145,24 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(
+        functions::business_day_count_with_holidays
+    ))
+    .unwrap();
+    m.add_wrapped(wrap_pyfunction!(
+        functions::business_day_count_with_week_masks
+    ))
+    .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_day_count_breakdown))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_day_count_by_period))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_day_count_fractional))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_hour_count))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::business_date_range))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
@@ -1,7 +1,8 @@
 from __future__ import annotations
 
 import datetime as dt
-from datetime import date
+from datetime import date, datetime, time, timedelta
+from typing import TYPE_CHECKING
 
 import hypothesis.strategies as st
 import numpy as np
@@ -10,8 +11,11 @@ from hypothesis import assume, given, reject
 
 import polars as pl
 from polars._utils.various import parse_version
-from polars.exceptions import ComputeError
-from polars.testing import assert_series_equal
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import BusinessPeriod, ClosedInterval
 
 
 def test_business_day_count() -> None:
@@ -172,3 +176,353 @@ def test_unequal_length_22018() -> None:
                 pl.Series([date(2020, 1, 1)] * 3),
             )
         )
+
+
+def test_business_day_count_in_unit() -> None:
+    df = pl.DataFrame({"start": [date(2024, 1, 1)], "end": [date(2024, 1, 15)]})
+    holidays = [date(2024, 1, 2)]
+
+    result = df.select(
+        days=pl.business_day_count("start", "end", holidays=holidays),
+        weeks=pl.business_day_count("start", "end", holidays=holidays, unit="weeks"),
+        hours=pl.business_day_count(
+            "start", "end", holidays=holidays, hours_per_day=7.5
+        ),
+    )
+    expected = pl.DataFrame(
+        {"days": [9], "weeks": [1.8], "hours": [67.5]},
+        schema={"days": pl.Int32, "weeks": pl.Float64, "hours": pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(
+        InvalidOperationError, match="`hours_per_day` can only be used when counting"
+    ):
+        df.select(pl.business_day_count("start", "end", unit="weeks", hours_per_day=8))
+    with pytest.raises(InvalidOperationError, match=r"must be in the range \(0, 24\]"):
+        df.select(pl.business_day_count("start", "end", hours_per_day=0))
+    # A week without business days can't be counted in.
+    with pytest.raises(ComputeError, match="at least one business day"):
+        df.select(
+            pl.business_day_count("start", "end", week_mask=[False] * 7, unit="weeks")
+        )
+    with pytest.raises(ValueError, match="`unit` must be one of"):
+        pl.business_day_count("start", "end", unit="months")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("closed", "expected_values"),
+    [
+        ("left", [0, 4, 0, -4, None, None]),
+        ("both", [1, 5, 0, -5, None, None]),
+        ("right", [0, 4, 0, -4, None, None]),
+        ("none", [0, 3, 0, -3, None, None]),
+    ],
+)
+def test_business_day_count_closed(
+    closed: ClosedInterval, expected_values: list[int | None]
+) -> None:
+    # 2024-01-01 is a Monday, 2024-01-05 a Friday and 2024-01-06 a Saturday.
+    df = pl.DataFrame(
+        {
+            "start": [
+                date(2024, 1, 1),
+                date(2024, 1, 1),
+                date(2024, 1, 6),
+                date(2024, 1, 5),
+                None,
+                date(2024, 1, 1),
+            ],
+            "end": [
+                date(2024, 1, 1),
+                date(2024, 1, 5),
+                date(2024, 1, 6),
+                date(2024, 1, 1),
+                date(2024, 1, 1),
+                None,
+            ],
+        }
+    )
+    result = df.select(n=pl.business_day_count("start", "end", closed=closed))["n"]
+    expected = pl.Series("n", expected_values, pl.Int32)
+    assert_series_equal(result, expected)
+
+
+def test_business_day_count_w_holiday_ranges() -> None:
+    df = pl.DataFrame({"start": [date(2024, 1, 1)], "end": [date(2024, 1, 15)]})
+    holidays = [(date(2024, 1, 2), date(2024, 1, 4)), date(2024, 1, 10)]
+    result = df.select(n=pl.business_day_count("start", "end", holidays=holidays))
+    assert result["n"].item() == 6
+
+
+def test_business_day_count_w_holidays_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 1), date(2024, 1, 8)],
+            "end": [date(2024, 1, 8), date(2024, 1, 15)],
+            "holiday": [date(2024, 1, 2), None],
+        }
+    )
+    result = df.select(
+        n=pl.business_day_count("start", "end", holidays=pl.col("holiday"))
+    )["n"]
+    expected = pl.Series("n", [4, 5], pl.Int32)
+    assert_series_equal(result, expected)
+
+    with pytest.raises(TypeError, match="can't both be expressions"):
+        pl.business_day_count(
+            "start", "end", week_mask=pl.col("mask"), holidays=pl.col("holiday")
+        )
+
+
+def test_business_day_count_w_week_masks_expr() -> None:
+    # 2024-01-01 (Monday) until 2024-01-08 (Monday).
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 1)] * 3,
+            "end": [date(2024, 1, 8)] * 3,
+            "week_mask": [
+                [True, True, True, True, True, False, False],
+                [True, True, True, True, False, False, True],
+                None,
+            ],
+        }
+    )
+    result = df.select(
+        n=pl.business_day_count("start", "end", week_mask=pl.col("week_mask"))
+    )["n"]
+    expected = pl.Series("n", [5, 5, None], pl.Int32)
+    assert_series_equal(result, expected)
+
+    # Mon-Sat as a bitmask.
+    df = df.head(1)
+    result = df.select(
+        n=pl.business_day_count(
+            "start", "end", week_mask=pl.lit(0b0111111), unit="weeks"
+        )
+    )["n"]
+    expected = pl.Series("n", [1.0])
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="must be a bitmask between 1 and 127"):
+        df.select(pl.business_day_count("start", "end", week_mask=pl.lit(0)))
+
+
+def test_business_day_count_breakdown() -> None:
+    # 2024-01-01 (Monday) until 2024-01-15 (Monday), and reversed.
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 1), date(2024, 1, 15), None],
+            "end": [date(2024, 1, 15), date(2024, 1, 1), date(2024, 1, 15)],
+        }
+    )
+    # 2024-01-02 is a Tuesday and 2024-01-06 a Saturday.
+    holidays = [date(2024, 1, 2), date(2024, 1, 6)]
+    result = df.select(
+        breakdown=pl.business_day_count_breakdown("start", "end", holidays=holidays),
+        n=pl.business_day_count("start", "end", holidays=holidays),
+    )
+    expected = pl.DataFrame(
+        {
+            "breakdown": [
+                {"calendar_days": 14, "weekend_days": 4, "holiday_days": 1},
+                {"calendar_days": -14, "weekend_days": -4, "holiday_days": -1},
+                None,
+            ],
+            "n": [9, -9, None],
+        },
+        schema={
+            "breakdown": pl.Struct(
+                {
+                    "calendar_days": pl.Int32,
+                    "weekend_days": pl.Int32,
+                    "holiday_days": pl.Int32,
+                }
+            ),
+            "n": pl.Int32,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("period", "expected_starts", "expected_counts"),
+    [
+        (
+            "month",
+            [date(2024, 1, 1), date(2024, 2, 1), date(2024, 3, 1), date(2024, 4, 1)],
+            [2, 21, 21, 1],
+        ),
+        ("quarter", [date(2024, 1, 1), date(2024, 4, 1)], [44, 1]),
+    ],
+)
+def test_business_day_count_by_period(
+    period: BusinessPeriod, expected_starts: list[date], expected_counts: list[int]
+) -> None:
+    # 2024-01-29 (Monday) until 2024-04-02 (Tuesday), with a holiday on 2024-01-30.
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 29), date(2024, 1, 29)],
+            "end": [date(2024, 4, 2), None],
+        }
+    )
+    result = df.select(
+        n=pl.business_day_count_by_period(
+            "start", "end", period, holidays=[date(2024, 1, 30)]
+        )
+    )["n"]
+    assert result.dtype == pl.List(
+        pl.Struct({"period_start": pl.Date, "business_days": pl.Int32})
+    )
+    assert result[1] is None
+    periods = result[0]
+    assert periods.struct.field("period_start").to_list() == expected_starts
+    assert periods.struct.field("business_days").to_list() == expected_counts
+
+
+def test_business_day_count_by_week() -> None:
+    df = pl.DataFrame({"start": [date(2024, 1, 29)], "end": [date(2024, 4, 2)]})
+    result = df.select(
+        pl.business_day_count_by_period(
+            "start", "end", "week", holidays=[date(2024, 1, 30)]
+        )
+        .explode()
+        .struct.unnest()
+    )
+    assert result.height == 10
+    assert result["period_start"][0] == date(2024, 1, 29)
+    assert result["business_days"][0] == 4
+    assert result["business_days"].sum() == 45
+
+
+def test_business_day_count_fractional() -> None:
+    # 2024-01-01 is a Monday, 2024-01-02 is a holiday, 2024-01-06 is a Saturday.
+    df = pl.DataFrame(
+        {
+            "start": [
+                datetime(2024, 1, 1, 12),
+                datetime(2024, 1, 1, 18),
+                datetime(2024, 1, 4, 12),
+                datetime(2024, 1, 6, 12),
+            ],
+            "end": [
+                datetime(2024, 1, 1, 18),
+                datetime(2024, 1, 3, 12),
+                datetime(2024, 1, 1, 18),
+                datetime(2024, 1, 8, 6),
+            ],
+        }
+    )
+    result = df.select(
+        n=pl.business_day_count_fractional(
+            "start", "end", holidays=[date(2024, 1, 2)]
+        )
+    )["n"]
+    expected = pl.Series("n", [0.25, 0.75, -1.75, 0.25])
+    assert_series_equal(result, expected)
+
+    # Dates cover whole days.
+    result = pl.select(
+        n=pl.business_day_count_fractional(
+            date(2024, 1, 1), date(2024, 1, 4), holidays=[date(2024, 1, 2)]
+        )
+    )["n"]
+    assert_series_equal(result, pl.Series("n", [2.0]))
+
+
+def test_business_hour_count() -> None:
+    # 2024-01-01 is a Monday, 2024-01-02 is a holiday.
+    df = pl.DataFrame(
+        {
+            "start": [
+                datetime(2024, 1, 1, 10),
+                datetime(2024, 1, 1, 8),
+                datetime(2024, 1, 1, 16),
+                datetime(2024, 1, 3, 10),
+                datetime(2024, 1, 5, 16),
+                datetime(2024, 1, 6, 10),
+            ],
+            "end": [
+                datetime(2024, 1, 1, 12),
+                datetime(2024, 1, 1, 18),
+                datetime(2024, 1, 3, 10),
+                datetime(2024, 1, 1, 16),
+                datetime(2024, 1, 8, 10),
+                datetime(2024, 1, 6, 12),
+            ],
+        }
+    )
+    result = df.select(
+        n=pl.business_hour_count(
+            "start", "end", time(9), time(17), holidays=[date(2024, 1, 2)]
+        )
+    )["n"]
+    expected = pl.Series("n", [2.0, 8.0, 2.0, -2.0, 2.0, 0.0])
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="business hours must satisfy"):
+        df.select(
+            pl.business_hour_count(
+                "start", "end", time(0), timedelta(days=1, microseconds=1)
+            )
+        )
+
+
+def test_business_date_range() -> None:
+    # 2024-01-05 (Friday) until 2024-01-12 (Friday), with a holiday on 2024-01-09.
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 5), date(2024, 1, 6), date(2024, 1, 12), None],
+            "end": [
+                date(2024, 1, 12),
+                date(2024, 1, 7),
+                date(2024, 1, 5),
+                date(2024, 1, 12),
+            ],
+        }
+    )
+    holidays = [date(2024, 1, 9)]
+    result = df.select(
+        step_1=pl.business_date_range("start", "end", holidays=holidays),
+        step_2=pl.business_date_range("start", "end", step=2, holidays=holidays),
+    )
+    expected = pl.DataFrame(
+        {
+            "step_1": [
+                [
+                    date(2024, 1, 5),
+                    date(2024, 1, 8),
+                    date(2024, 1, 10),
+                    date(2024, 1, 11),
+                    date(2024, 1, 12),
+                ],
+                [],
+                [],
+                None,
+            ],
+            "step_2": [
+                [date(2024, 1, 5), date(2024, 1, 10), date(2024, 1, 12)],
+                [],
+                [],
+                None,
+            ],
+        },
+        schema={"step_1": pl.List(pl.Date), "step_2": pl.List(pl.Date)},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="`step` must be positive"):
+        df.select(pl.business_date_range("start", "end", step=0))
+
+
+@pytest.mark.parametrize(("time_zone", "expected"), [("UTC", 1), ("Asia/Kolkata", 0)])
+def test_business_day_count_time_zone_aware(time_zone: str, expected: int) -> None:
+    # 21:30 UTC on 2024-01-01 (Monday) is 03:00 on 2024-01-02 in Asia/Kolkata. Days are
+    # taken in the time zone of the datetimes, and 2024-01-02 is a holiday.
+    df = pl.DataFrame(
+        {"start": [datetime(2024, 1, 1, 21, 30)], "end": [datetime(2024, 1, 2, 21, 30)]}
+    ).with_columns(pl.all().dt.replace_time_zone("UTC").dt.convert_time_zone(time_zone))
+    result = df.select(
+        n=pl.business_day_count("start", "end", holidays=[date(2024, 1, 2)])
+    )["n"]
+    assert result.item() == expected
@@ -11,10 +11,10 @@ from hypothesis import assume, given
 
 import polars as pl
 from polars.exceptions import ComputeError, InvalidOperationError
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import Roll, TimeUnit
+    from polars._typing import PolarsIntegerType, Roll, TimeUnit
 
 from zoneinfo import ZoneInfo
 
@@ -214,7 +214,8 @@ def test_add_business_days_invalid() -> None:
         )
     with pytest.raises(
         ValueError,
-        match="`roll` must be one of {'raise', 'forward', 'backward'}, got cabbage",
+        match="`roll` must be one of {'raise', 'forward', 'backward', "
+        "'nearest_forward', 'nearest_backward'}, got cabbage",
     ):
         df.select(result=pl.col("start").dt.add_business_days(1, roll="cabbage"))  # type: ignore[arg-type]
 
@@ -290,3 +291,262 @@ def test_against_np_busday_offset(
 def test_unequal_lengths_22018() -> None:
     with pytest.raises(pl.exceptions.ShapeError):
         pl.Series([date(2088, 8, 5)] * 2).dt.add_business_days(pl.Series([1] * 3))
+
+
+@pytest.mark.parametrize(
+    ("start", "n", "holidays", "expected"),
+    [
+        # 1970-01-05 is a Monday, and ten million business days are two million
+        # weeks.
+        (4, 10_000_000, [], 14_000_004),
+        (4, 10_000_000, [date(1970, 1, 8)], 14_000_005),
+        (14_000_004, -10_000_000, [], 4),
+        (14_000_005, -10_000_000, [date(1970, 1, 8)], 4),
+        # Results close to the bounds of the dates are still exact.
+        (2**31 - 11, 1, [], 2**31 - 10),
+    ],
+)
+def test_add_business_days_large_n(
+    start: int, n: int, holidays: list[date], expected: int
+) -> None:
+    s = pl.Series("start", [start], pl.Int32).cast(pl.Date)
+    result = s.dt.add_business_days(n, holidays=holidays, roll="forward")
+    assert result.cast(pl.Int32).item() == expected
+
+
+@pytest.mark.parametrize(
+    ("start", "n", "dtype"),
+    [
+        (2**31 - 11, 10, pl.Int32),
+        (4, 2**31 - 1, pl.Int32),
+        (-(2**31) + 10, -10, pl.Int32),
+        # Numbers that don't fit in an Int32 raise rather than becoming null.
+        (4, 2**31, pl.Int64),
+        (4, 2**31, pl.UInt32),
+    ],
+)
+def test_add_business_days_overflow(
+    start: int, n: int, dtype: PolarsIntegerType
+) -> None:
+    s = pl.Series("start", [start], pl.Int32).cast(pl.Date)
+    with pytest.raises(ComputeError, match="overflows the range of dates"):
+        s.dt.add_business_days(pl.lit(n, dtype=dtype), roll="forward")
+
+
+@pytest.mark.parametrize(
+    ("start", "n", "week_mask", "holidays", "expected"),
+    [
+        # Without holidays, whole weeks land on the same day of the week.
+        (date(2024, 12, 23), 1, None, (), date(2024, 12, 30)),
+        (date(2024, 12, 23), -3, None, (), date(2024, 12, 2)),
+        (
+            date(2024, 12, 23),
+            1,
+            (True, True, True, True, False, False, False),
+            (),
+            date(2024, 12, 30),
+        ),
+        # Every holiday that is jumped over pushes the result by a business day.
+        (date(2024, 12, 23), 1, None, "christmas", date(2025, 1, 2)),
+        (date(2024, 12, 23), 2, None, "christmas", date(2025, 1, 9)),
+        (date(2025, 1, 9), -2, None, "christmas", date(2024, 12, 23)),
+        (date(2024, 12, 23), 0, None, "christmas", date(2024, 12, 23)),
+        # The start is rolled before adding: Saturday 2024-12-28 rolls to Monday.
+        (date(2024, 12, 28), 1, None, "christmas", date(2025, 1, 7)),
+    ],
+)
+def test_add_business_weeks(
+    start: date,
+    n: int,
+    week_mask: tuple[bool, ...] | None,
+    holidays: tuple[date, ...] | str,
+    expected: date,
+) -> None:
+    if holidays == "christmas":
+        holidays = (date(2024, 12, 25), date(2024, 12, 26), date(2025, 1, 1))
+    if week_mask is None:
+        week_mask = (True, True, True, True, True, False, False)
+    result = pl.Series("start", [start]).dt.add_business_days(
+        n, week_mask=week_mask, holidays=holidays, roll="forward", unit="weeks"
+    )
+    assert result.item() == expected
+
+
+def test_add_business_weeks_invalid() -> None:
+    s = pl.Series("start", [date(2024, 12, 23)])
+    with pytest.raises(ComputeError, match="business weeks overflow"):
+        s.dt.add_business_days(2**31 - 1, unit="weeks")
+    with pytest.raises(ValueError, match="`unit` must be 'days'"):
+        s.dt.add_business_days(1, week_mask=pl.lit(31), unit="weeks")
+
+
+@pytest.mark.parametrize(
+    ("n", "roll", "expected_days"),
+    [
+        (0, "nearest_forward", [5, 5, 5, 15, 15]),
+        (0, "nearest_backward", [5, 5, 5, 5, 15]),
+        (1, "nearest_forward", [15, 15, 15, 16, 16]),
+        (-1, "nearest_backward", [4, 4, 4, 4, 5]),
+    ],
+)
+def test_add_business_days_roll_nearest(
+    n: int, roll: Roll, expected_days: list[int]
+) -> None:
+    # 2024-01-08 (Monday) until 2024-01-12 (Friday) are holidays, so that together
+    # with the surrounding weekends, 2024-01-05 (Friday) and 2024-01-15 (Monday) are
+    # the closest business days to the days in between.
+    holidays = [(date(2024, 1, 8), date(2024, 1, 12))]
+    s = pl.Series("start", [date(2024, 1, day) for day in [5, 7, 9, 10, 11]])
+    result = s.dt.add_business_days(n, holidays=holidays, roll=roll)
+    expected = pl.Series("start", [date(2024, 1, day) for day in expected_days])
+    assert_series_equal(result, expected)
+
+
+def test_add_business_days_raise() -> None:
+    # 2024-01-05 (Friday), 2024-01-06 (Saturday) and 2024-01-08 (Monday).
+    s = pl.Series("start", [date(2024, 1, 5), None, date(2024, 1, 6)])
+    with pytest.raises(ComputeError, match=r"2024-01-06 \(row 2\)"):
+        s.dt.add_business_days(1)
+    s.head(2).dt.add_business_days(1, holidays=[date(2024, 1, 8)])
+    s = pl.Series("start", [date(2024, 1, 8)])
+    with pytest.raises(ComputeError, match=r"2024-01-08 \(row 0\)"):
+        s.dt.add_business_days(1, holidays=[date(2024, 1, 8)])
+
+
+@pytest.mark.parametrize("roll", ["forward", "backward"])
+def test_sub_business_days(roll: Roll) -> None:
+    # 2024-01-06 (Saturday) and 2024-01-08 (Monday).
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 6), date(2024, 1, 8)],
+            "n": pl.Series([1, 3], dtype=pl.UInt32),
+        }
+    )
+    result = df.select(pl.col("start").dt.sub_business_days("n", roll=roll))
+    expected = df.select(
+        pl.col("start").dt.add_business_days(-pl.col("n").cast(pl.Int32), roll=roll)
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_sub_business_days_overflow() -> None:
+    s = pl.Series("start", [date(2024, 1, 8)])
+    with pytest.raises(ComputeError, match="overflows the range of dates"):
+        s.dt.sub_business_days(pl.lit(-(2**31), dtype=pl.Int32))
+
+
+def test_add_business_days_w_week_masks_expr() -> None:
+    # 2024-01-05 is a Friday.
+    df = pl.DataFrame(
+        {
+            "week_mask": [
+                [True, True, True, True, True, False, False],
+                [True, True, True, True, True, True, False],
+                None,
+            ]
+        }
+    )
+    result = df.select(
+        result=pl.lit(date(2024, 1, 5)).dt.add_business_days(
+            1, week_mask=pl.col("week_mask")
+        )
+    )["result"]
+    expected = pl.Series("result", [date(2024, 1, 8), date(2024, 1, 6), None])
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="has 2 elements, expected 7"):
+        pl.select(
+            pl.lit(date(2024, 1, 5)).dt.add_business_days(
+                1, week_mask=pl.lit(pl.Series([[True, True]]))
+            )
+        )
+
+
+def test_next_and_previous_business_day() -> None:
+    # 2024-01-05 (Friday) until 2024-01-09 (Tuesday), with a holiday on 2024-01-08.
+    s = pl.Series("date", [date(2024, 1, day) for day in range(5, 10)] + [None])
+    holidays = [date(2024, 1, 8)]
+
+    result = s.dt.next_business_day(holidays=holidays)
+    expected = [date(2024, 1, day) for day in [9, 9, 9, 9, 10]] + [None]
+    assert_series_equal(result, pl.Series("date", expected))
+    result = s.dt.previous_business_day(holidays=holidays)
+    expected = [date(2024, 1, day) for day in [4, 5, 5, 5, 5]] + [None]
+    assert_series_equal(result, pl.Series("date", expected))
+
+    result = s.dt.next_business_day(holidays=holidays, inclusive=True)
+    expected = [date(2024, 1, day) for day in [5, 9, 9, 9, 9]] + [None]
+    assert_series_equal(result, pl.Series("date", expected))
+    result = s.dt.previous_business_day(holidays=holidays, inclusive=True)
+    expected = [date(2024, 1, day) for day in [5, 5, 5, 5, 9]] + [None]
+    assert_series_equal(result, pl.Series("date", expected))
+
+
+def test_business_month_end_and_begin() -> None:
+    # 2024-03-31 is a Sunday and 2024-03-29 (Good Friday) a holiday; 2024-06-01 is
+    # a Saturday and 2024-06-03 a holiday.
+    holidays = [date(2024, 3, 29), date(2024, 6, 3)]
+    s = pl.Series(
+        "date",
+        [
+            date(2024, 3, 1),
+            date(2024, 3, 31),
+            None,
+            date(2024, 5, 31),
+            date(2024, 6, 30),
+        ],
+    )
+
+    result = s.dt.business_month_end(holidays=holidays)
+    expected = pl.Series(
+        "date",
+        [
+            date(2024, 3, 28),
+            date(2024, 3, 28),
+            None,
+            date(2024, 5, 31),
+            date(2024, 6, 28),
+        ],
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.business_month_begin(holidays=holidays)
+    expected = pl.Series(
+        "date",
+        [date(2024, 3, 1), date(2024, 3, 1), None, date(2024, 5, 1), date(2024, 6, 4)],
+    )
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="at least one business day"):
+        s.dt.business_month_end(week_mask=[False] * 7)
+
+
+def test_add_business_days_time_zone_aware() -> None:
+    # 03:00 on 2024-01-01 (Monday) in Asia/Kolkata is on the Sunday before in UTC.
+    s = pl.Series("start", [datetime(2024, 1, 1, 3)]).dt.replace_time_zone(
+        "Asia/Kolkata"
+    )
+    result = s.dt.add_business_days(1, holidays=[date(2024, 1, 2)])
+    expected = pl.Series("start", [datetime(2024, 1, 3, 3)]).dt.replace_time_zone(
+        "Asia/Kolkata"
+    )
+    assert_series_equal(result, expected)
+
+
+def test_add_business_days_across_dst() -> None:
+    # The clocks go forward on Sunday 2024-03-31 in Europe/London, but the local time
+    # of day is kept.
+    s = pl.Series("start", [datetime(2024, 3, 29, 12)]).dt.replace_time_zone(
+        "Europe/London"
+    )
+    result = s.dt.add_business_days(1)
+    expected = pl.Series("start", [datetime(2024, 4, 1, 12)]).dt.replace_time_zone(
+        "Europe/London"
+    )
+    assert_series_equal(result, expected)
+
+    # 01:30 doesn't exist on 2024-03-31, and exists twice on 2024-10-27.
+    for start in [datetime(2024, 3, 30, 1, 30), datetime(2024, 10, 26, 1, 30)]:
+        s = pl.Series("start", [start]).dt.replace_time_zone("Europe/London")
+        with pytest.raises(ComputeError):
+            s.dt.add_business_days(1, week_mask=[True] * 7)
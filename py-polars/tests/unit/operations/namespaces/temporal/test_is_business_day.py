@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date, datetime
+from datetime import date, datetime, timedelta
 
 import pytest
 
@@ -8,6 +8,8 @@ import polars as pl
 from polars.exceptions import ComputeError
 from polars.testing import assert_series_equal
 
+EPOCH = date(1970, 1, 1)
+
 
 @pytest.mark.parametrize(
     ("holidays", "week_mask", "expected_values"),
@@ -82,3 +84,131 @@ def test_is_business_day_invalid() -> None:
 
 def test_is_business_day_repr() -> None:
     assert "is_business_day" in repr(pl.col("date").dt.is_business_day())
+
+
+@pytest.mark.parametrize(
+    "holidays",
+    [
+        # Holidays within a short range use a bitset, ones spread over a long range are
+        # binary searched.
+        [-300, -7, 0, 3, 64, 65, 128, 500],
+        [-100_000, -7, 0, 3, 64, 500, 100_000],
+        [],
+    ],
+)
+def test_is_business_day_holiday_lookup(holidays: list[int]) -> None:
+    week_mask = (True, True, True, True, False, True, False)
+    holiday_dates = [EPOCH + timedelta(days=holiday) for holiday in holidays]
+    dates = [EPOCH + timedelta(days=day) for day in range(-1000, 1000)]
+    result = pl.Series("date", dates).dt.is_business_day(
+        week_mask=week_mask, holidays=holiday_dates
+    )
+    expected = pl.Series(
+        "date",
+        [week_mask[date.weekday()] and date not in holiday_dates for date in dates],
+    )
+    assert_series_equal(result, expected)
+
+
+def test_is_business_day_w_holiday_ranges() -> None:
+    # Reversed ranges are empty.
+    holidays = [
+        EPOCH + timedelta(days=10),
+        EPOCH + timedelta(days=3),
+        (EPOCH + timedelta(days=5), EPOCH + timedelta(days=7)),
+        (EPOCH + timedelta(days=9), EPOCH + timedelta(days=8)),
+        (EPOCH + timedelta(days=12), EPOCH + timedelta(days=12)),
+    ]
+    s = pl.Series("date", [EPOCH + timedelta(days=day) for day in range(15)])
+    result = s.dt.is_holiday(holidays)
+    expected = pl.Series("date", [day in (3, 5, 6, 7, 10, 12) for day in range(15)])
+    assert_series_equal(result, expected)
+    result = s.dt.is_business_day(week_mask=[True] * 7, holidays=holidays)
+    assert_series_equal(result, ~expected)
+
+
+def test_is_business_day_holidays_eq() -> None:
+    def is_business_day(holidays: list[date | tuple[date, date]]) -> pl.Expr:
+        return pl.col("date").dt.is_business_day(holidays=holidays)
+
+    def day(n: int) -> date:
+        return EPOCH + timedelta(days=n)
+
+    # Holidays are compared as the sets of days they cover.
+    a = is_business_day([day(10), day(3), day(3), (day(5), day(6))])
+    b = is_business_day([day(5), day(10), (day(6), day(6)), (day(2), day(3))])
+    assert not a.meta.eq(b)
+    b = is_business_day([day(5), day(10), (day(6), day(6)), (day(3), day(3))])
+    assert a.meta.eq(b)
+
+    # Huge ranges are compared without expanding them.
+    a = is_business_day([date(9999, 12, 31), (EPOCH, date(9999, 12, 30))])
+    b = is_business_day([(day(10), date(9999, 12, 31)), (EPOCH, day(9))])
+    assert a.meta.eq(b)
+    assert not a.meta.eq(is_business_day([EPOCH]))
+
+
+def test_is_business_day_time_zone_aware() -> None:
+    # 03:00 on 2024-01-02 in Asia/Kolkata is 21:30 on 2024-01-01 in UTC.
+    holidays = [date(2024, 1, 2)]
+    s = pl.Series("date", [datetime(2024, 1, 2, 3)]).dt.replace_time_zone(
+        "Asia/Kolkata"
+    )
+    assert s.dt.is_business_day(holidays=holidays).item() is False
+    s = s.dt.convert_time_zone("UTC")
+    assert s.dt.is_business_day(holidays=holidays).item() is True
+
+
+def test_is_holiday() -> None:
+    # 2024-01-01 (Monday), 2024-01-06 (Saturday) and 2024-01-07 (Sunday).
+    s = pl.Series("date", [date(2024, 1, 1), date(2024, 1, 6), None, date(2024, 1, 7)])
+    result = s.dt.is_holiday([date(2024, 1, 6), date(2024, 1, 1), date(2024, 1, 1)])
+    expected = pl.Series("date", [True, True, None, False])
+    assert_series_equal(result, expected)
+
+
+def test_is_weekend() -> None:
+    # 2024-01-01 (Monday), 2024-01-06 (Saturday) and 2024-01-07 (Sunday).
+    s = pl.Series("date", [date(2024, 1, 1), date(2024, 1, 6), None, date(2024, 1, 7)])
+    result = s.dt.is_weekend(week_mask=(True, True, True, True, True, True, False))
+    expected = pl.Series("date", [False, False, None, True])
+    assert_series_equal(result, expected)
+
+
+def test_business_days_until_holiday() -> None:
+    s = pl.Series(
+        "date",
+        [
+            date(2024, 1, 1),
+            date(2024, 1, 3),
+            date(2024, 1, 6),
+            None,
+            date(2024, 1, 10),
+            date(2024, 1, 20),
+        ],
+    )
+    # 2024-01-13 is a Saturday, so it's reached on the Monday after it.
+    result = s.dt.business_days_until_holiday(
+        holidays=[date(2024, 1, 13), date(2024, 1, 5)]
+    )
+    expected = pl.Series("date", [4, 2, 5, None, 3, None], dtype=pl.Int32)
+    assert_series_equal(result, expected)
+
+
+def test_business_day_of_month() -> None:
+    # 2024-02-01 (Thursday) until 2024-02-07 (Wednesday), with a holiday on 2024-02-05.
+    s = pl.Series("date", [date(2024, 2, day) for day in range(1, 8)] + [None])
+    result = s.dt.business_day_of_month(holidays=[date(2024, 2, 5)])
+    expected = pl.Series("date", [1, 2, None, None, None, 3, 4, None], dtype=pl.Int32)
+    assert_series_equal(result, expected)
+
+
+def test_business_day_reason() -> None:
+    # 2024-01-05 (Friday) until 2024-01-08 (Monday), with holidays on 2024-01-06 and
+    # 2024-01-08.
+    s = pl.Series("date", [date(2024, 1, day) for day in range(5, 9)] + [None])
+    result = s.dt.business_day_reason(holidays=[date(2024, 1, 6), date(2024, 1, 8)])
+    expected = pl.Series("date", ["business", "weekend", "weekend", "holiday", None])
+    assert_series_equal(result, expected)
+    with pytest.raises(ComputeError, match="at least one business day"):
+        s.dt.business_day_reason(week_mask=[False] * 7)